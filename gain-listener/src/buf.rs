@@ -0,0 +1,180 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::Conn;
+use futures_io::{AsyncBufRead, AsyncRead, AsyncWrite};
+use std::future::poll_fn;
+use std::io::{self, IoSlice};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+const DEFAULT_CAPACITY: usize = 8192;
+
+/// Connection wrapper with a read buffer, for protocols which are parsed
+/// incrementally, e.g. line by line.  Writes are passed through.
+pub struct BufConn {
+    conn: Conn,
+    buf: Box<[u8]>,
+    pos: usize,
+    cap: usize,
+}
+
+impl BufConn {
+    pub fn new(conn: Conn) -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY, conn)
+    }
+
+    /// Buffer at most `capacity` bytes (at least 1).
+    pub fn with_capacity(capacity: usize, conn: Conn) -> Self {
+        Self {
+            conn,
+            buf: vec![0; capacity.max(1)].into_boxed_slice(),
+            pos: 0,
+            cap: 0,
+        }
+    }
+
+    pub fn get_ref(&self) -> &Conn {
+        &self.conn
+    }
+
+    pub fn get_mut(&mut self) -> &mut Conn {
+        &mut self.conn
+    }
+
+    /// Unwrap the connection.  Buffered data is lost; see `buffer`.
+    pub fn into_inner(self) -> Conn {
+        self.conn
+    }
+
+    /// Data which has been read from the connection but not consumed.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..self.cap]
+    }
+
+    /// Read more data if the buffer is empty.
+    fn poll_fill(&mut self, cx: &mut Context) -> Poll<io::Result<()>> {
+        if self.pos >= self.cap {
+            let n = ready!(Pin::new(&mut self.conn).poll_read(cx, &mut self.buf))?;
+            self.pos = 0;
+            self.cap = n;
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    /// Return the buffered data, reading more if the buffer is empty.  An
+    /// empty slice is returned at end of stream.  The data stays in the
+    /// buffer until it is consumed.
+    pub async fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        poll_fn(|cx| self.poll_fill(cx)).await?;
+        Ok(self.buffer())
+    }
+
+    /// Mark `amount` bytes of the buffered data as read.
+    pub fn consume(&mut self, amount: usize) {
+        self.pos = (self.pos + amount).min(self.cap);
+    }
+
+    /// Read data until the delimiter or end of stream, and append it to
+    /// `out` (including the delimiter).  Returns the number of bytes
+    /// appended; 0 means end of stream.
+    pub async fn read_until(&mut self, delim: u8, out: &mut Vec<u8>) -> io::Result<usize> {
+        let mut total = 0;
+
+        loop {
+            let available = self.fill_buf().await?;
+            if available.is_empty() {
+                return Ok(total);
+            }
+
+            let (n, found) = match available.iter().position(|&b| b == delim) {
+                Some(i) => (i + 1, true),
+                None => (available.len(), false),
+            };
+
+            out.extend_from_slice(&available[..n]);
+            self.consume(n);
+            total += n;
+
+            if found {
+                return Ok(total);
+            }
+        }
+    }
+
+    /// Read a line (terminated by `\n`, which is included) and append it to
+    /// `out`.  Returns the number of bytes appended; 0 means end of stream.
+    /// An `io::ErrorKind::InvalidData` error is returned if the line is not
+    /// valid UTF-8, in which case nothing is appended.
+    pub async fn read_line(&mut self, out: &mut String) -> io::Result<usize> {
+        let mut line = Vec::new();
+        let n = self.read_until(b'\n', &mut line).await?;
+
+        match String::from_utf8(line) {
+            Ok(s) => {
+                out.push_str(&s);
+                Ok(n)
+            }
+            Err(_) => Err(io::ErrorKind::InvalidData.into()),
+        }
+    }
+}
+
+impl AsyncRead for BufConn {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        // Bypass the buffer for large reads when it is empty.
+        if this.pos >= this.cap && buf.len() >= this.buf.len() {
+            return Pin::new(&mut this.conn).poll_read(cx, buf);
+        }
+
+        ready!(this.poll_fill(cx))?;
+
+        let available = this.buffer();
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        this.consume(n);
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncBufRead for BufConn {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        ready!(this.poll_fill(cx))?;
+        Poll::Ready(Ok(this.buffer()))
+    }
+
+    fn consume(self: Pin<&mut Self>, amount: usize) {
+        self.get_mut().consume(amount)
+    }
+}
+
+impl AsyncWrite for BufConn {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().conn).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        bufs: &[IoSlice],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().conn).poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().conn).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().conn).poll_close(cx)
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::recv::RecvBuf;
+use crate::{Conn, ConnReader, OwnedReadHalf};
+use bytes::Bytes;
+use std::future::poll_fn;
+use std::io;
+
+impl ConnReader {
+    /// Receive a chunk of data.  The chunk is the connection's receive
+    /// buffer itself, containing everything which has been received but not
+    /// read yet, so the data isn't copied (the buffer is replaced with a new
+    /// one).  Its size is bounded by `RecvOptions`.  None is returned at end
+    /// of stream.
+    pub async fn recv_bytes(&mut self) -> io::Result<Option<Bytes>> {
+        let mut chunk = None;
+
+        let n = poll_fn(|cx| {
+            let chunk = &mut chunk;
+
+            self.poll_read_with(cx, move |src: &mut RecvBuf| {
+                let data = src.take_bytes();
+                let n = data.len();
+                *chunk = Some(data);
+                n
+            })
+        })
+        .await?;
+
+        if n == 0 {
+            Ok(None)
+        } else {
+            Ok(chunk)
+        }
+    }
+}
+
+impl OwnedReadHalf {
+    /// See `ConnReader::recv_bytes`.
+    pub async fn recv_bytes(&mut self) -> io::Result<Option<Bytes>> {
+        self.reader.recv_bytes().await
+    }
+}
+
+impl Conn {
+    /// See `ConnReader::recv_bytes`.  Takes over the `stream` field like
+    /// `Conn::split`.
+    pub async fn recv_bytes(&mut self) -> io::Result<Option<Bytes>> {
+        self.split().0.recv_bytes().await
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{flat, CloseError, Conn, SERVICE};
+use flatbuffers::{root, FlatBufferBuilder};
+use futures_io::AsyncWrite;
+use gain::stream::Close as _;
+use std::future::poll_fn;
+use std::pin::Pin;
+
+impl Conn {
+    /// Close the connection gracefully.  Pending writes are flushed, and the
+    /// service is asked to send a TLS close_notify alert and to wait for the
+    /// client's response.  A `CloseErrorKind::NotAcknowledged` error is
+    /// returned if the client doesn't respond in kind.
+    pub async fn close(mut self) -> Result<(), CloseError> {
+        match &mut self.io {
+            Some(io) => {
+                let writer = &mut io.writer;
+                poll_fn(|cx| Pin::new(&mut *writer).poll_close(cx))
+                    .await
+                    .map_err(|_| CloseError::write())?;
+            }
+
+            None => self.stream.close().await,
+        }
+
+        close_conn(self.id).await
+    }
+
+    /// Tear down the connection without flushing pending writes.  The
+    /// service resets the client connection, if supported; otherwise the
+    /// streams are just dropped.  A write which has been started is finished
+    /// before this returns, as the runtime requires it.
+    pub async fn abort(mut self) {
+        // Dropping the streams would close the connection gracefully, so
+        // they are held until the service has been asked to reset it.
+        abort_conn(self.id).await;
+
+        let writer = self.io.take().map(|io| io.writer);
+        drop(self);
+
+        if let Some(writer) = writer {
+            writer.discard().await;
+        }
+    }
+}
+
+async fn close_conn(conn_id: i32) -> Result<(), CloseError> {
+    let mut b = FlatBufferBuilder::new();
+
+    let function = flat::CloseConn::create(&mut b, &flat::CloseConnArgs { conn_id });
+
+    let call = flat::Call::create(
+        &mut b,
+        &flat::CallArgs {
+            function_type: flat::Function::CloseConn,
+            function: Some(function.as_union_value()),
+        },
+    );
+
+    b.finish_minimal(call);
+
+    SERVICE
+        .call(b.finished_data(), |reply: &[u8]| {
+            if reply.is_empty() {
+                return Err(CloseError::unsupported_call());
+            }
+
+            let r = match root::<flat::ConnClosed>(reply) {
+                Ok(r) => r,
+                Err(_) => return Err(CloseError::decode()),
+            };
+
+            if r.error() != flat::CloseError::None {
+                return Err(CloseError::new(r.error()));
+            }
+
+            Ok(())
+        })
+        .await
+}
+
+async fn abort_conn(conn_id: i32) {
+    let mut b = FlatBufferBuilder::new();
+
+    let function = flat::AbortConn::create(&mut b, &flat::AbortConnArgs { conn_id });
+
+    let call = flat::Call::create(
+        &mut b,
+        &flat::CallArgs {
+            function_type: flat::Function::AbortConn,
+            function: Some(function.as_union_value()),
+        },
+    );
+
+    b.finish_minimal(call);
+
+    // An unsupported call leaves closing to the dropped streams.
+    SERVICE.call(b.finished_data(), |_: &[u8]| ()).await;
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::recv::RecvBuf;
+use crate::Conn;
+use futures_io::AsyncWrite as _;
+use std::io::{self, IoSlice};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+/// Connection wrapper which implements the Tokio I/O traits.  See
+/// `Conn::compat`.
+pub struct Compat {
+    conn: Conn,
+}
+
+impl Compat {
+    pub fn get_ref(&self) -> &Conn {
+        &self.conn
+    }
+
+    pub fn get_mut(&mut self) -> &mut Conn {
+        &mut self.conn
+    }
+
+    pub fn into_inner(self) -> Conn {
+        self.conn
+    }
+}
+
+impl Conn {
+    /// Wrap the connection for libraries which use `tokio::io::AsyncRead`
+    /// and `tokio::io::AsyncWrite`.  The `stream` field is taken over like
+    /// with `Conn::split`.
+    pub fn compat(self) -> Compat {
+        Compat { conn: self }
+    }
+}
+
+impl AsyncRead for Compat {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        buf: &mut ReadBuf,
+    ) -> Poll<io::Result<()>> {
+        if buf.remaining() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        // Copy directly into the unfilled part without initializing it.
+        let reader = self.get_mut().conn.split().0;
+        ready!(reader.poll_read_with(cx, |src: &mut RecvBuf| {
+            let n = src.len().min(buf.remaining());
+            buf.put_slice(&src.as_slice()[..n]);
+            src.consume(n);
+            n
+        }))?;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for Compat {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().conn).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        bufs: &[IoSlice],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().conn).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().conn).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().conn).poll_close(cx)
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::io::write_error;
+use crate::{
+    accept, bind, flat, flat_addr, recv_frame, socket_addr, AcceptError, BindError, BindOptions,
+    Binding, FrameError, Protocol, SERVICE, SIZE_PREFIX,
+};
+use flatbuffers::{size_prefixed_root, FlatBufferBuilder};
+use gain::stream::{Close, CloseStream, RecvOnlyStream, RecvStream, RecvWriteStream, Write};
+use std::io;
+use std::net::SocketAddr;
+
+/// Datagram listener.
+pub struct DatagramListener {
+    stream: RecvWriteStream,
+    pub addr: Binding,
+}
+
+impl DatagramListener {
+    /// Listen to UDP datagrams at `BindOptions::port`.  The fully-qualified
+    /// DNS name can be discovered from the `DatagramListener::addr.hostname`
+    /// field.
+    ///
+    /// `BindOptions::prefix` has the same semantics as with
+    /// `Listener::bind_tls`.
+    pub async fn bind_udp(opt: BindOptions<'_>) -> Result<Self, BindError> {
+        let (listen_id, _, addr, _) = bind(opt, Protocol::Udp, None).await?;
+
+        Ok(Self {
+            stream: SERVICE.stream(listen_id),
+            addr,
+        })
+    }
+
+    /// Receive a datagram.  On success, returns the number of bytes read and
+    /// the origin.  If a datagram is too long to fit in `buf`, excess bytes
+    /// are discarded.  A `NotConnected` error is returned if the listener
+    /// has been closed, which may happen due to environmental causes.
+    pub async fn recv_from(&mut self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        let frame = match recv_frame(&mut self.stream).await {
+            Ok(frame) => frame,
+            Err(FrameError::Closed) => return Err(closed()),
+            Err(FrameError::TooLarge) => return Err(too_large()),
+        };
+
+        let d = match size_prefixed_root::<flat::Datagram>(&frame) {
+            Ok(d) => d,
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        };
+        let addr = match d.addr() {
+            Some(v) => v,
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "datagram without address",
+                ))
+            }
+        };
+
+        let data = match d.data() {
+            Some(v) => v.bytes(),
+            None => &[],
+        };
+
+        let n = data.len().min(buf.len());
+        buf[..n].copy_from_slice(&data[..n]);
+
+        Ok((n, socket_addr(addr, d.port())))
+    }
+
+    /// Send a datagram to the given address.  On success, returns the number
+    /// of bytes written.  A `BrokenPipe` error is returned if the listener
+    /// has been closed.
+    pub async fn send_to(&mut self, buf: &[u8], target: SocketAddr) -> io::Result<usize> {
+        let mut b = FlatBufferBuilder::new();
+
+        let data = b.create_vector(buf);
+
+        let d = flat::Datagram::create(
+            &mut b,
+            &flat::DatagramArgs {
+                addr: Some(&flat_addr(&target)),
+                port: target.port(),
+                data: Some(data),
+            },
+        );
+
+        b.finish_size_prefixed(d, None);
+
+        self.stream
+            .write_all(b.finished_data())
+            .await
+            .map_err(write_error)?;
+        Ok(buf.len())
+    }
+}
+
+/// DTLS association listener.
+pub struct DtlsListener {
+    stream: RecvStream,
+    accept_size: flat::AcceptSize,
+    pub addr: Binding,
+}
+
+impl DtlsListener {
+    /// Listen to DTLS associations at `BindOptions::port`.  The service
+    /// performs the handshakes and tracks associations per peer address; each
+    /// new peer is accepted as a `DtlsAssociation`.
+    ///
+    /// `BindOptions::prefix` has the same semantics as with
+    /// `Listener::bind_tls`.
+    pub async fn bind_dtls(opt: BindOptions<'_>) -> Result<Self, BindError> {
+        let (listen_id, accept_size, addr, _) = bind(opt, Protocol::Dtls, None).await?;
+
+        Ok(Self {
+            stream: SERVICE.input_stream(listen_id),
+            accept_size,
+            addr,
+        })
+    }
+
+    /// Accept an association with a new peer.  An `AcceptErrorKind::Closed`
+    /// error may occur due to environmental causes.
+    pub async fn accept(&mut self) -> Result<DtlsAssociation, AcceptError> {
+        let conn = accept(&mut self.stream, self.accept_size, None).await?;
+
+        Ok(DtlsAssociation {
+            stream: conn.stream,
+            peer_addr: conn.peer_addr,
+        })
+    }
+
+    /// Detach the closing functionality.  When the `CloseStream` is closed or
+    /// dropped, `accept` will return an `AcceptErrorKind::Closed` error.
+    pub fn split(self) -> (DtlsAcceptor, CloseStream) {
+        let (stream, c) = self.stream.split();
+        (
+            DtlsAcceptor {
+                stream,
+                accept_size: self.accept_size,
+                addr: self.addr,
+            },
+            c,
+        )
+    }
+}
+
+/// DTLS association acceptor.
+pub struct DtlsAcceptor {
+    stream: RecvOnlyStream,
+    accept_size: flat::AcceptSize,
+    pub addr: Binding,
+}
+
+impl DtlsAcceptor {
+    /// Accept an association with a new peer.  An `AcceptErrorKind::Closed`
+    /// error may be caused by the associated `CloseStream`, or other
+    /// environmental reasons.
+    pub async fn accept(&mut self) -> Result<DtlsAssociation, AcceptError> {
+        let conn = accept(&mut self.stream, self.accept_size, None).await?;
+
+        Ok(DtlsAssociation {
+            stream: conn.stream,
+            peer_addr: conn.peer_addr,
+        })
+    }
+}
+
+/// DTLS association with a single peer.  Datagram boundaries are preserved.
+pub struct DtlsAssociation {
+    stream: RecvWriteStream,
+
+    /// The peer's address.
+    pub peer_addr: SocketAddr,
+}
+
+impl DtlsAssociation {
+    /// Receive a datagram from the peer.  On success, returns the number of
+    /// bytes read.  If a datagram is too long to fit in `buf`, excess bytes
+    /// are discarded.  A `NotConnected` error is returned when the
+    /// association has ended.
+    pub async fn recv(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let frame = match recv_frame(&mut self.stream).await {
+            Ok(frame) => frame,
+            Err(FrameError::Closed) => return Err(closed()),
+            Err(FrameError::TooLarge) => return Err(too_large()),
+        };
+
+        let data = &frame[SIZE_PREFIX..];
+        let n = data.len().min(buf.len());
+        buf[..n].copy_from_slice(&data[..n]);
+        Ok(n)
+    }
+
+    /// Send a datagram to the peer.  On success, returns the number of bytes
+    /// written.  A `BrokenPipe` error is returned when the association has
+    /// ended.
+    pub async fn send(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut frame = Vec::with_capacity(SIZE_PREFIX + buf.len());
+        frame.extend_from_slice(&(buf.len() as u32).to_le_bytes());
+        frame.extend_from_slice(buf);
+
+        self.stream.write_all(&frame).await.map_err(write_error)?;
+        Ok(buf.len())
+    }
+
+    /// End the association.
+    pub async fn close(&mut self) {
+        self.stream.close().await
+    }
+}
+
+fn closed() -> io::Error {
+    io::ErrorKind::NotConnected.into()
+}
+
+fn too_large() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "frame too large")
+}
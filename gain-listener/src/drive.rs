@@ -0,0 +1,154 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+//! Futures which are driven by the runtime's I/O wakeups.
+//!
+//! The runtime panics if a task is spawned or woken while a spawned task is
+//! being run, but it wakes stream and service futures only while it performs
+//! I/O, between task runs.  A driven future is polled with a waker which
+//! spawns a task to poll it again, so it makes progress without an owner
+//! polling it, and it can be started from any task.  Its wakers must not be
+//! woken by anything else than the runtime's I/O.
+
+use gain::task::spawn_local;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Wake, Waker};
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+struct Entry {
+    /// None while the future is being polled.
+    future: Option<LocalFuture>,
+
+    /// Invoked when the future is woken.
+    notify: Option<Rc<dyn Fn()>>,
+
+    /// A task which polls the future has been spawned.
+    scheduled: bool,
+}
+
+thread_local! {
+    static FUTURES: RefCell<HashMap<u64, Entry>> = RefCell::new(HashMap::new());
+    static NEXT_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Handle to a driven future.  Dropping it doesn't cancel the future.
+pub(crate) struct Driven {
+    id: u64,
+}
+
+impl Driven {
+    /// Poll the future in the current context, if it hasn't completed.
+    pub(crate) fn poll(&self) {
+        poll(self.id);
+    }
+}
+
+/// Poll the future until it completes, starting immediately.  The notify
+/// function is invoked whenever the future is woken, before it is polled;
+/// it may wake tasks.
+pub(crate) fn start<F>(future: F, notify: Option<Rc<dyn Fn()>>) -> Driven
+where
+    F: Future<Output = ()> + 'static,
+{
+    let id = NEXT_ID.with(|n| n.replace(n.get() + 1));
+
+    FUTURES.with(|f| {
+        f.borrow_mut().insert(
+            id,
+            Entry {
+                future: Some(Box::pin(future)),
+                notify,
+                scheduled: false,
+            },
+        )
+    });
+
+    poll(id);
+    Driven { id }
+}
+
+/// Poll the future until it completes, starting immediately, e.g. a write
+/// which must not be abandoned after it has been started.
+pub(crate) fn detach<F>(future: F)
+where
+    F: Future<Output = ()> + 'static,
+{
+    start(future, None);
+}
+
+fn poll(id: u64) {
+    let future = FUTURES.with(|f| {
+        f.borrow_mut()
+            .get_mut(&id)
+            .and_then(|entry| entry.future.take())
+    });
+
+    let mut future = match future {
+        Some(future) => future,
+        None => return,
+    };
+
+    let waker = Waker::from(Arc::new(DriveWaker { id }));
+
+    // The entry isn't borrowed while polling, so the future may start
+    // other futures.
+    let done = future
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker))
+        .is_ready();
+
+    FUTURES.with(|f| {
+        let mut futures = f.borrow_mut();
+
+        if done {
+            futures.remove(&id);
+        } else if let Some(entry) = futures.get_mut(&id) {
+            entry.future = Some(future);
+        }
+    });
+}
+
+struct DriveWaker {
+    id: u64,
+}
+
+impl Wake for DriveWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let id = self.id;
+
+        let (notify, spawn) = FUTURES.with(|f| match f.borrow_mut().get_mut(&id) {
+            Some(entry) => (
+                entry.notify.clone(),
+                !mem::replace(&mut entry.scheduled, true),
+            ),
+            None => (None, false),
+        });
+
+        if let Some(notify) = notify {
+            notify();
+        }
+
+        if spawn {
+            spawn_local(async move {
+                FUTURES.with(|f| {
+                    if let Some(entry) = f.borrow_mut().get_mut(&id) {
+                        entry.scheduled = false;
+                    }
+                });
+                poll(id);
+            });
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{Conn, ConnReader, ConnWriter, OwnedReadHalf, OwnedWriteHalf};
+use embedded_io_async::{ErrorType, Read, Write};
+use futures_io::{AsyncRead as _, AsyncWrite as _};
+use std::future::poll_fn;
+use std::io;
+use std::pin::Pin;
+
+impl ErrorType for ConnReader {
+    type Error = io::Error;
+}
+
+impl Read for ConnReader {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_read(cx, buf)).await
+    }
+}
+
+impl ErrorType for ConnWriter {
+    type Error = io::Error;
+}
+
+impl Write for ConnWriter {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_write(cx, buf)).await
+    }
+
+    async fn flush(&mut self) -> Result<(), io::Error> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_flush(cx)).await
+    }
+}
+
+impl ErrorType for OwnedReadHalf {
+    type Error = io::Error;
+}
+
+impl Read for OwnedReadHalf {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_read(cx, buf)).await
+    }
+}
+
+impl ErrorType for OwnedWriteHalf {
+    type Error = io::Error;
+}
+
+impl Write for OwnedWriteHalf {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_write(cx, buf)).await
+    }
+
+    async fn flush(&mut self) -> Result<(), io::Error> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_flush(cx)).await
+    }
+}
+
+impl ErrorType for Conn {
+    type Error = io::Error;
+}
+
+/// Takes over the `stream` field; see `Conn::split`.
+impl Read for Conn {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        self.split().0.read(buf).await
+    }
+}
+
+/// Takes over the `stream` field; see `Conn::split`.
+impl Write for Conn {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.split().1.write(buf).await
+    }
+
+    async fn flush(&mut self) -> Result<(), io::Error> {
+        self.split().1.flush().await
+    }
+}
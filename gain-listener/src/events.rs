@@ -0,0 +1,124 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::poll_fn;
+use std::net::SocketAddr;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+
+/// Events which are queued until they are received.  Older events are
+/// discarded when the queue is full.
+const MAX_QUEUED_EVENTS: usize = 256;
+
+/// Something which happened at a listener without producing a connection.
+pub enum ListenerEvent {
+    /// A TLS handshake was rejected due to `BindOptions::strict_sni`.
+    SniRejected(SniRejection),
+}
+
+/// Details of a rejected TLS handshake.
+pub struct SniRejection {
+    _internal: (),
+
+    /// The client's address.
+    pub peer_addr: SocketAddr,
+
+    /// Server name indicated by the client, or None if the client didn't send
+    /// one.
+    pub server_name: Option<String>,
+}
+
+impl SniRejection {
+    pub(crate) fn new(peer_addr: SocketAddr, server_name: Option<String>) -> Self {
+        Self {
+            _internal: (),
+            peer_addr,
+            server_name,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    queue: VecDeque<ListenerEvent>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// Receiver of listener events.
+pub struct ListenerEvents {
+    shared: Rc<RefCell<Shared>>,
+}
+
+impl ListenerEvents {
+    /// Wait for the next event.  None is returned after the listener (or
+    /// acceptor) has been dropped and the queued events have been received.
+    /// Events are produced only while a connection is being accepted.
+    pub async fn next(&mut self) -> Option<ListenerEvent> {
+        poll_fn(|cx| {
+            let mut shared = self.shared.borrow_mut();
+
+            if let Some(event) = shared.queue.pop_front() {
+                Poll::Ready(Some(event))
+            } else if shared.closed {
+                Poll::Ready(None)
+            } else {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Receive a queued event without waiting.
+    pub fn try_next(&mut self) -> Option<ListenerEvent> {
+        self.shared.borrow_mut().queue.pop_front()
+    }
+}
+
+/// Sending end of listener events.  Events are discarded while there are no
+/// receivers.
+#[derive(Default)]
+pub(crate) struct EventSink {
+    shared: Rc<RefCell<Shared>>,
+}
+
+impl EventSink {
+    pub(crate) fn subscribe(&self) -> ListenerEvents {
+        ListenerEvents {
+            shared: self.shared.clone(),
+        }
+    }
+
+    pub(crate) fn push(&self, event: ListenerEvent) {
+        if Rc::strong_count(&self.shared) == 1 {
+            return;
+        }
+
+        let mut shared = self.shared.borrow_mut();
+
+        if shared.queue.len() == MAX_QUEUED_EVENTS {
+            shared.queue.pop_front();
+        }
+        shared.queue.push_back(event);
+
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for EventSink {
+    fn drop(&mut self) {
+        let mut shared = self.shared.borrow_mut();
+
+        shared.closed = true;
+
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{flat, Acceptor, SERVICE};
+use flatbuffers::FlatBufferBuilder;
+use std::net::SocketAddr;
+use std::rc::Rc;
+
+/// Decision of an accept filter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Accept,
+    Reject,
+}
+
+pub(crate) type Filter = Rc<dyn Fn(&SocketAddr) -> Verdict>;
+
+impl Acceptor {
+    /// Install a function which decides whether to accept a connection based
+    /// on the client's address.  Rejected connections are closed by the
+    /// service without opening a stream for them, and are not returned by
+    /// `accept`.  Replaces any previously installed filter.
+    pub fn set_filter<F>(&mut self, filter: F)
+    where
+        F: Fn(&SocketAddr) -> Verdict + 'static,
+    {
+        self.filter = Some(Rc::new(filter));
+    }
+
+    /// Remove the accept filter.
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+    }
+}
+
+pub(crate) async fn reject_conn(conn_id: i32) {
+    let mut b = FlatBufferBuilder::new();
+
+    let function = flat::RejectConn::create(&mut b, &flat::RejectConnArgs { conn_id });
+
+    let call = flat::Call::create(
+        &mut b,
+        &flat::CallArgs {
+            function_type: flat::Function::RejectConn,
+            function: Some(function.as_union_value()),
+        },
+    );
+
+    b.finish_minimal(call);
+
+    let supported = SERVICE
+        .call(b.finished_data(), |reply: &[u8]| !reply.is_empty())
+        .await;
+
+    if !supported {
+        // Closing the connection stream is the best we can do.
+        drop(SERVICE.stream(conn_id));
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+//! HTTP/1.1 server.
+//!
+//! ```no_run
+//! use gain_listener::http::{serve_http, Request, Response};
+//! use gain_listener::{BindOptions, Listener};
+//!
+//! async fn hello(_: Request) -> Response {
+//!     Response::text(200, "Hello, world\n")
+//! }
+//!
+//! async fn serve() {
+//!     let listener = Listener::bind_tls(BindOptions::with_prefix("www", 443))
+//!         .await
+//!         .unwrap();
+//!
+//!     serve_http(listener, hello).await.unwrap();
+//! }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+
+mod chunked;
+#[cfg(feature = "compression")]
+mod compress;
+mod cookie;
+mod files;
+mod form;
+mod headers;
+mod multipart;
+mod request;
+mod response;
+mod router;
+mod server;
+mod sse;
+mod upgrade;
+mod writer;
+
+#[cfg(feature = "compression")]
+pub use compress::{Compress, CompressOptions};
+pub use cookie::{Cookie, CookieJar, SameSite};
+pub use files::StaticFiles;
+pub use form::{percent_decode, Form};
+pub use headers::Headers;
+pub use multipart::{Multipart, MultipartOptions, Part};
+pub use request::{Body, Request, Version};
+pub use response::{reason_phrase, Response};
+pub use router::Router;
+pub use server::{serve_conn, serve_conn_with, serve_http, serve_http_with, ServerOptions};
+pub use sse::{Event, EventStream};
+pub use upgrade::Upgraded;
+pub use writer::BodyWriter;
+
+/// Boxed future which resolves to a response.
+pub type ResponseFuture = Pin<Box<dyn Future<Output = Response>>>;
+
+/// Request handler.  It is implemented for async functions and closures
+/// which take a `Request` and return a `Response`.
+pub trait Handler {
+    fn call(&self, req: Request) -> ResponseFuture;
+}
+
+impl<F, R> Handler for F
+where
+    F: Fn(Request) -> R,
+    R: Future<Output = Response> + 'static,
+{
+    fn call(&self, req: Request) -> ResponseFuture {
+        Box::pin(self(req))
+    }
+}
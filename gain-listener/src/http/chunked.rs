@@ -0,0 +1,251 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::Headers;
+use std::io::{self, Write as _};
+
+/// Maximum length of a chunk header or trailer line.
+const MAX_LINE: usize = 1024;
+
+/// Maximum total size of trailer fields.
+const MAX_TRAILER: usize = 16384;
+
+enum State {
+    Size,
+    Data(u64),
+    DataEnd,
+    Trailer(usize),
+    Done,
+}
+
+/// Decoder for the chunked transfer coding.  Chunk extensions and trailer
+/// fields are discarded.
+pub(crate) struct ChunkedDecoder {
+    state: State,
+}
+
+impl ChunkedDecoder {
+    pub(crate) fn new() -> Self {
+        Self { state: State::Size }
+    }
+
+    pub(crate) fn is_done(&self) -> bool {
+        matches!(self.state, State::Done)
+    }
+
+    /// Decode data from `src` into `dest`.  Returns the number of bytes
+    /// consumed from `src` and the number of bytes written to `dest`.  Nothing
+    /// is written if more input is needed or the body has ended.
+    pub(crate) fn decode(&mut self, src: &[u8], dest: &mut [u8]) -> io::Result<(usize, usize)> {
+        let mut consumed = 0;
+
+        loop {
+            let src = &src[consumed..];
+
+            match self.state {
+                State::Data(remaining) => {
+                    let n = (src.len().min(dest.len()) as u64).min(remaining) as usize;
+
+                    dest[..n].copy_from_slice(&src[..n]);
+                    consumed += n;
+
+                    self.state = if n as u64 == remaining {
+                        State::DataEnd
+                    } else {
+                        State::Data(remaining - n as u64)
+                    };
+
+                    return Ok((consumed, n));
+                }
+
+                State::Done => return Ok((consumed, 0)),
+
+                _ => {}
+            }
+
+            let line = match src.iter().position(|&b| b == b'\n') {
+                Some(i) => &src[..i],
+                None if src.len() > MAX_LINE => return Err(invalid("chunk line is too long")),
+                None => return Ok((consumed, 0)),
+            };
+
+            consumed += line.len() + 1;
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+            if line.len() > MAX_LINE {
+                return Err(invalid("chunk line is too long"));
+            }
+
+            self.state = match self.state {
+                State::Size => match parse_size(line)? {
+                    0 => State::Trailer(0),
+                    size => State::Data(size),
+                },
+
+                State::DataEnd if line.is_empty() => State::Size,
+                State::DataEnd => return Err(invalid("chunk data is too long")),
+
+                State::Trailer(_) if line.is_empty() => State::Done,
+                State::Trailer(total) if total + line.len() > MAX_TRAILER => {
+                    return Err(invalid("chunked trailer is too large"))
+                }
+                State::Trailer(total) => State::Trailer(total + line.len()),
+
+                State::Data(_) | State::Done => unreachable!(),
+            };
+        }
+    }
+}
+
+/// Parse a chunk size line, ignoring extensions.
+fn parse_size(line: &[u8]) -> io::Result<u64> {
+    let digits = match line.iter().position(|&b| b == b';') {
+        Some(i) => &line[..i],
+        None => line,
+    };
+    let digits = digits.trim_ascii();
+
+    if digits.is_empty() || digits.len() > 16 {
+        return Err(invalid("invalid chunk size"));
+    }
+
+    let mut size = 0;
+
+    for &b in digits {
+        let digit = match b {
+            b'0'..=b'9' => b - b'0',
+            b'a'..=b'f' => b - b'a' + 10,
+            b'A'..=b'F' => b - b'A' + 10,
+            _ => return Err(invalid("invalid chunk size")),
+        };
+        size = (size << 4) | digit as u64;
+    }
+
+    Ok(size)
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Encode data as a chunk.  Empty data must not be encoded, because it would
+/// terminate the body.
+pub(crate) fn encode_chunk(data: &[u8]) -> Vec<u8> {
+    let mut chunk = Vec::with_capacity(data.len() + 20);
+
+    let _ = write!(chunk, "{:x}\r\n", data.len());
+    chunk.extend_from_slice(data);
+    chunk.extend_from_slice(b"\r\n");
+    chunk
+}
+
+/// Encode the chunk which terminates the body, followed by trailer fields.
+/// Fields which affect message framing are skipped.
+pub(crate) fn encode_last_chunk(trailers: &Headers) -> Vec<u8> {
+    let mut chunk = b"0\r\n".to_vec();
+
+    for (name, value) in trailers.iter() {
+        if name.eq_ignore_ascii_case("Content-Length")
+            || name.eq_ignore_ascii_case("Transfer-Encoding")
+            || name.eq_ignore_ascii_case("Connection")
+        {
+            continue;
+        }
+
+        chunk.extend_from_slice(name.as_bytes());
+        chunk.extend_from_slice(b": ");
+        chunk.extend_from_slice(value);
+        chunk.extend_from_slice(b"\r\n");
+    }
+
+    chunk.extend_from_slice(b"\r\n");
+    chunk
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode a body which arrives `step` bytes at a time into a buffer of
+    /// `dest_size` bytes.  Returns the body and the number of bytes consumed.
+    fn decode(data: &[u8], step: usize, dest_size: usize) -> io::Result<(Vec<u8>, usize)> {
+        let mut decoder = ChunkedDecoder::new();
+        let mut body = Vec::new();
+        let mut dest = vec![0; dest_size];
+        let mut received = 0;
+        let mut pos = 0;
+
+        while !decoder.is_done() {
+            if received == data.len() {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            received = (received + step).min(data.len());
+
+            loop {
+                let (consumed, n) = decoder.decode(&data[pos..received], &mut dest)?;
+                pos += consumed;
+                body.extend_from_slice(&dest[..n]);
+
+                if consumed == 0 && n == 0 {
+                    break;
+                }
+            }
+        }
+
+        Ok((body, pos))
+    }
+
+    #[test]
+    fn decode_body() {
+        let data = b"5\r\nhello\r\n7;ext=1\r\n, world\r\n0\r\nX-Trailer: y\r\n\r\nnext";
+
+        for step in [1, 3, data.len()] {
+            for dest_size in [1, 4, 64] {
+                let (body, n) = decode(data, step, dest_size).unwrap();
+                assert_eq!(body, b"hello, world");
+                assert_eq!(n, data.len() - 4);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_bare_newlines() {
+        let (body, _) = decode(b"A\nabcdefghij\n0\n\n", 64, 64).unwrap();
+        assert_eq!(body, b"abcdefghij");
+    }
+
+    #[test]
+    fn decode_invalid() {
+        for data in [
+            &b"x\r\n"[..],
+            b"\r\n",
+            b"10000000000000000\r\n",
+            b"3\r\nabcd\r\n",
+        ] {
+            let e = decode(data, 64, 64).unwrap_err();
+            assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        }
+
+        let long = vec![b'0'; MAX_LINE + 2];
+        let e = decode(&long, long.len(), 64).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let mut trailers = Headers::new();
+        trailers.insert("X-Checksum", "abc");
+        trailers.insert("Content-Length", "9");
+
+        let mut data = encode_chunk(b"hello, ");
+        data.extend_from_slice(&encode_chunk(b"world"));
+        data.extend_from_slice(&encode_last_chunk(&trailers));
+
+        assert!(data.ends_with(b"0\r\nX-Checksum: abc\r\n\r\n"));
+
+        let (body, n) = decode(&data, 5, 3).unwrap();
+        assert_eq!(body, b"hello, world");
+        assert_eq!(n, data.len());
+    }
+}
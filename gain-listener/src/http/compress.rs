@@ -0,0 +1,264 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::{Handler, Headers, Request, Response, ResponseFuture};
+use flate2::write::{DeflateEncoder, GzEncoder};
+use flate2::Compression;
+use std::io::{self, Write as _};
+use std::rc::Rc;
+
+/// Response compression options.
+#[derive(Clone, Debug)]
+pub struct CompressOptions {
+    _internal: (),
+
+    /// Smaller bodies are not compressed.
+    pub min_size: usize,
+
+    /// Media types which are compressed.  An entry like `text/*` matches all
+    /// subtypes.
+    pub content_types: Vec<String>,
+
+    /// Compression level from 0 to 9.  Brotli uses the same scale.
+    pub level: u32,
+}
+
+impl CompressOptions {
+    pub fn new() -> Self {
+        Self {
+            _internal: (),
+            min_size: 1024,
+            content_types: [
+                "text/*",
+                "application/javascript",
+                "application/json",
+                "application/wasm",
+                "application/xml",
+                "image/svg+xml",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+            level: 6,
+        }
+    }
+
+    fn allows(&self, content_type: &[u8]) -> bool {
+        let media_type = content_type
+            .split(|&b| b == b';')
+            .next()
+            .unwrap_or_default()
+            .trim_ascii();
+
+        self.content_types
+            .iter()
+            .any(|entry| match entry.strip_suffix('*') {
+                Some(prefix) => media_type
+                    .get(..prefix.len())
+                    .is_some_and(|s| s.eq_ignore_ascii_case(prefix.as_bytes())),
+                None => media_type.eq_ignore_ascii_case(entry.as_bytes()),
+            })
+    }
+}
+
+impl Default for CompressOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Content coding.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Coding {
+    #[cfg(feature = "brotli")]
+    Brotli,
+    Gzip,
+    Deflate,
+}
+
+impl Coding {
+    /// Supported codings in order of preference.
+    const ALL: &'static [Coding] = &[
+        #[cfg(feature = "brotli")]
+        Coding::Brotli,
+        Coding::Gzip,
+        Coding::Deflate,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            #[cfg(feature = "brotli")]
+            Coding::Brotli => "br",
+            Coding::Gzip => "gzip",
+            Coding::Deflate => "deflate",
+        }
+    }
+
+    fn encode(self, data: &[u8], level: u32) -> io::Result<Vec<u8>> {
+        let level = level.min(9);
+
+        match self {
+            #[cfg(feature = "brotli")]
+            Coding::Brotli => {
+                let mut w = brotli::CompressorWriter::new(Vec::new(), 4096, level, 22);
+                w.write_all(data)?;
+                Ok(w.into_inner())
+            }
+
+            Coding::Gzip => {
+                let mut w = GzEncoder::new(Vec::new(), Compression::new(level));
+                w.write_all(data)?;
+                w.finish()
+            }
+
+            Coding::Deflate => {
+                let mut w = DeflateEncoder::new(Vec::new(), Compression::new(level));
+                w.write_all(data)?;
+                w.finish()
+            }
+        }
+    }
+}
+
+/// Middleware which compresses response bodies using a content coding
+/// accepted by the client: gzip or deflate, or br if the brotli feature is
+/// enabled.
+///
+/// Only complete bodies are compressed; streamed bodies, partial content and
+/// responses which already have a Content-Encoding are passed through.  The
+/// body is left as it is if compression doesn't make it smaller.
+///
+/// ```no_run
+/// use gain_listener::http::{Compress, Router, StaticFiles};
+///
+/// let handler = Compress::new(Router::new().fallback(StaticFiles::new()));
+/// ```
+pub struct Compress<H> {
+    handler: H,
+    options: Rc<CompressOptions>,
+}
+
+impl<H> Compress<H> {
+    /// Compress responses of the handler with default options.
+    pub fn new(handler: H) -> Self {
+        Self::with_options(handler, CompressOptions::new())
+    }
+
+    pub fn with_options(handler: H, options: CompressOptions) -> Self {
+        Self {
+            handler,
+            options: Rc::new(options),
+        }
+    }
+}
+
+impl<H: Handler + 'static> Handler for Compress<H> {
+    fn call(&self, req: Request) -> ResponseFuture {
+        let coding = negotiate(&req.headers);
+        let resp = self.handler.call(req);
+        let options = self.options.clone();
+
+        Box::pin(async move {
+            let mut resp = resp.await;
+            compress(&mut resp, coding, &options);
+            resp
+        })
+    }
+}
+
+/// Choose the preferred coding among the ones with the highest quality
+/// value.
+fn negotiate(headers: &Headers) -> Option<Coding> {
+    let mut best: Option<(Coding, u32)> = None;
+
+    for &coding in Coding::ALL {
+        let q = quality(headers, coding.name());
+
+        if q > 0 && best.is_none_or(|(_, best_q)| q > best_q) {
+            best = Some((coding, q));
+        }
+    }
+
+    best.map(|(coding, _)| coding)
+}
+
+/// Quality value of a coding in Accept-Encoding, in thousandths.
+fn quality(headers: &Headers, name: &str) -> u32 {
+    let mut wildcard = None;
+
+    for value in headers.get_all("Accept-Encoding") {
+        for item in value.split(|&b| b == b',') {
+            let mut params = item.split(|&b| b == b';');
+            let token = params.next().unwrap_or_default().trim_ascii();
+
+            let q = params
+                .filter_map(|param| param.trim_ascii().strip_prefix(b"q="))
+                .find_map(parse_quality)
+                .unwrap_or(1000);
+
+            if token.eq_ignore_ascii_case(name.as_bytes()) {
+                return q;
+            }
+            if token == b"*" {
+                wildcard = Some(q);
+            }
+        }
+    }
+
+    wildcard.unwrap_or(0)
+}
+
+fn parse_quality(value: &[u8]) -> Option<u32> {
+    let s = std::str::from_utf8(value).ok()?;
+    let q: f32 = s.trim().parse().ok()?;
+
+    if (0.0..=1.0).contains(&q) {
+        Some((q * 1000.0).round() as u32)
+    } else {
+        None
+    }
+}
+
+fn compress(resp: &mut Response, coding: Option<Coding>, options: &CompressOptions) {
+    if resp.is_streamed()
+        || resp.is_bodiless()
+        || resp.status == 206
+        || resp.headers.contains("Content-Encoding")
+        || resp.body().len() < options.min_size
+    {
+        return;
+    }
+
+    match resp.headers.get("Content-Type") {
+        Some(content_type) if options.allows(content_type) => {}
+        _ => return,
+    }
+
+    // The representation depends on the request's Accept-Encoding.
+    if !resp.headers.has_token("Vary", "Accept-Encoding") {
+        resp.headers.append("Vary", "Accept-Encoding");
+    }
+
+    let coding = match coding {
+        Some(coding) => coding,
+        None => return,
+    };
+
+    let data = match coding.encode(resp.body(), options.level) {
+        Ok(data) if data.len() < resp.body().len() => data,
+        _ => return,
+    };
+
+    resp.set_body(data);
+    resp.headers.insert("Content-Encoding", coding.name());
+
+    // The compressed body is a different representation.
+    if let Some(etag) = resp.headers.get("ETag") {
+        if !etag.starts_with(b"W/") {
+            let mut weak = b"W/".to_vec();
+            weak.extend_from_slice(etag);
+            resp.headers.insert("ETag", weak);
+        }
+    }
+}
@@ -0,0 +1,277 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::{Headers, Request, Response};
+use std::fmt;
+use std::time::Duration;
+
+/// SameSite attribute of a cookie.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+/// Cookie to be set with a Set-Cookie header field.  Its Display
+/// implementation produces the field value.
+///
+/// The name and value are not encoded: the name must be a token, and the
+/// value must not contain whitespace, double quotes, commas, semicolons or
+/// backslashes.
+#[derive(Clone, Debug)]
+pub struct Cookie {
+    _internal: (),
+
+    pub name: String,
+    pub value: String,
+
+    pub path: Option<String>,
+    pub domain: Option<String>,
+
+    /// How long the cookie is kept.  It's a session cookie if not set.
+    pub max_age: Option<Duration>,
+
+    /// Send the cookie only over HTTPS.
+    pub secure: bool,
+
+    /// Don't expose the cookie to scripts.
+    pub http_only: bool,
+
+    pub same_site: Option<SameSite>,
+}
+
+impl Cookie {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            _internal: (),
+            name: name.into(),
+            value: value.into(),
+            path: None,
+            domain: None,
+            max_age: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+        }
+    }
+
+    /// Cookie which removes a previously set cookie from the client.  The
+    /// path and domain must match those of the original cookie.
+    pub fn removal(name: impl Into<String>) -> Self {
+        Self::new(name, "").with_max_age(Duration::ZERO)
+    }
+
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    pub fn with_secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    pub fn with_http_only(mut self, http_only: bool) -> Self {
+        self.http_only = http_only;
+        self
+    }
+
+    pub fn with_same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+}
+
+impl fmt::Display for Cookie {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)?;
+
+        if let Some(path) = &self.path {
+            write!(f, "; Path={}", path)?;
+        }
+        if let Some(domain) = &self.domain {
+            write!(f, "; Domain={}", domain)?;
+        }
+        if let Some(max_age) = self.max_age {
+            write!(f, "; Max-Age={}", max_age.as_secs())?;
+        }
+        if self.secure {
+            f.write_str("; Secure")?;
+        }
+        if self.http_only {
+            f.write_str("; HttpOnly")?;
+        }
+        match self.same_site {
+            Some(SameSite::Strict) => f.write_str("; SameSite=Strict")?,
+            Some(SameSite::Lax) => f.write_str("; SameSite=Lax")?,
+            Some(SameSite::None) => f.write_str("; SameSite=None")?,
+            None => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// Cookies received with a request, and changes to be sent with the
+/// response.
+///
+/// ```no_run
+/// use gain_listener::http::{Cookie, CookieJar, Request, Response};
+///
+/// async fn visit(req: Request) -> Response {
+///     let mut jar = CookieJar::from_request(&req);
+///
+///     let count = jar
+///         .get("visits")
+///         .and_then(|s| s.parse::<u32>().ok())
+///         .unwrap_or(0)
+///         + 1;
+///
+///     jar.add(Cookie::new("visits", count.to_string()).with_http_only(true));
+///
+///     jar.apply(Response::text(200, format!("Visit #{}\n", count)))
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CookieJar {
+    cookies: Vec<(String, String)>,
+    changes: Vec<Cookie>,
+}
+
+impl CookieJar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cookies from the request's Cookie header fields.
+    pub fn from_request(req: &Request) -> Self {
+        Self::from_headers(&req.headers)
+    }
+
+    /// Cookies from Cookie header fields.  Malformed pairs are skipped;
+    /// double quotes around values are removed.
+    pub fn from_headers(headers: &Headers) -> Self {
+        let mut cookies = Vec::new();
+
+        for value in headers.get_all("Cookie") {
+            let value = String::from_utf8_lossy(value);
+
+            for pair in value.split(';') {
+                if let Some((name, value)) = pair.split_once('=') {
+                    let name = name.trim();
+                    let value = value.trim();
+                    let value = value
+                        .strip_prefix('"')
+                        .and_then(|v| v.strip_suffix('"'))
+                        .unwrap_or(value);
+
+                    if !name.is_empty() {
+                        cookies.push((name.to_string(), value.to_string()));
+                    }
+                }
+            }
+        }
+
+        Self {
+            cookies,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Current value of a cookie, taking changes into account.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        if let Some(cookie) = self.changes.iter().rev().find(|c| c.name == name) {
+            if cookie.max_age == Some(Duration::ZERO) {
+                return None;
+            }
+            return Some(&cookie.value);
+        }
+
+        self.cookies
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Names and values of the received cookies.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.cookies.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+
+    /// Set a cookie in the response.
+    pub fn add(&mut self, cookie: Cookie) {
+        self.changes.push(cookie);
+    }
+
+    /// Remove a cookie from the client.  The path and domain must match
+    /// those of the original cookie.  See `Cookie::removal`.
+    pub fn remove(&mut self, mut cookie: Cookie) {
+        cookie.value.clear();
+        cookie.max_age = Some(Duration::ZERO);
+        self.add(cookie);
+    }
+
+    /// Cookies which will be set in the response.
+    pub fn changes(&self) -> &[Cookie] {
+        &self.changes
+    }
+
+    /// Add Set-Cookie header fields for the changes.
+    pub fn write(&self, headers: &mut Headers) {
+        for cookie in &self.changes {
+            headers.append("Set-Cookie", cookie.to_string());
+        }
+    }
+
+    /// Add Set-Cookie header fields for the changes to the response.
+    pub fn apply(&self, mut resp: Response) -> Response {
+        self.write(&mut resp.headers);
+        resp
+    }
+
+    /// Value of a signed cookie.  None is returned if the cookie doesn't
+    /// exist or its signature isn't valid for the key.
+    #[cfg(feature = "signed-cookies")]
+    pub fn get_signed(&self, name: &str, key: &[u8]) -> Option<&str> {
+        let (value, signature) = self.get(name)?.rsplit_once('.')?;
+
+        if constant_time_eq(signature.as_bytes(), sign(name, value, key).as_bytes()) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Set a cookie whose value is signed with the key (HMAC-SHA256), so
+    /// that the client can't change it.  The value is not encrypted.
+    #[cfg(feature = "signed-cookies")]
+    pub fn add_signed(&mut self, mut cookie: Cookie, key: &[u8]) {
+        let signature = sign(&cookie.name, &cookie.value, key);
+        cookie.value = format!("{}.{}", cookie.value, signature);
+        self.add(cookie);
+    }
+}
+
+/// Signature of a cookie's name and value, in hexadecimal.
+#[cfg(feature = "signed-cookies")]
+fn sign(name: &str, value: &str, key: &[u8]) -> String {
+    let mac = hmac_sha256::HMAC::mac(format!("{}={}", name, value), key);
+    mac.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(feature = "signed-cookies")]
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
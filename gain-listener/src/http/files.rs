@@ -0,0 +1,78 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::{Handler, Request, Response, ResponseFuture};
+use std::collections::HashMap;
+use std::future::ready;
+
+/// Handler which serves files embedded in the program, e.g. with
+/// `include_bytes!`.  Each request path maps to a Content-Type and data.
+///
+/// GET and HEAD requests are supported.  A request for a path which ends
+/// with a slash is served the path's index.html file.  Unknown paths get a
+/// 404 response.
+///
+/// ```no_run
+/// use gain_listener::http::StaticFiles;
+///
+/// let files = StaticFiles::new()
+///     .file("/index.html", "text/html; charset=utf-8", b"<b>Hello</b>\n")
+///     .file("/robots.txt", "text/plain", b"User-agent: *\nDisallow:\n");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StaticFiles {
+    files: HashMap<String, (&'static str, &'static [u8])>,
+}
+
+impl StaticFiles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Files from a table of paths, Content-Types and data.
+    pub fn from_table(table: &[(&str, &'static str, &'static [u8])]) -> Self {
+        table
+            .iter()
+            .fold(Self::new(), |files, &(path, content_type, data)| {
+                files.file(path, content_type, data)
+            })
+    }
+
+    /// Add or replace a file.  The path must start with a slash.
+    pub fn file(
+        mut self,
+        path: impl Into<String>,
+        content_type: &'static str,
+        data: &'static [u8],
+    ) -> Self {
+        self.files.insert(path.into(), (content_type, data));
+        self
+    }
+
+    /// Content-Type and data of a file.
+    pub fn get(&self, path: &str) -> Option<(&'static str, &'static [u8])> {
+        if path.ends_with('/') {
+            self.files.get(&format!("{}index.html", path)).copied()
+        } else {
+            self.files.get(path).copied()
+        }
+    }
+
+    fn respond(&self, req: &Request) -> Response {
+        if req.method != "GET" && req.method != "HEAD" {
+            return Response::error(405).with_header("Allow", "GET, HEAD");
+        }
+
+        match self.get(&req.path) {
+            Some((content_type, data)) => Response::new(200).with_static_body(content_type, data),
+            None => Response::error(404),
+        }
+    }
+}
+
+impl Handler for StaticFiles {
+    fn call(&self, req: Request) -> ResponseFuture {
+        Box::pin(ready(self.respond(&req)))
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use std::borrow::Cow;
+use std::io;
+use std::str::FromStr;
+
+/// Name-value pairs decoded from a query string or an
+/// application/x-www-form-urlencoded body.  Names may be repeated.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Form {
+    pairs: Vec<(String, String)>,
+}
+
+impl Form {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode urlencoded data.  Empty pairs are skipped, a pair without an
+    /// equals sign has an empty value, plus signs are decoded as spaces, and
+    /// invalid UTF-8 is replaced with U+FFFD.
+    pub fn parse(data: &[u8]) -> Self {
+        let pairs = data
+            .split(|&b| b == b'&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (name, value) = match pair.iter().position(|&b| b == b'=') {
+                    Some(i) => (&pair[..i], &pair[i + 1..]),
+                    None => (pair, &b""[..]),
+                };
+                (decode_component(name), decode_component(value))
+            })
+            .collect();
+
+        Self { pairs }
+    }
+
+    /// First value of a field.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.pairs
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// All values of a field.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.pairs
+            .iter()
+            .filter(move |(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Parse the first value of a field.  None is returned if the field is
+    /// missing; an `io::ErrorKind::InvalidData` error if parsing fails.
+    pub fn parse_field<T: FromStr>(&self, name: &str) -> Option<io::Result<T>> {
+        self.get(name).map(|value| {
+            value.parse().map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid value for field {}", name),
+                )
+            })
+        })
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+}
+
+/// Decode a form name or value.
+fn decode_component(data: &[u8]) -> String {
+    let data: Cow<[u8]> = if data.contains(&b'+') {
+        Cow::Owned(
+            data.iter()
+                .map(|&b| if b == b'+' { b' ' } else { b })
+                .collect(),
+        )
+    } else {
+        Cow::Borrowed(data)
+    };
+
+    String::from_utf8_lossy(&percent_decode(&data)).into_owned()
+}
+
+/// Decode %XX escapes.  Invalid escapes are left as they are.
+pub fn percent_decode(data: &[u8]) -> Cow<'_, [u8]> {
+    if !data.contains(&b'%') {
+        return Cow::Borrowed(data);
+    }
+
+    let mut out = Vec::with_capacity(data.len());
+    let mut i = 0;
+
+    while i < data.len() {
+        if data[i] == b'%' {
+            if let (Some(hi), Some(lo)) = (
+                data.get(i + 1).and_then(|&b| hex_value(b)),
+                data.get(i + 2).and_then(|&b| hex_value(b)),
+            ) {
+                out.push(hi << 4 | lo);
+                i += 3;
+                continue;
+            }
+        }
+
+        out.push(data[i]);
+        i += 1;
+    }
+
+    Cow::Owned(out)
+}
+
+fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Deserialize urlencoded data.
+#[cfg(feature = "form-serde")]
+pub(crate) fn deserialize<T: serde::de::DeserializeOwned>(data: &[u8]) -> io::Result<T> {
+    serde_urlencoded::from_bytes(data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
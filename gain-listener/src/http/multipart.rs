@@ -0,0 +1,559 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::{Headers, Request};
+use futures_io::AsyncRead;
+use std::future::poll_fn;
+use std::io;
+use std::pin::Pin;
+
+/// Amount of body data read at a time.
+const READ_SIZE: usize = 8192;
+
+/// Multipart parsing limits.
+#[derive(Clone, Debug)]
+pub struct MultipartOptions {
+    _internal: (),
+
+    /// Maximum size of a part's body.
+    pub max_part_size: u64,
+
+    /// Maximum size of a part's header.
+    pub max_header_size: usize,
+
+    /// Maximum number of header fields per part.
+    pub max_headers: usize,
+
+    /// Maximum number of parts.
+    pub max_parts: usize,
+}
+
+impl MultipartOptions {
+    pub fn new() -> Self {
+        Self {
+            _internal: (),
+            max_part_size: 16 << 20,
+            max_header_size: 8192,
+            max_headers: 16,
+            max_parts: 100,
+        }
+    }
+}
+
+impl Default for MultipartOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+enum State {
+    /// Before the first delimiter.
+    Preamble,
+
+    /// After a delimiter: either the rest of the delimiter line or the close
+    /// delimiter.
+    Boundary,
+
+    Headers,
+    Data,
+    Done,
+}
+
+/// Streaming multipart/form-data parser.  Parts are read one at a time
+/// directly from the request body.
+///
+/// ```no_run
+/// use gain_listener::http::{Multipart, Request, Response};
+///
+/// async fn upload(mut req: Request) -> Response {
+///     let mut multipart = match Multipart::new(&mut req) {
+///         Ok(multipart) => multipart,
+///         Err(_) => return Response::error(415),
+///     };
+///
+///     let mut total = 0;
+///
+///     while let Ok(Some(mut part)) = multipart.next_part().await {
+///         let mut buf = [0; 4096];
+///         while let Ok(n) = part.read(&mut buf).await {
+///             if n == 0 {
+///                 break;
+///             }
+///             total += n;
+///         }
+///     }
+///
+///     Response::text(200, format!("{} bytes\n", total))
+/// }
+/// ```
+pub struct Multipart<'a> {
+    body: &'a mut (dyn AsyncRead + Unpin),
+    options: MultipartOptions,
+    delimiter: Vec<u8>,
+    buf: Vec<u8>,
+    state: State,
+    parts: usize,
+}
+
+impl<'a> Multipart<'a> {
+    /// Parse the request body with default limits.  See `with_options`.
+    pub fn new(req: &'a mut Request) -> io::Result<Self> {
+        Self::with_options(req, MultipartOptions::new())
+    }
+
+    /// Parse the request body.  An `io::ErrorKind::InvalidData` error is
+    /// returned if the request isn't multipart/form-data or its boundary is
+    /// missing.
+    pub fn with_options(req: &'a mut Request, options: MultipartOptions) -> io::Result<Self> {
+        let content_type = req.headers.get_str("Content-Type").unwrap_or_default();
+        Self::from_body(&mut req.body, content_type, options)
+    }
+
+    fn from_body(
+        body: &'a mut (dyn AsyncRead + Unpin),
+        content_type: &str,
+        options: MultipartOptions,
+    ) -> io::Result<Self> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+        if !media_type.eq_ignore_ascii_case("multipart/form-data") {
+            return Err(invalid("request body is not multipart/form-data"));
+        }
+
+        let boundary = match header_param(content_type, "boundary") {
+            Some(boundary) if !boundary.is_empty() && boundary.len() <= 70 => boundary,
+            _ => return Err(invalid("invalid multipart boundary")),
+        };
+
+        let mut delimiter = b"\r\n--".to_vec();
+        delimiter.extend_from_slice(boundary.as_bytes());
+
+        Ok(Self {
+            body,
+            options,
+            delimiter,
+            // The first delimiter may be at the start of the body.
+            buf: b"\r\n".to_vec(),
+            state: State::Preamble,
+            parts: 0,
+        })
+    }
+
+    /// Get the next part.  The unread body of the previous part is skipped.
+    /// None is returned after the last part.
+    pub async fn next_part(&mut self) -> io::Result<Option<Part<'_, 'a>>> {
+        loop {
+            match self.state {
+                State::Preamble => match find(&self.buf, &self.delimiter) {
+                    Some(i) => {
+                        self.buf.drain(..i + self.delimiter.len());
+                        self.state = State::Boundary;
+                    }
+                    None => {
+                        let keep = self.delimiter.len() - 1;
+                        if self.buf.len() > keep {
+                            self.buf.drain(..self.buf.len() - keep);
+                        }
+                        self.fill().await?;
+                    }
+                },
+
+                State::Boundary => {
+                    if self.buf.starts_with(b"--") {
+                        self.state = State::Done;
+                        continue;
+                    }
+
+                    match self.buf.iter().position(|&b| b == b'\n') {
+                        Some(i) => {
+                            // Transport padding may precede the line break.
+                            if !self.buf[..i]
+                                .iter()
+                                .all(|&b| b == b' ' || b == b'\t' || b == b'\r')
+                            {
+                                return Err(invalid("invalid multipart delimiter"));
+                            }
+                            self.buf.drain(..i + 1);
+                            self.state = State::Headers;
+                        }
+                        None if self.buf.len() > 1024 => {
+                            return Err(invalid("invalid multipart delimiter"));
+                        }
+                        None => self.fill().await?,
+                    }
+                }
+
+                State::Headers => {
+                    if self.parts == self.options.max_parts {
+                        return Err(invalid("too many multipart parts"));
+                    }
+
+                    match self.parse_headers()? {
+                        Some(headers) => {
+                            self.parts += 1;
+                            self.state = State::Data;
+                            return Ok(Some(Part::new(self, headers)));
+                        }
+                        None => self.fill().await?,
+                    }
+                }
+
+                State::Data => {
+                    let mut buf = [0; 1024];
+                    while self.read_data(&mut buf).await? > 0 {}
+                }
+
+                State::Done => return Ok(None),
+            }
+        }
+    }
+
+    /// Parse a part header from the buffer.  Returns None if it's incomplete.
+    fn parse_headers(&mut self) -> io::Result<Option<Headers>> {
+        let mut fields = vec![httparse::EMPTY_HEADER; self.options.max_headers];
+
+        let (n, headers) = match httparse::parse_headers(&self.buf, &mut fields) {
+            Ok(httparse::Status::Complete((n, parsed))) => {
+                let mut headers = Headers::new();
+                for field in parsed.iter() {
+                    headers.append(field.name, field.value);
+                }
+                (n, headers)
+            }
+
+            Ok(httparse::Status::Partial) => {
+                if self.buf.len() >= self.options.max_header_size {
+                    return Err(invalid("multipart header is too large"));
+                }
+                return Ok(None);
+            }
+
+            Err(_) => return Err(invalid("invalid multipart header")),
+        };
+
+        if n > self.options.max_header_size {
+            return Err(invalid("multipart header is too large"));
+        }
+
+        self.buf.drain(..n);
+        Ok(Some(headers))
+    }
+
+    /// Read body data of the current part.  Returns 0 at its end.
+    async fn read_data(&mut self, dest: &mut [u8]) -> io::Result<usize> {
+        loop {
+            if !matches!(self.state, State::Data) || dest.is_empty() {
+                return Ok(0);
+            }
+
+            // Data before a potential partial delimiter at the end of the
+            // buffer can be returned.
+            let (available, end) = match find(&self.buf, &self.delimiter) {
+                Some(i) => (i, true),
+                None => (
+                    self.buf.len().saturating_sub(self.delimiter.len() - 1),
+                    false,
+                ),
+            };
+
+            if available > 0 {
+                let n = available.min(dest.len());
+                dest[..n].copy_from_slice(&self.buf[..n]);
+                self.buf.drain(..n);
+                return Ok(n);
+            }
+
+            if end {
+                self.buf.drain(..self.delimiter.len());
+                self.state = State::Boundary;
+                return Ok(0);
+            }
+
+            self.fill().await?;
+        }
+    }
+
+    /// Read more of the request body into the buffer.
+    async fn fill(&mut self) -> io::Result<()> {
+        let len = self.buf.len();
+        self.buf.resize(len + READ_SIZE, 0);
+
+        let dest = &mut self.buf[len..];
+
+        let n = match poll_fn(|cx| Pin::new(&mut *self.body).poll_read(cx, dest)).await {
+            Ok(n) => n,
+            Err(e) => {
+                self.buf.truncate(len);
+                return Err(e);
+            }
+        };
+
+        self.buf.truncate(len + n);
+
+        if n == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "multipart body ended unexpectedly",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Part of a multipart body.
+pub struct Part<'m, 'a> {
+    multipart: &'m mut Multipart<'a>,
+    size: u64,
+
+    pub headers: Headers,
+}
+
+impl<'m, 'a> Part<'m, 'a> {
+    fn new(multipart: &'m mut Multipart<'a>, headers: Headers) -> Self {
+        Self {
+            multipart,
+            size: 0,
+            headers,
+        }
+    }
+
+    /// Form field name from Content-Disposition.
+    pub fn name(&self) -> Option<String> {
+        self.disposition_param("name")
+    }
+
+    /// Original file name from Content-Disposition, if the part is a file.
+    pub fn filename(&self) -> Option<String> {
+        self.disposition_param("filename")
+    }
+
+    pub fn content_type(&self) -> Option<&str> {
+        self.headers.get_str("Content-Type")
+    }
+
+    fn disposition_param(&self, name: &str) -> Option<String> {
+        header_param(self.headers.get_str("Content-Disposition")?, name)
+    }
+
+    /// Read some of the part's body.  Returns 0 at its end.  An
+    /// `io::ErrorKind::InvalidData` error is returned if the part exceeds the
+    /// size limit.
+    pub async fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.multipart.read_data(buf).await?;
+
+        self.size += n as u64;
+        if self.size > self.multipart.options.max_part_size {
+            return Err(invalid("multipart part is too large"));
+        }
+
+        Ok(n)
+    }
+
+    /// Read the rest of the part's body into memory.  An
+    /// `io::ErrorKind::InvalidData` error is returned if it is larger than
+    /// `limit` bytes.
+    pub async fn read_to_end(&mut self, limit: usize) -> io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        let mut buf = [0; 4096];
+
+        loop {
+            let n = self.read(&mut buf).await?;
+            if n == 0 {
+                return Ok(data);
+            }
+            if data.len() + n > limit {
+                return Err(invalid("multipart part is too large"));
+            }
+            data.extend_from_slice(&buf[..n]);
+        }
+    }
+}
+
+/// Find a parameter in a header field value like `form-data; name="x"`.
+/// The parameter name is case-insensitive.  Quoted values are unescaped.
+fn header_param(value: &str, name: &str) -> Option<String> {
+    // Skip the media type or disposition type.
+    let mut rest = &value[value.find(';')? + 1..];
+
+    loop {
+        rest = rest.trim_start_matches([' ', '\t', ';']);
+        if rest.is_empty() {
+            return None;
+        }
+
+        let eq = rest.find(['=', ';']).unwrap_or(rest.len());
+        let param = rest[..eq].trim();
+
+        let value = if rest[eq..].starts_with('=') {
+            rest = rest[eq + 1..].trim_start();
+
+            if let Some(quoted) = rest.strip_prefix('"') {
+                let mut s = String::new();
+                let mut chars = quoted.char_indices();
+                let mut end = quoted.len();
+
+                while let Some((i, c)) = chars.next() {
+                    match c {
+                        '"' => {
+                            end = i + 1;
+                            break;
+                        }
+                        '\\' => {
+                            if let Some((_, c)) = chars.next() {
+                                s.push(c);
+                            }
+                        }
+                        c => s.push(c),
+                    }
+                }
+
+                rest = &quoted[end..];
+                s
+            } else {
+                let end = rest.find(';').unwrap_or(rest.len());
+                let s = rest[..end].trim().to_string();
+                rest = &rest[end..];
+                s
+            }
+        } else {
+            rest = &rest[eq..];
+            String::new()
+        };
+
+        if param.eq_ignore_ascii_case(name) {
+            return Some(value);
+        }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::Future;
+    use std::task::{Context, Poll, Waker};
+
+    const CONTENT_TYPE: &str = "multipart/form-data; boundary=xyz";
+
+    /// Body which is received `step` bytes at a time.
+    struct Chunks<'d> {
+        data: &'d [u8],
+        step: usize,
+    }
+
+    impl AsyncRead for Chunks<'_> {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _: &mut Context,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            let n = self.data.len().min(self.step).min(buf.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Poll::Ready(Ok(n))
+        }
+    }
+
+    /// The body is always ready, so the future completes without waiting.
+    fn ready<T>(future: impl Future<Output = T>) -> T {
+        match std::pin::pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(x) => x,
+            Poll::Pending => panic!("future is pending"),
+        }
+    }
+
+    /// Parse all parts into names and bodies.
+    fn parse(data: &[u8], step: usize) -> io::Result<Vec<(Option<String>, Vec<u8>)>> {
+        let mut body = Chunks { data, step };
+        let mut multipart = Multipart::from_body(&mut body, CONTENT_TYPE, MultipartOptions::new())?;
+        let mut parts = Vec::new();
+
+        ready(async {
+            while let Some(mut part) = multipart.next_part().await? {
+                let data = part.read_to_end(1024).await?;
+                parts.push((part.name(), data));
+            }
+            Ok(parts)
+        })
+    }
+
+    #[test]
+    fn parse_parts() {
+        let data = b"preamble\r\n--xyz\r\n\
+            Content-Disposition: form-data; name=\"a\"\r\n\r\n\
+            first\r\n-- xyz\r\n\
+            --xyz  \r\n\
+            Content-Disposition: form-data; name=b; filename=\"b.txt\"\r\n\
+            Content-Type: text/plain\r\n\r\n\
+            \r\n--xy\r\n\
+            --xyz--\r\nepilogue";
+
+        for step in [1, 5, data.len()] {
+            let parts = parse(data, step).unwrap();
+            assert_eq!(
+                parts,
+                [
+                    (Some("a".to_string()), b"first\r\n-- xyz".to_vec()),
+                    (Some("b".to_string()), b"\r\n--xy".to_vec()),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn parse_delimiter_at_start() {
+        let data = b"--xyz\r\n\r\nbody\r\n--xyz--";
+        assert_eq!(parse(data, 3).unwrap(), [(None, b"body".to_vec())]);
+    }
+
+    #[test]
+    fn skip_unread_part() {
+        let data = b"--xyz\r\n\r\nskipped\r\n--xyz\r\n\r\nread\r\n--xyz--";
+        let mut body = Chunks { data, step: 2 };
+        let mut multipart =
+            Multipart::from_body(&mut body, CONTENT_TYPE, MultipartOptions::new()).unwrap();
+
+        ready(async {
+            multipart.next_part().await.unwrap().unwrap();
+            let mut part = multipart.next_part().await.unwrap().unwrap();
+            assert_eq!(part.read_to_end(1024).await.unwrap(), b"read");
+            assert!(multipart.next_part().await.unwrap().is_none());
+        });
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for data in [
+            &b"--xyz\r\n\r\ntruncated"[..],
+            b"--xyz junk\r\n\r\n\r\n--xyz--",
+            b"--xyz\r\nBad Header\r\n\r\n\r\n--xyz--",
+        ] {
+            assert!(parse(data, 4).is_err());
+        }
+
+        let mut body = Chunks { data: b"", step: 1 };
+        for content_type in ["text/plain; boundary=xyz", "multipart/form-data"] {
+            assert!(
+                Multipart::from_body(&mut body, content_type, MultipartOptions::new()).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn header_params() {
+        let value = r#"form-data; name="a\"b"; filename=c.txt; flag"#;
+        assert_eq!(header_param(value, "NAME").as_deref(), Some("a\"b"));
+        assert_eq!(header_param(value, "filename").as_deref(), Some("c.txt"));
+        assert_eq!(header_param(value, "flag").as_deref(), Some(""));
+        assert_eq!(header_param(value, "missing"), None);
+        assert_eq!(header_param("form-data", "name"), None);
+    }
+}
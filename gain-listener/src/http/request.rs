@@ -0,0 +1,652 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::chunked::ChunkedDecoder;
+use super::form::Form;
+use super::{Headers, ServerOptions};
+use crate::recv::RecvBuf;
+use crate::{ConnInfo, OwnedReadHalf};
+use futures_io::AsyncRead;
+use std::cell::RefCell;
+use std::future::poll_fn;
+use std::io;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::str;
+use std::task::{ready, Context, Poll};
+
+const FILL_SIZE: usize = 8192;
+
+/// HTTP protocol version of a request.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Version {
+    Http10,
+    Http11,
+}
+
+/// HTTP request.
+pub struct Request {
+    _internal: (),
+
+    /// Request method, e.g. GET.  Methods are case-sensitive.
+    pub method: String,
+
+    /// Path component of the request target, e.g. `/index.html`.
+    pub path: String,
+
+    /// Query component of the request target (without the question mark),
+    /// if there is one.
+    pub query: Option<String>,
+
+    pub version: Version,
+    pub headers: Headers,
+
+    /// Information about the connection which the request was received on.
+    pub conn: ConnInfo,
+
+    pub body: Body,
+
+    pub(crate) params: Vec<(String, String)>,
+}
+
+impl Request {
+    /// The request target as it appeared on the request line.
+    pub fn target(&self) -> String {
+        match &self.query {
+            Some(query) => format!("{}?{}", self.path, query),
+            None => self.path.clone(),
+        }
+    }
+
+    /// Value of a path parameter matched by a `Router` route, e.g. `id` for
+    /// the pattern `/users/:id`.  The value is not percent-decoded; see
+    /// `percent_decode`.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Request method is HEAD.
+    pub fn is_head(&self) -> bool {
+        self.method == "HEAD"
+    }
+
+    /// Decode the query string.
+    pub fn query_form(&self) -> Form {
+        Form::parse(self.query.as_deref().unwrap_or_default().as_bytes())
+    }
+
+    /// Deserialize the query string.
+    #[cfg(feature = "form-serde")]
+    pub fn query_as<T: serde::de::DeserializeOwned>(&self) -> io::Result<T> {
+        super::form::deserialize(self.query.as_deref().unwrap_or_default().as_bytes())
+    }
+
+    /// Read and decode an application/x-www-form-urlencoded body.  An
+    /// `io::ErrorKind::InvalidData` error is returned if the request has a
+    /// different Content-Type, or if the body is larger than `limit` bytes.
+    pub async fn read_form(&mut self, limit: usize) -> io::Result<Form> {
+        Ok(Form::parse(&self.read_form_data(limit).await?))
+    }
+
+    /// Read and deserialize an application/x-www-form-urlencoded body.  See
+    /// `read_form`.
+    #[cfg(feature = "form-serde")]
+    pub async fn read_form_as<T: serde::de::DeserializeOwned>(
+        &mut self,
+        limit: usize,
+    ) -> io::Result<T> {
+        super::form::deserialize(&self.read_form_data(limit).await?)
+    }
+
+    async fn read_form_data(&mut self, limit: usize) -> io::Result<Vec<u8>> {
+        let media_type = self
+            .headers
+            .get_str("Content-Type")
+            .and_then(|s| s.split(';').next())
+            .map(str::trim);
+
+        if !media_type.is_some_and(|s| s.eq_ignore_ascii_case("application/x-www-form-urlencoded"))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "request body is not a urlencoded form",
+            ));
+        }
+
+        self.body.read_to_end(limit).await
+    }
+}
+
+/// Buffered reading half of a connection, shared by the server and request
+/// bodies.
+pub(crate) struct Input {
+    reader: OwnedReadHalf,
+    buf: Vec<u8>,
+    pos: usize,
+    seq: u64,
+    framing: Framing,
+}
+
+/// How the end of a request body is determined.
+enum Framing {
+    Length(u64),
+    Chunked(ChunkedDecoder),
+}
+
+pub(crate) type SharedInput = Rc<RefCell<Input>>;
+
+impl Input {
+    pub(crate) fn new(reader: OwnedReadHalf) -> SharedInput {
+        Rc::new(RefCell::new(Self {
+            reader,
+            buf: Vec::new(),
+            pos: 0,
+            seq: 0,
+            framing: Framing::Length(0),
+        }))
+    }
+
+    /// Take the connection and the data which has been received but not
+    /// consumed.
+    pub(crate) fn into_parts(mut self) -> (OwnedReadHalf, Vec<u8>) {
+        self.buf.drain(..self.pos);
+        (self.reader, self.buf)
+    }
+
+    /// Amount of the current request's body which hasn't been read, if
+    /// known.
+    pub(crate) fn body_remaining(&self) -> Option<u64> {
+        match &self.framing {
+            Framing::Length(n) => Some(*n),
+            Framing::Chunked(d) if d.is_done() => Some(0),
+            Framing::Chunked(_) => None,
+        }
+    }
+
+    /// Data which has been received but not consumed.
+    fn buffered(&self) -> &[u8] {
+        &self.buf[self.pos..]
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.pos += n;
+
+        if self.pos == self.buf.len() {
+            self.buf.clear();
+            self.pos = 0;
+        }
+    }
+
+    /// Receive up to `max` more bytes into the buffer.  Returns 0 at end of
+    /// stream.
+    fn poll_fill(&mut self, cx: &mut Context, max: usize) -> Poll<io::Result<usize>> {
+        if self.pos > 0 {
+            self.buf.drain(..self.pos);
+            self.pos = 0;
+        }
+
+        let dest = &mut self.buf;
+
+        self.reader.reader.poll_read_with(cx, |src: &mut RecvBuf| {
+            let n = src.len().min(max);
+            dest.extend_from_slice(&src.as_slice()[..n]);
+            src.consume(n);
+            n
+        })
+    }
+}
+
+/// Reasons for not getting a request.
+pub(crate) enum HeadError {
+    /// The client closed the connection or sent something which isn't
+    /// answered.
+    Io(io::Error),
+
+    /// The client should get an error response with the status code.
+    Status(u16),
+}
+
+impl From<io::Error> for HeadError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Receive and parse a request header.  None is returned if the client
+/// closes the connection before sending anything.  A client whose header is
+/// too large gets 431.
+pub(crate) async fn read_request(
+    input: &SharedInput,
+    conn: &ConnInfo,
+    options: &ServerOptions,
+) -> Result<Option<Request>, HeadError> {
+    loop {
+        {
+            let mut i = input.borrow_mut();
+            let data = i.buffered();
+
+            if let Some((head, framing, n)) = parse_head(data, options.max_headers)? {
+                let seq = i.seq + 1;
+
+                let body = Body {
+                    input: input.clone(),
+                    seq,
+                    length: match framing {
+                        Framing::Length(n) => Some(n),
+                        Framing::Chunked(_) => None,
+                    },
+                };
+
+                let req = Request {
+                    _internal: (),
+                    method: head.method,
+                    path: head.path,
+                    query: head.query,
+                    version: head.version,
+                    headers: head.headers,
+                    conn: conn.clone(),
+                    body,
+                    params: Vec::new(),
+                };
+
+                i.consume(n);
+                i.seq = seq;
+                i.framing = framing;
+                return Ok(Some(req));
+            }
+
+            if i.buffered().len() >= options.max_header_size {
+                return Err(HeadError::Status(431));
+            }
+        }
+
+        let n = poll_fn(|cx| {
+            let mut i = input.borrow_mut();
+            let max = options.max_header_size - i.buffered().len();
+            i.poll_fill(cx, max)
+        })
+        .await?;
+
+        if n == 0 {
+            if input.borrow().buffered().is_empty() {
+                return Ok(None);
+            }
+            return Err(HeadError::Io(io::ErrorKind::UnexpectedEof.into()));
+        }
+    }
+}
+
+/// Request line and header fields.
+struct Head {
+    method: String,
+    path: String,
+    query: Option<String>,
+    version: Version,
+    headers: Headers,
+}
+
+/// Returns the request header, its body framing and the size of the header,
+/// or None if the header is incomplete.
+fn parse_head(
+    data: &[u8],
+    max_headers: usize,
+) -> Result<Option<(Head, Framing, usize)>, HeadError> {
+    let mut fields = vec![httparse::EMPTY_HEADER; max_headers];
+    let mut parsed = httparse::Request::new(&mut fields);
+
+    let n = match parsed.parse(data) {
+        Ok(httparse::Status::Complete(n)) => n,
+        Ok(httparse::Status::Partial) => return Ok(None),
+        Err(httparse::Error::TooManyHeaders) => return Err(HeadError::Status(431)),
+        Err(_) => return Err(HeadError::Status(400)),
+    };
+
+    let version = match parsed.version {
+        Some(0) => Version::Http10,
+        Some(1) => Version::Http11,
+        _ => return Err(HeadError::Status(505)),
+    };
+
+    let target = parsed.path.unwrap_or_default();
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), Some(query.to_string())),
+        None => (target.to_string(), None),
+    };
+
+    let mut headers = Headers::new();
+    for field in parsed.headers.iter() {
+        headers.append(field.name, field.value);
+    }
+
+    let framing = framing(&headers)?;
+
+    let head = Head {
+        method: parsed.method.unwrap_or_default().to_string(),
+        path,
+        query,
+        version,
+        headers,
+    };
+
+    Ok(Some((head, framing, n)))
+}
+
+/// Determine how the body is delimited.  Transfer codings other than chunked
+/// are not supported.
+fn framing(headers: &Headers) -> Result<Framing, HeadError> {
+    if headers.contains("Transfer-Encoding") {
+        let codings: Vec<&[u8]> = headers
+            .get_all("Transfer-Encoding")
+            .flat_map(|value| value.split(|&b| b == b','))
+            .map(|coding| coding.trim_ascii())
+            .filter(|coding| !coding.is_empty())
+            .collect();
+
+        if !codings.iter().all(|c| c.eq_ignore_ascii_case(b"chunked")) {
+            return Err(HeadError::Status(501));
+        }
+
+        // A message with both is suspicious (request smuggling).
+        if codings.len() != 1 || headers.contains("Content-Length") {
+            return Err(HeadError::Status(400));
+        }
+
+        return Ok(Framing::Chunked(ChunkedDecoder::new()));
+    }
+
+    let mut length = None;
+
+    for value in headers.get_all("Content-Length") {
+        let n = str::from_utf8(value)
+            .ok()
+            .filter(|s| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|s| s.parse::<u64>().ok())
+            .ok_or(HeadError::Status(400))?;
+
+        if length.is_some_and(|len| len != n) {
+            return Err(HeadError::Status(400));
+        }
+        length = Some(n);
+    }
+
+    Ok(Framing::Length(length.unwrap_or(0)))
+}
+
+/// Request body.  It is received from the connection while it is being
+/// read; a chunked body is decoded.  After the response has been sent, the
+/// server discards the rest of the body (or closes the connection if too
+/// much remains).
+pub struct Body {
+    input: SharedInput,
+    seq: u64,
+    length: Option<u64>,
+}
+
+impl Body {
+    /// Amount of body data which hasn't been read, if known.  Nothing
+    /// remains once the server has moved on to the next request.
+    fn remaining(&self) -> Option<u64> {
+        let input = self.input.borrow();
+
+        if input.seq == self.seq {
+            input.body_remaining()
+        } else {
+            Some(0)
+        }
+    }
+
+    /// Size of the body as declared by the client with Content-Length.  None
+    /// if the body is chunked.
+    pub fn len(&self) -> Option<u64> {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == Some(0)
+    }
+
+    /// The whole body has been read.
+    pub fn is_finished(&self) -> bool {
+        self.remaining() == Some(0)
+    }
+
+    /// Read part of the body.  Returns 0 at the end of the body.  An
+    /// `io::ErrorKind::UnexpectedEof` error is returned if the client closes
+    /// the connection before sending the whole body.
+    pub async fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        poll_fn(|cx| self.poll_read_body(cx, buf)).await
+    }
+
+    /// Read the rest of the body into memory.  An `io::ErrorKind::InvalidData`
+    /// error is returned if the body is larger than `limit` bytes; if its
+    /// size is known, nothing is read in that case.
+    pub async fn read_to_end(&mut self, limit: usize) -> io::Result<Vec<u8>> {
+        let too_large = || io::Error::new(io::ErrorKind::InvalidData, "request body is too large");
+
+        let mut data = Vec::new();
+
+        if let Some(remaining) = self.remaining() {
+            if remaining > limit as u64 {
+                return Err(too_large());
+            }
+            data.reserve_exact(remaining as usize);
+        }
+
+        let mut buf = [0; 4096];
+
+        loop {
+            let n = self.read(&mut buf).await?;
+            if n == 0 {
+                return Ok(data);
+            }
+            if data.len() + n > limit {
+                return Err(too_large());
+            }
+            data.extend_from_slice(&buf[..n]);
+        }
+    }
+
+    /// Read and discard the rest of the body.
+    pub async fn discard(&mut self) -> io::Result<()> {
+        let mut buf = [0; 1024];
+        while self.read(&mut buf).await? > 0 {}
+        Ok(())
+    }
+
+    fn poll_read_body(&mut self, cx: &mut Context, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let mut input = self.input.borrow_mut();
+
+        if input.seq != self.seq {
+            return Poll::Ready(Ok(0));
+        }
+
+        input.poll_read_body(cx, buf)
+    }
+}
+
+impl Input {
+    /// Read part of the current request's body.
+    fn poll_read_body(&mut self, cx: &mut Context, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        match &mut self.framing {
+            Framing::Length(remaining) => {
+                if *remaining == 0 {
+                    return Poll::Ready(Ok(0));
+                }
+
+                if self.pos == self.buf.len() {
+                    let max = (*remaining).min(buf.len() as u64) as usize;
+                    if ready!(self.poll_fill(cx, max))? == 0 {
+                        return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+                    }
+                }
+
+                let Framing::Length(remaining) = &mut self.framing else {
+                    unreachable!()
+                };
+
+                let available = &self.buf[self.pos..];
+                let n = (available.len().min(buf.len()) as u64).min(*remaining) as usize;
+
+                buf[..n].copy_from_slice(&available[..n]);
+                *remaining -= n as u64;
+                self.consume(n);
+
+                Poll::Ready(Ok(n))
+            }
+
+            Framing::Chunked(_) => loop {
+                let Framing::Chunked(decoder) = &mut self.framing else {
+                    unreachable!()
+                };
+
+                let (consumed, n) = decoder.decode(&self.buf[self.pos..], buf)?;
+                let done = decoder.is_done();
+                self.consume(consumed);
+
+                if n > 0 || done {
+                    return Poll::Ready(Ok(n));
+                }
+
+                if ready!(self.poll_fill(cx, FILL_SIZE))? == 0 {
+                    return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+                }
+            },
+        }
+    }
+}
+
+/// Read and discard the rest of the current request's body, unless more than
+/// `limit` bytes remain.  Returns true if the body was finished.
+pub(crate) async fn finish_body(input: &SharedInput, limit: u64) -> io::Result<bool> {
+    let mut buf = [0; 1024];
+    let mut total = 0;
+
+    loop {
+        let n = poll_fn(|cx| input.borrow_mut().poll_read_body(cx, &mut buf)).await?;
+        if n == 0 {
+            return Ok(true);
+        }
+
+        total += n as u64;
+        if total > limit {
+            return Ok(false);
+        }
+    }
+}
+
+impl AsyncRead for Body {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().poll_read_body(cx, buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(data: &[u8]) -> Result<Option<(Head, Framing, usize)>, u16> {
+        parse_head(data, 10).map_err(|e| match e {
+            HeadError::Status(status) => status,
+            HeadError::Io(e) => panic!("{}", e),
+        })
+    }
+
+    fn framing_of(fields: &[(&str, &str)]) -> Result<Option<u64>, u16> {
+        let mut headers = Headers::new();
+        for (name, value) in fields {
+            headers.append(*name, *value);
+        }
+
+        match framing(&headers) {
+            Ok(Framing::Length(n)) => Ok(Some(n)),
+            Ok(Framing::Chunked(_)) => Ok(None),
+            Err(HeadError::Status(status)) => Err(status),
+            Err(HeadError::Io(e)) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn parse_complete_head() {
+        let data = b"GET /a/b?x=1 HTTP/1.1\r\nHost: example.net\r\nContent-Length: 3\r\n\r\nabc";
+
+        let (head, framing, n) = parse(data).unwrap().unwrap();
+        assert_eq!(head.method, "GET");
+        assert_eq!(head.path, "/a/b");
+        assert_eq!(head.query.as_deref(), Some("x=1"));
+        assert_eq!(head.version, Version::Http11);
+        assert_eq!(head.headers.get_str("host"), Some("example.net"));
+        assert!(matches!(framing, Framing::Length(3)));
+        assert_eq!(n, data.len() - 3);
+    }
+
+    #[test]
+    fn parse_partial_head() {
+        let data = b"POST / HTTP/1.0\r\nHost: example.net\r\n\r\n";
+
+        for n in 0..data.len() {
+            assert!(parse(&data[..n]).unwrap().is_none());
+        }
+
+        let (head, _, _) = parse(data).unwrap().unwrap();
+        assert_eq!(head.version, Version::Http10);
+        assert_eq!(head.query, None);
+    }
+
+    #[test]
+    fn parse_invalid_head() {
+        assert_eq!(
+            parse(b"GET / HTTP/1.1\r\nBad Name: x\r\n\r\n").err(),
+            Some(400)
+        );
+        assert_eq!(parse(b"GET / HTTP/2.0\r\n\r\n").err(), Some(400));
+
+        let mut data = b"GET / HTTP/1.1\r\n".to_vec();
+        for i in 0..11 {
+            data.extend_from_slice(format!("X-{}: y\r\n", i).as_bytes());
+        }
+        data.extend_from_slice(b"\r\n");
+        assert_eq!(parse(&data).err(), Some(431));
+    }
+
+    #[test]
+    fn content_length_framing() {
+        assert_eq!(framing_of(&[]), Ok(Some(0)));
+        assert_eq!(framing_of(&[("Content-Length", "42")]), Ok(Some(42)));
+        assert_eq!(
+            framing_of(&[("Content-Length", "5"), ("Content-Length", "5")]),
+            Ok(Some(5))
+        );
+        assert_eq!(
+            framing_of(&[("Content-Length", "5"), ("Content-Length", "6")]),
+            Err(400)
+        );
+        assert_eq!(framing_of(&[("Content-Length", "+5")]), Err(400));
+        assert_eq!(framing_of(&[("Content-Length", "")]), Err(400));
+    }
+
+    #[test]
+    fn transfer_encoding_framing() {
+        assert_eq!(framing_of(&[("Transfer-Encoding", "Chunked")]), Ok(None));
+        assert_eq!(framing_of(&[("Transfer-Encoding", "gzip")]), Err(501));
+        assert_eq!(
+            framing_of(&[("Transfer-Encoding", "chunked, chunked")]),
+            Err(400)
+        );
+        assert_eq!(
+            framing_of(&[("Transfer-Encoding", "chunked"), ("Content-Length", "5")]),
+            Err(400)
+        );
+    }
+}
@@ -0,0 +1,312 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::upgrade::UpgradeFn;
+use super::writer::BodyStream;
+use super::{BodyWriter, Cookie, Headers, Upgraded};
+use futures_io::AsyncRead;
+use std::borrow::Cow;
+use std::fmt;
+use std::future::Future;
+use std::io::Write as _;
+use std::pin::Pin;
+
+/// HTTP response.
+pub struct Response {
+    _internal: (),
+
+    /// Status code, e.g. 200.
+    pub status: u16,
+
+    /// Header fields.  Content-Length, Transfer-Encoding and Connection are
+    /// set by the server, but a `Connection: close` field makes the server
+    /// close the connection after the response.
+    pub headers: Headers,
+
+    content: Content,
+    upgrade: Option<UpgradeFn>,
+}
+
+pub(crate) enum Content {
+    Full(Cow<'static, [u8]>),
+    Reader(Pin<Box<dyn AsyncRead>>),
+    Writer(BodyStream),
+}
+
+impl Response {
+    /// Response with an empty body.
+    pub fn new(status: u16) -> Self {
+        Self {
+            _internal: (),
+            status,
+            headers: Headers::new(),
+            content: Content::Full(Cow::Borrowed(&[])),
+            upgrade: None,
+        }
+    }
+
+    /// Response with a plain text body.
+    pub fn text(status: u16, body: impl Into<String>) -> Self {
+        Self::new(status).with_body("text/plain; charset=utf-8", body.into())
+    }
+
+    /// Response with an HTML body.
+    pub fn html(status: u16, body: impl Into<String>) -> Self {
+        Self::new(status).with_body("text/html; charset=utf-8", body.into())
+    }
+
+    /// Redirection to another location, e.g. with status 302.
+    pub fn redirect(status: u16, location: &str) -> Self {
+        Self::html(
+            status,
+            format!("Go to <a href=\"{0}\">{0}</a>\n", html_escape(location)),
+        )
+        .with_header("Location", location)
+    }
+
+    /// Response with a short HTML body which describes the status.
+    pub fn error(status: u16) -> Self {
+        Self::html(
+            status,
+            format!("<h1>Error</h1> <code>{}</code>\n", reason_phrase(status)),
+        )
+    }
+
+    /// Add a header field.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<Vec<u8>>) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    /// Add a Set-Cookie header field.
+    pub fn with_cookie(self, cookie: &Cookie) -> Self {
+        self.with_header("Set-Cookie", cookie.to_string())
+    }
+
+    /// Set the body and its Content-Type.
+    pub fn with_body(mut self, content_type: &str, body: impl Into<Vec<u8>>) -> Self {
+        self.headers.insert("Content-Type", content_type);
+        self.content = Content::Full(Cow::Owned(body.into()));
+        self
+    }
+
+    /// Set a body which is not copied, and its Content-Type.
+    pub fn with_static_body(mut self, content_type: &str, body: &'static [u8]) -> Self {
+        self.headers.insert("Content-Type", content_type);
+        self.content = Content::Full(Cow::Borrowed(body));
+        self
+    }
+
+    /// Set a body which is streamed from a reader, and its Content-Type.  It
+    /// is sent using chunked transfer coding, or by closing the connection
+    /// after it if the client doesn't support HTTP/1.1.
+    pub fn with_reader(mut self, content_type: &str, reader: impl AsyncRead + 'static) -> Self {
+        self.headers.insert("Content-Type", content_type);
+        self.content = Content::Reader(Box::pin(reader));
+        self
+    }
+
+    /// Set a body which is streamed from a writer, and its Content-Type.  The
+    /// function is called with the writer, and the returned future is run by
+    /// the server task while it sends the body, after the handler has
+    /// returned the response.  The body is framed like with `with_reader`,
+    /// and it may be followed by trailer fields.
+    ///
+    /// ```no_run
+    /// use gain_listener::http::Response;
+    ///
+    /// let resp = Response::new(200).with_writer("text/plain", |mut w| async move {
+    ///     if w.write(b"Hello, world\n").await.is_ok() {
+    ///         w.finish();
+    ///     }
+    /// });
+    /// ```
+    pub fn with_writer<F, R>(mut self, content_type: &str, f: F) -> Self
+    where
+        F: FnOnce(BodyWriter) -> R,
+        R: Future<Output = ()> + 'static,
+    {
+        self.headers.insert("Content-Type", content_type);
+        self.content = Content::Writer(BodyWriter::start(f));
+        self
+    }
+
+    /// Switch the connection to another protocol after this response.  The
+    /// status should be 101 and the Upgrade header should be set; the server
+    /// sets `Connection: Upgrade`.  The function is called with the
+    /// connection after the response has been sent, and the connection is
+    /// closed when the returned future completes.
+    ///
+    /// The connection can't be upgraded (it is closed instead) if the request
+    /// body is still referenced when the response has been sent, or if too
+    /// much of it is unread.  The upgrade is ignored if the status isn't 101.
+    pub fn with_upgrade<F, R>(mut self, f: F) -> Self
+    where
+        F: FnOnce(Upgraded) -> R + 'static,
+        R: Future<Output = ()> + 'static,
+    {
+        self.upgrade = Some(Box::new(move |upgraded| Box::pin(f(upgraded))));
+        self
+    }
+
+    /// Body data.  It is empty if the body is streamed.
+    pub fn body(&self) -> &[u8] {
+        match &self.content {
+            Content::Full(body) => body,
+            Content::Reader(_) | Content::Writer(_) => &[],
+        }
+    }
+
+    /// Replace the body.  Content-Type is not changed.
+    pub fn set_body(&mut self, body: impl Into<Vec<u8>>) {
+        self.content = Content::Full(Cow::Owned(body.into()));
+    }
+
+    /// The body is streamed from a reader.
+    pub fn is_streamed(&self) -> bool {
+        !matches!(self.content, Content::Full(_))
+    }
+
+    /// Take the protocol switch function, if the response switches
+    /// protocols.
+    pub(crate) fn take_upgrade(&mut self) -> Option<UpgradeFn> {
+        if self.status == 101 {
+            self.upgrade.take()
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn into_content(self) -> Content {
+        self.content
+    }
+
+    /// Status code doesn't allow a body.
+    pub(crate) fn is_bodiless(&self) -> bool {
+        matches!(self.status, 100..=199 | 204 | 304)
+    }
+
+    /// Status line and header fields, including the terminating empty line.
+    /// A streamed body is framed with chunked transfer coding if `chunked` is
+    /// true, or by closing the connection otherwise.
+    pub(crate) fn encode_head(&self, connection: Option<&str>, chunked: bool) -> Vec<u8> {
+        let mut head = Vec::new();
+
+        let _ = write!(
+            head,
+            "HTTP/1.1 {} {}\r\n",
+            self.status,
+            reason_phrase(self.status)
+        );
+
+        for (name, value) in self.headers.iter() {
+            if name.eq_ignore_ascii_case("Content-Length")
+                || name.eq_ignore_ascii_case("Transfer-Encoding")
+                || name.eq_ignore_ascii_case("Connection")
+            {
+                continue;
+            }
+
+            head.extend_from_slice(name.as_bytes());
+            head.extend_from_slice(b": ");
+            head.extend_from_slice(value);
+            head.extend_from_slice(b"\r\n");
+        }
+
+        if !self.is_bodiless() {
+            match &self.content {
+                Content::Full(body) => {
+                    let _ = write!(head, "Content-Length: {}\r\n", body.len());
+                }
+                Content::Reader(_) | Content::Writer(_) if chunked => {
+                    head.extend_from_slice(b"Transfer-Encoding: chunked\r\n");
+                }
+                Content::Reader(_) | Content::Writer(_) => {}
+            }
+        }
+
+        if let Some(value) = connection {
+            let _ = write!(head, "Connection: {}\r\n", value);
+        }
+
+        head.extend_from_slice(b"\r\n");
+        head
+    }
+}
+
+impl fmt::Debug for Response {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Response");
+        s.field("status", &self.status);
+        s.field("headers", &self.headers);
+        match &self.content {
+            Content::Full(body) => s.field("body", body),
+            Content::Reader(_) => s.field("body", &"<reader>"),
+            Content::Writer(_) => s.field("body", &"<writer>"),
+        };
+        s.field("upgrade", &self.upgrade.is_some());
+        s.finish()
+    }
+}
+
+/// Standard reason phrase for a status code.  An empty string is returned
+/// for unknown codes.
+pub fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        100 => "Continue",
+        101 => "Switching Protocols",
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        206 => "Partial Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        303 => "See Other",
+        304 => "Not Modified",
+        307 => "Temporary Redirect",
+        308 => "Permanent Redirect",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        409 => "Conflict",
+        410 => "Gone",
+        411 => "Length Required",
+        413 => "Content Too Large",
+        414 => "URI Too Long",
+        415 => "Unsupported Media Type",
+        416 => "Range Not Satisfiable",
+        417 => "Expectation Failed",
+        425 => "Too Early",
+        426 => "Upgrade Required",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        505 => "HTTP Version Not Supported",
+        _ => "",
+    }
+}
+
+fn html_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+
+    out
+}
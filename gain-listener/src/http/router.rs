@@ -0,0 +1,169 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::{Handler, Request, Response, ResponseFuture};
+
+enum Segment {
+    Literal(String),
+    Param(String),
+    Rest(String),
+}
+
+struct Route {
+    method: String,
+    pattern: Vec<Segment>,
+    handler: Box<dyn Handler>,
+}
+
+impl Route {
+    /// Match the path and return the parameters.
+    fn matches(&self, path: &str) -> Option<Vec<(String, String)>> {
+        let mut params = Vec::new();
+        let mut parts = path.split('/');
+
+        for segment in &self.pattern {
+            match segment {
+                Segment::Rest(name) => {
+                    let rest: Vec<&str> = parts.collect();
+                    params.push((name.clone(), rest.join("/")));
+                    return Some(params);
+                }
+
+                Segment::Literal(s) => {
+                    if parts.next()? != s {
+                        return None;
+                    }
+                }
+
+                Segment::Param(name) => {
+                    let part = parts.next().filter(|p| !p.is_empty())?;
+                    params.push((name.clone(), part.to_string()));
+                }
+            }
+        }
+
+        match parts.next() {
+            None => Some(params),
+            Some(_) => None,
+        }
+    }
+}
+
+/// Request handler which dispatches requests by method and path.
+///
+/// Path patterns consist of segments separated by slashes.  A segment which
+/// starts with a colon (e.g. `/users/:id`) matches any non-empty segment, and
+/// a final segment which starts with an asterisk (e.g. `/files/*path`)
+/// matches the rest of the path.  Matched values are available through
+/// `Request::param`.  Routes are tried in the order in which they were added.
+///
+/// If the path matches a route but the method doesn't, the response has
+/// status 405 and an Allow header.  HEAD requests are routed to GET routes
+/// unless there is a HEAD route.  Requests which match no route are passed to
+/// the fallback handler, or get a 404 response.
+#[derive(Default)]
+pub struct Router {
+    routes: Vec<Route>,
+    fallback: Option<Box<dyn Handler>>,
+}
+
+impl Router {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a route for a method and a path pattern.
+    pub fn route<H>(mut self, method: &str, pattern: &str, handler: H) -> Self
+    where
+        H: Handler + 'static,
+    {
+        let pattern = pattern
+            .split('/')
+            .map(|s| {
+                if let Some(name) = s.strip_prefix(':') {
+                    Segment::Param(name.to_string())
+                } else if let Some(name) = s.strip_prefix('*') {
+                    Segment::Rest(name.to_string())
+                } else {
+                    Segment::Literal(s.to_string())
+                }
+            })
+            .collect();
+
+        self.routes.push(Route {
+            method: method.to_string(),
+            pattern,
+            handler: Box::new(handler),
+        });
+        self
+    }
+
+    pub fn get<H: Handler + 'static>(self, pattern: &str, handler: H) -> Self {
+        self.route("GET", pattern, handler)
+    }
+
+    pub fn post<H: Handler + 'static>(self, pattern: &str, handler: H) -> Self {
+        self.route("POST", pattern, handler)
+    }
+
+    pub fn put<H: Handler + 'static>(self, pattern: &str, handler: H) -> Self {
+        self.route("PUT", pattern, handler)
+    }
+
+    pub fn delete<H: Handler + 'static>(self, pattern: &str, handler: H) -> Self {
+        self.route("DELETE", pattern, handler)
+    }
+
+    /// Handle requests which don't match any route.
+    pub fn fallback<H: Handler + 'static>(mut self, handler: H) -> Self {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Handler for Router {
+    fn call(&self, mut req: Request) -> ResponseFuture {
+        let mut allowed: Vec<&str> = Vec::new();
+        let mut get = None;
+
+        for route in &self.routes {
+            let params = match route.matches(&req.path) {
+                Some(params) => params,
+                None => continue,
+            };
+
+            if route.method == req.method {
+                req.params = params;
+                return route.handler.call(req);
+            }
+
+            if req.is_head() && route.method == "GET" && get.is_none() {
+                get = Some((route, params));
+            }
+
+            if !allowed.contains(&route.method.as_str()) {
+                allowed.push(&route.method);
+            }
+        }
+
+        if let Some((route, params)) = get {
+            req.params = params;
+            return route.handler.call(req);
+        }
+
+        if !allowed.is_empty() {
+            if allowed.contains(&"GET") && !allowed.contains(&"HEAD") {
+                allowed.push("HEAD");
+            }
+
+            let resp = Response::error(405).with_header("Allow", allowed.join(", "));
+            return Box::pin(async move { resp });
+        }
+
+        match &self.fallback {
+            Some(handler) => handler.call(req),
+            None => Box::pin(async { Response::error(404) }),
+        }
+    }
+}
@@ -0,0 +1,416 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::chunked::{encode_chunk, encode_last_chunk};
+use super::request::{finish_body, read_request, HeadError, Input};
+use super::response::Content;
+use super::upgrade::Upgraded;
+use super::{Handler, Headers, Response, Version};
+use crate::{AcceptError, AcceptErrorKind, Conn, Listener, OwnedWriteHalf};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::future::{poll_fn, Future};
+use std::io;
+use std::pin::{pin, Pin};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{ready, Context, Poll, Wake, Waker};
+
+/// Amount of unread request body which is discarded to keep a connection
+/// open.
+const MAX_DISCARD: u64 = 65536;
+
+/// Maximum amount of streamed response body read at a time.
+const STREAM_BUF_SIZE: usize = 16384;
+
+/// HTTP server options.
+#[derive(Clone, Debug)]
+pub struct ServerOptions {
+    _internal: (),
+
+    /// Serve multiple requests per connection, if the client supports it.
+    pub keep_alive: bool,
+
+    /// Maximum number of requests served per connection.  The connection is
+    /// closed after the response to the last one.
+    pub max_requests: usize,
+
+    /// Maximum size of a request header in bytes, including the request
+    /// line.  Larger requests get a 431 response.
+    pub max_header_size: usize,
+
+    /// Maximum number of header fields in a request.  Requests with more
+    /// get a 431 response.
+    pub max_headers: usize,
+}
+
+impl ServerOptions {
+    pub fn new() -> Self {
+        Self {
+            _internal: (),
+            keep_alive: true,
+            max_requests: 100,
+            max_header_size: 16384,
+            max_headers: 100,
+        }
+    }
+}
+
+impl Default for ServerOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Accept connections and serve them concurrently with default options.  See
+/// `serve_http_with`.
+pub async fn serve_http<H>(listener: Listener, handler: H) -> Result<(), AcceptError>
+where
+    H: Handler + 'static,
+{
+    serve_http_with(listener, ServerOptions::new(), handler).await
+}
+
+/// Accept connections and serve them concurrently.  The connections are
+/// polled by the returned future instead of being spawned as tasks, so it
+/// can be run in a spawned task.  Returns when the listener is closed and
+/// the connections have been served.  Connections which fail during
+/// handshake or are rejected are skipped; other accept errors are returned
+/// (after the connections have been served).
+pub async fn serve_http_with<H>(
+    mut listener: Listener,
+    options: ServerOptions,
+    handler: H,
+) -> Result<(), AcceptError>
+where
+    H: Handler + 'static,
+{
+    let options = Rc::new(options);
+    let handler = Rc::new(handler);
+    let mut conns = ConnSet::default();
+
+    let result = loop {
+        let accepted = {
+            let mut accept = pin!(listener.accept());
+
+            poll_fn(|cx| {
+                let _ = conns.poll(cx);
+                accept.as_mut().poll(cx)
+            })
+            .await
+        };
+
+        let conn = match accepted {
+            Ok(conn) => conn,
+            Err(e) => match e.kind() {
+                AcceptErrorKind::Closed => break Ok(()),
+                AcceptErrorKind::HandshakeFailed
+                | AcceptErrorKind::PolicyRejected
+                | AcceptErrorKind::ResourceExhausted => continue,
+                _ => break Err(e),
+            },
+        };
+
+        let options = options.clone();
+        let handler = handler.clone();
+
+        conns.push(async move {
+            let _ = serve_conn_with(conn, &options, &*handler).await;
+        });
+    };
+
+    poll_fn(|cx| conns.poll(cx)).await;
+    result
+}
+
+/// Connections which are being served by a single future.  Only connections
+/// which have been woken are polled.
+#[derive(Default)]
+struct ConnSet {
+    conns: Vec<Served>,
+}
+
+struct Served {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    waker: Arc<ConnWaker>,
+}
+
+impl ConnSet {
+    fn push(&mut self, future: impl Future<Output = ()> + 'static) {
+        let waker = Arc::new(ConnWaker {
+            woken: AtomicBool::new(true),
+            parent: Waker::noop().clone(),
+        });
+        self.conns.push(Served {
+            future: Box::pin(future),
+            waker,
+        });
+    }
+
+    /// Ready when all connections have been served.
+    fn poll(&mut self, cx: &mut Context) -> Poll<()> {
+        let mut i = 0;
+
+        while i < self.conns.len() {
+            let conn = &mut self.conns[i];
+
+            if conn.waker.woken.swap(false, Ordering::Relaxed) {
+                conn.waker = Arc::new(ConnWaker {
+                    woken: AtomicBool::new(false),
+                    parent: cx.waker().clone(),
+                });
+
+                let waker = Waker::from(conn.waker.clone());
+                if conn
+                    .future
+                    .as_mut()
+                    .poll(&mut Context::from_waker(&waker))
+                    .is_ready()
+                {
+                    self.conns.swap_remove(i);
+                    continue;
+                }
+            }
+
+            i += 1;
+        }
+
+        if self.conns.is_empty() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Marks a connection to be polled and wakes the serving future.
+struct ConnWaker {
+    woken: AtomicBool,
+    parent: Waker,
+}
+
+impl Wake for ConnWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Relaxed);
+        self.parent.wake_by_ref();
+    }
+}
+
+/// Serve requests received on the connection with default options.  See
+/// `serve_conn_with`.
+pub async fn serve_conn<H>(conn: Conn, handler: &H) -> io::Result<()>
+where
+    H: Handler + ?Sized,
+{
+    serve_conn_with(conn, &ServerOptions::new(), handler).await
+}
+
+/// Serve requests received on the connection until it is closed.  Malformed
+/// requests are answered with an error status without invoking the handler.
+///
+/// The connection is kept open after a response if keep-alive is enabled,
+/// the client didn't ask for the connection to be closed (HTTP/1.1) or asked
+/// for it to be kept alive (HTTP/1.0), the response doesn't have a
+/// `Connection: close` header, and not too much of the request body was left
+/// unread.  A streamed response body is sent using chunked transfer coding;
+/// HTTP/1.0 connections are closed after it.
+pub async fn serve_conn_with<H>(conn: Conn, options: &ServerOptions, handler: &H) -> io::Result<()>
+where
+    H: Handler + ?Sized,
+{
+    let info = conn.info();
+    let (reader, mut writer) = conn.into_split();
+    let input = Input::new(reader);
+    let mut served = 0;
+
+    loop {
+        let req = match read_request(&input, &info, options).await {
+            Ok(Some(req)) => req,
+
+            Ok(None) => return close(&mut writer).await,
+
+            Err(HeadError::Io(e)) => return Err(e),
+
+            Err(HeadError::Status(status)) => {
+                let resp = Response::error(status);
+                write_response(&mut writer, resp, false, Some("close"), false).await?;
+                return close(&mut writer).await;
+            }
+        };
+
+        served += 1;
+
+        let persistent = match req.version {
+            Version::Http11 => !req.headers.has_token("Connection", "close"),
+            Version::Http10 => req.headers.has_token("Connection", "keep-alive"),
+        };
+        let mut keep_alive = options.keep_alive && persistent && served < options.max_requests;
+
+        let head = req.is_head();
+        let version = req.version;
+        let mut resp = handler.call(req).await;
+        let upgrade = resp.take_upgrade();
+
+        if resp.headers.has_token("Connection", "close") {
+            keep_alive = false;
+        }
+
+        // The end of a streamed body can be delimited only by closing the
+        // connection if the client doesn't support chunked transfer coding.
+        let chunked = version == Version::Http11;
+        if resp.is_streamed() && !chunked && !head {
+            keep_alive = false;
+        }
+
+        // The rest of the body must be discarded before the next request can
+        // be read.
+        if input
+            .borrow()
+            .body_remaining()
+            .is_some_and(|n| n > MAX_DISCARD)
+        {
+            keep_alive = false;
+        }
+
+        let connection = match (keep_alive, version) {
+            _ if upgrade.is_some() => Some("Upgrade"),
+            (false, _) => Some("close"),
+            (true, Version::Http10) => Some("keep-alive"),
+            (true, Version::Http11) => None,
+        };
+
+        write_response(&mut writer, resp, head, connection, chunked).await?;
+
+        if let Some(upgrade) = upgrade {
+            // The new protocol starts after the request body.
+            if !finish_body(&input, MAX_DISCARD).await? {
+                return close(&mut writer).await;
+            }
+
+            let (reader, buffered) = match Rc::try_unwrap(input) {
+                Ok(input) => input.into_inner().into_parts(),
+                Err(_) => return close(&mut writer).await,
+            };
+
+            upgrade(Upgraded::new(buffered, reader, writer)).await;
+            return Ok(());
+        }
+
+        if !keep_alive {
+            return close(&mut writer).await;
+        }
+
+        // A chunked body may turn out to be too large to discard.
+        if !finish_body(&input, MAX_DISCARD).await? {
+            return close(&mut writer).await;
+        }
+    }
+}
+
+/// Write the status line, header fields and body (unless the request method
+/// was HEAD).  A complete body is written as a single stream write together
+/// with the header.
+async fn write_response(
+    w: &mut OwnedWriteHalf,
+    resp: Response,
+    head: bool,
+    connection: Option<&str>,
+    chunked: bool,
+) -> io::Result<()> {
+    let mut data = resp.encode_head(connection, chunked);
+
+    if head || resp.is_bodiless() {
+        return write_all(w, &data).await;
+    }
+
+    match resp.into_content() {
+        Content::Full(body) => {
+            data.extend_from_slice(&body);
+            write_all(w, &data).await
+        }
+
+        Content::Reader(mut reader) => {
+            w.cork();
+            write_all(w, &data).await?;
+            write_stream(w, reader.as_mut(), chunked).await?;
+            end_stream(w, &Headers::new(), chunked).await
+        }
+
+        Content::Writer(mut stream) => {
+            w.cork();
+            write_all(w, &data).await?;
+            write_stream(w, Pin::new(&mut stream), chunked).await?;
+            end_stream(w, &stream.take_trailers(), chunked).await
+        }
+    }
+}
+
+/// Copy a streamed body from the reader.  The header has been staged on the
+/// corked writer; it is sent together with the first chunk, or as soon as
+/// the reader would block.
+async fn write_stream<R>(
+    w: &mut OwnedWriteHalf,
+    mut reader: Pin<&mut R>,
+    chunked: bool,
+) -> io::Result<()>
+where
+    R: AsyncRead + ?Sized,
+{
+    let mut buf = vec![0; STREAM_BUF_SIZE];
+
+    loop {
+        let n = poll_fn(|cx| match reader.as_mut().poll_read(cx, &mut buf) {
+            Poll::Pending => {
+                ready!(Pin::new(&mut *w).poll_flush(cx))?;
+                Poll::Pending
+            }
+            result => result,
+        })
+        .await?;
+
+        if n == 0 {
+            break;
+        }
+
+        if chunked {
+            write_all(w, &encode_chunk(&buf[..n])).await?;
+        } else {
+            write_all(w, &buf[..n]).await?;
+        }
+
+        w.flush().await?;
+    }
+
+    Ok(())
+}
+
+/// Terminate a streamed body and send the trailer fields, if chunked.
+async fn end_stream(w: &mut OwnedWriteHalf, trailers: &Headers, chunked: bool) -> io::Result<()> {
+    if chunked {
+        write_all(w, &encode_last_chunk(trailers)).await?;
+    }
+
+    w.flush().await
+}
+
+async fn write_all(w: &mut OwnedWriteHalf, data: &[u8]) -> io::Result<()> {
+    let mut buf = data;
+
+    while !buf.is_empty() {
+        match poll_fn(|cx| Pin::new(&mut *w).poll_write(cx, buf)).await? {
+            0 => return Err(io::ErrorKind::WriteZero.into()),
+            n => buf = &buf[n..],
+        }
+    }
+
+    Ok(())
+}
+
+async fn close(w: &mut OwnedWriteHalf) -> io::Result<()> {
+    poll_fn(|cx| Pin::new(&mut *w).poll_close(cx)).await
+}
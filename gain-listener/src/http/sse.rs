@@ -0,0 +1,141 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::{BodyWriter, Response};
+use std::future::Future;
+use std::io;
+use std::time::Duration;
+
+/// Server-sent event.
+#[derive(Clone, Debug, Default)]
+pub struct Event {
+    _internal: (),
+
+    /// Event type.  The client dispatches it as "message" if not set.
+    pub event: Option<String>,
+
+    /// Data, possibly consisting of multiple lines.
+    pub data: String,
+
+    /// Last event ID which the client reports when it reconnects.
+    pub id: Option<String>,
+
+    /// Reconnection delay for the client.
+    pub retry: Option<Duration>,
+}
+
+impl Event {
+    /// Event with data.
+    pub fn new(data: impl Into<String>) -> Self {
+        Self {
+            data: data.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_event(mut self, event: impl Into<String>) -> Self {
+        self.event = Some(event.into());
+        self
+    }
+
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn with_retry(mut self, retry: Duration) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Encode in text/event-stream format.  Line breaks are removed from the
+    /// event type and ID.
+    fn encode(&self) -> String {
+        let mut s = String::new();
+
+        if let Some(event) = &self.event {
+            push_field(&mut s, "event", event);
+        }
+        if let Some(id) = &self.id {
+            push_field(&mut s, "id", id);
+        }
+        if let Some(retry) = self.retry {
+            push_field(&mut s, "retry", &retry.as_millis().to_string());
+        }
+
+        for line in self.data.split("\r\n").flat_map(|s| s.split(['\r', '\n'])) {
+            s.push_str("data: ");
+            s.push_str(line);
+            s.push('\n');
+        }
+
+        s.push('\n');
+        s
+    }
+}
+
+fn push_field(s: &mut String, name: &str, value: &str) {
+    s.push_str(name);
+    s.push_str(": ");
+    s.extend(value.chars().filter(|&c| c != '\r' && c != '\n'));
+    s.push('\n');
+}
+
+/// Server-sent event stream.  See `EventStream::response`.
+///
+/// When the client disconnects, the future which sends the events is
+/// dropped.  The runtime has no timers, so keep-alive comments aren't sent
+/// automatically; a long-lived stream which may be idle for a while should
+/// send a `comment` whenever its future is woken anyway, so that
+/// intermediaries don't time out the response.
+pub struct EventStream {
+    writer: BodyWriter,
+}
+
+impl EventStream {
+    /// Create a text/event-stream response.  The function is called with the
+    /// stream, and the returned future sends the events; it is run by the
+    /// server task after the handler has returned the response (see
+    /// `Response::with_writer`).
+    ///
+    /// ```no_run
+    /// use gain_listener::http::{Event, EventStream, Request, Response};
+    ///
+    /// async fn events(_: Request) -> Response {
+    ///     EventStream::response(|mut events| async move {
+    ///         if events.send(&Event::new("hello")).await.is_ok() {
+    ///             events.finish();
+    ///         }
+    ///     })
+    /// }
+    /// ```
+    pub fn response<F, R>(f: F) -> Response
+    where
+        F: FnOnce(Self) -> R,
+        R: Future<Output = ()> + 'static,
+    {
+        Response::new(200)
+            .with_header("Cache-Control", "no-cache")
+            .with_writer("text/event-stream", |writer| f(Self { writer }))
+    }
+
+    /// Send an event.
+    pub async fn send(&mut self, event: &Event) -> io::Result<()> {
+        self.writer.write(event.encode().as_bytes()).await
+    }
+
+    /// Send a comment line, which the client ignores.  Line breaks are
+    /// removed.
+    pub async fn comment(&mut self, text: &str) -> io::Result<()> {
+        let mut s = String::from(":");
+        s.extend(text.chars().filter(|&c| c != '\r' && c != '\n'));
+        s.push_str("\n\n");
+        self.writer.write(s.as_bytes()).await
+    }
+
+    /// End the response.
+    pub fn finish(self) {
+        self.writer.finish()
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{OwnedReadHalf, OwnedWriteHalf};
+use std::future::Future;
+use std::pin::Pin;
+
+/// Connection which has been switched to another protocol.  See
+/// `Response::with_upgrade`.
+pub struct Upgraded {
+    _internal: (),
+
+    /// Data which was received after the request, before the protocol
+    /// switch.  It must be processed before reading from the connection.
+    pub buffered: Vec<u8>,
+
+    pub reader: OwnedReadHalf,
+    pub writer: OwnedWriteHalf,
+}
+
+impl Upgraded {
+    pub(crate) fn new(buffered: Vec<u8>, reader: OwnedReadHalf, writer: OwnedWriteHalf) -> Self {
+        Self {
+            _internal: (),
+            buffered,
+            reader,
+            writer,
+        }
+    }
+}
+
+pub(crate) type UpgradeFn = Box<dyn FnOnce(Upgraded) -> Pin<Box<dyn Future<Output = ()>>>>;
@@ -0,0 +1,213 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use super::Headers;
+use futures_io::{AsyncRead, AsyncWrite};
+use std::cell::RefCell;
+use std::future::{poll_fn, Future};
+use std::io;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+/// Future which writes a response body.
+pub(crate) type Producer = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Maximum amount of body data buffered between a writer and the server.
+const PIPE_CAPACITY: usize = 65536;
+
+struct Pipe {
+    data: Vec<u8>,
+    trailers: Headers,
+    finished: bool,
+    writer_dropped: bool,
+    reader_dropped: bool,
+}
+
+/// Writer for a streamed response body.  See `Response::with_writer`.
+///
+/// The data is sent to the client while it is being written.  Writing waits
+/// when the server hasn't caught up.  The writer is driven by the server
+/// task which sends the response, so it may only be used by the future
+/// which it was given to; writing to it elsewhere doesn't make progress.
+/// If the response won't be sent any further (the connection was lost, or
+/// the request method was HEAD), the future is dropped.
+///
+/// The body must be ended by calling `finish` or closing the writer.  If the
+/// writer is dropped without that, the connection is closed without
+/// completing the response, so that the client can tell that the body is
+/// truncated.
+pub struct BodyWriter {
+    pipe: Rc<RefCell<Pipe>>,
+}
+
+impl BodyWriter {
+    /// Create a writer and pass it to the function.  The returned stream
+    /// drives the resulting future while the body is being read.
+    pub(crate) fn start<F, R>(f: F) -> BodyStream
+    where
+        F: FnOnce(Self) -> R,
+        R: Future<Output = ()> + 'static,
+    {
+        let pipe = Rc::new(RefCell::new(Pipe {
+            data: Vec::new(),
+            trailers: Headers::new(),
+            finished: false,
+            writer_dropped: false,
+            reader_dropped: false,
+        }));
+
+        let producer = Box::pin(f(Self { pipe: pipe.clone() }));
+
+        BodyStream {
+            pipe,
+            producer: Some(producer),
+        }
+    }
+
+    /// Write all data.
+    pub async fn write(&mut self, data: &[u8]) -> io::Result<()> {
+        let mut buf = data;
+
+        while !buf.is_empty() {
+            let n = poll_fn(|cx| Pin::new(&mut *self).poll_write(cx, buf)).await?;
+            buf = &buf[n..];
+        }
+
+        Ok(())
+    }
+
+    /// Wait until the server has taken all written data.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_flush(cx)).await
+    }
+
+    /// Set a trailer field which is sent after the body, replacing previous
+    /// values.  Trailer fields are sent only if chunked transfer coding is
+    /// used (the client supports HTTP/1.1).  Content-Length,
+    /// Transfer-Encoding and Connection fields are ignored.
+    pub fn set_trailer(&mut self, name: impl Into<String>, value: impl Into<Vec<u8>>) {
+        self.pipe.borrow_mut().trailers.insert(name, value);
+    }
+
+    /// End the body.  Data which has already been written will still be
+    /// sent.
+    pub fn finish(self) {
+        self.pipe.borrow_mut().finished = true;
+    }
+
+}
+
+impl AsyncWrite for BodyWriter {
+    fn poll_write(self: Pin<&mut Self>, _: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let mut pipe = self.pipe.borrow_mut();
+
+        if pipe.reader_dropped {
+            return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+        }
+
+        if pipe.finished {
+            return Poll::Ready(Err(io::ErrorKind::NotConnected.into()));
+        }
+
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        // The stream polls the writer's future again after it has taken
+        // some data, so no waker is needed.
+        let n = buf.len().min(PIPE_CAPACITY - pipe.data.len());
+        if n == 0 {
+            return Poll::Pending;
+        }
+
+        pipe.data.extend_from_slice(&buf[..n]);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        let pipe = self.pipe.borrow();
+
+        if pipe.data.is_empty() {
+            Poll::Ready(Ok(()))
+        } else if pipe.reader_dropped {
+            Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        self.pipe.borrow_mut().finished = true;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for BodyWriter {
+    fn drop(&mut self) {
+        self.pipe.borrow_mut().writer_dropped = true;
+    }
+}
+
+/// Server side of a body writer.  Reading polls the writer's future in the
+/// reading task.
+pub(crate) struct BodyStream {
+    pipe: Rc<RefCell<Pipe>>,
+    producer: Option<Producer>,
+}
+
+impl BodyStream {
+    /// Trailer fields set by the writer.  Valid after the whole body has
+    /// been read.
+    pub(crate) fn take_trailers(&mut self) -> Headers {
+        std::mem::take(&mut self.pipe.borrow_mut().trailers)
+    }
+}
+
+impl AsyncRead for BodyStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+
+        // The writer's future is pending while the pipe is full or while it
+        // waits for something else, which wakes this task.
+        if let Some(producer) = &mut this.producer {
+            if producer.as_mut().poll(cx).is_ready() {
+                this.producer = None;
+            }
+        }
+
+        let mut pipe = this.pipe.borrow_mut();
+
+        if pipe.data.is_empty() {
+            if pipe.finished || buf.is_empty() {
+                return Poll::Ready(Ok(0));
+            }
+
+            if pipe.writer_dropped {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "response body writer was dropped",
+                )));
+            }
+
+            return Poll::Pending;
+        }
+
+        let n = pipe.data.len().min(buf.len());
+        buf[..n].copy_from_slice(&pipe.data[..n]);
+        pipe.data.drain(..n);
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl Drop for BodyStream {
+    fn drop(&mut self) {
+        self.producer = None;
+        self.pipe.borrow_mut().reader_dropped = true;
+    }
+}
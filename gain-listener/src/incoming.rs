@@ -0,0 +1,45 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{AcceptError, AcceptErrorKind, Acceptor, Conn};
+use futures_core::Stream;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Stream of accepted connections.  See `Acceptor::incoming`.
+pub struct Incoming {
+    acceptor: Option<Acceptor>,
+}
+
+impl Acceptor {
+    /// Convert the acceptor into a stream of connections.  The stream ends
+    /// when the acceptor is closed; other errors are yielded as items.
+    pub fn incoming(self) -> Incoming {
+        Incoming {
+            acceptor: Some(self),
+        }
+    }
+}
+
+impl Stream for Incoming {
+    type Item = Result<Conn, AcceptError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let acc = match self.acceptor.as_mut() {
+            Some(acc) => acc,
+            None => return Poll::Ready(None),
+        };
+
+        match acc.poll_accept(cx) {
+            Poll::Pending => Poll::Pending,
+
+            Poll::Ready(Err(e)) if e.kind() == AcceptErrorKind::Closed => {
+                self.acceptor = None;
+                Poll::Ready(None)
+            }
+
+            Poll::Ready(result) => Poll::Ready(Some(result)),
+        }
+    }
+}
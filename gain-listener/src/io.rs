@@ -0,0 +1,803 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+//! Connection I/O through the `futures_io` traits.
+
+use crate::drive;
+use crate::recv::{Receiver, RecvBuf, RecvOptions};
+use crate::stats::Counters;
+use crate::{Conn, ConnPermit};
+use futures_io::{AsyncRead, AsyncWrite};
+use gain::stream::{future, Close as _, ErrorCode, RecvWriteStream, Write as _, WriteStream};
+use std::future::{poll_fn, Future};
+use std::io::{self, IoSlice, IoSliceMut};
+use std::mem::{self, MaybeUninit};
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{ready, Context, Poll};
+
+const DEFAULT_READ_SIZE: usize = 8192;
+
+type WriteFuture = Pin<Box<dyn Future<Output = (WriteStream, io::Result<()>)>>>;
+
+/// Reading half of a connection.  See `Conn::split`.
+///
+/// A stream error reported by the service (the client reset the connection)
+/// is returned as an `io::ErrorKind::ConnectionReset` error, with the
+/// `gain::stream::ErrorCode` as the inner error.
+pub struct ConnReader {
+    stream: Receiver,
+    counters: Rc<Counters>,
+}
+
+impl AsyncRead for ConnReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let copy = |src: &mut RecvBuf| io::Read::read(src, buf).unwrap_or(0);
+        self.get_mut().poll_read_with(cx, copy)
+    }
+
+    /// Buffered data is scattered across the buffers; a buffer is filled
+    /// before the next one is used.
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        bufs: &mut [IoSliceMut],
+    ) -> Poll<io::Result<usize>> {
+        if bufs.iter().all(|b| b.is_empty()) {
+            return Poll::Ready(Ok(0));
+        }
+
+        let scatter = |src: &mut RecvBuf| {
+            let mut total = 0;
+            for dest in bufs.iter_mut() {
+                let n = io::Read::read(src, dest).unwrap_or(0);
+                total += n;
+                if n < dest.len() {
+                    break;
+                }
+            }
+            total
+        };
+
+        self.get_mut().poll_read_with(cx, scatter)
+    }
+}
+
+impl ConnReader {
+    /// Change the receive chunk size and flow window.
+    pub fn set_recv_options(&mut self, options: RecvOptions) {
+        self.stream.set_options(options);
+    }
+
+    /// Read directly from the internal buffer.  The receptor returns the
+    /// number of bytes it consumed; it isn't called at end of stream.
+    pub(crate) fn poll_read_with<F>(
+        &mut self,
+        cx: &mut Context,
+        receptor: F,
+    ) -> Poll<io::Result<usize>>
+    where
+        F: FnOnce(&mut RecvBuf) -> usize,
+    {
+        let result = self.stream.poll_read_with(cx, receptor);
+        self.finish(result)
+    }
+
+    /// Count the data of a read result.
+    fn finish(&mut self, result: Poll<io::Result<usize>>) -> Poll<io::Result<usize>> {
+        if let Poll::Ready(Ok(n)) = &result {
+            self.counters.add_read(*n);
+        }
+
+        result
+    }
+
+    /// Read into several buffers at once, e.g. a frame header and its
+    /// payload.  Returns 0 at end of stream.
+    pub async fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_read_vectored(cx, bufs)).await
+    }
+
+    /// Read into a buffer which hasn't been initialized.  Returns the number
+    /// of bytes which were initialized at the start of the buffer; 0 means
+    /// end of stream (or an empty buffer).
+    pub async fn read_uninit(&mut self, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        poll_fn(|cx| {
+            let dest = &mut *buf;
+
+            self.poll_read_with(cx, move |src: &mut RecvBuf| {
+                let n = src.len().min(dest.len());
+                for (d, s) in dest.iter_mut().zip(&src.as_slice()[..n]) {
+                    d.write(*s);
+                }
+                src.consume(n);
+                n
+            })
+        })
+        .await
+    }
+
+    /// Append data to the vector without initializing its spare capacity
+    /// first.  At most the spare capacity is read; 8 kB is reserved if there
+    /// is none.  Returns the number of bytes appended; 0 means end of stream.
+    pub async fn read_buf(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        if buf.capacity() == buf.len() {
+            buf.reserve(DEFAULT_READ_SIZE);
+        }
+
+        poll_fn(|cx| {
+            let dest = &mut *buf;
+
+            self.poll_read_with(cx, move |src: &mut RecvBuf| {
+                let n = src.len().min(dest.capacity() - dest.len());
+                dest.extend_from_slice(&src.as_slice()[..n]);
+                src.consume(n);
+                n
+            })
+        })
+        .await
+    }
+}
+
+/// The stream is moved into the write future while data is being written,
+/// because a started write must be polled to completion.
+enum WriteState {
+    Idle(WriteStream),
+    Writing(WriteFuture),
+    Closing(future::Close),
+    Closed,
+}
+
+/// Writing half of a connection.  See `Conn::split`.
+///
+/// A write is reported as complete once the data has been handed to the
+/// runtime; `poll_flush` waits until it has actually been written.  A write
+/// error is reported by the next write, flush or close.  If the writer is
+/// dropped while a write or close is in progress, it is finished in the
+/// background.
+///
+/// Writing after the client has stopped receiving fails with an
+/// `io::ErrorKind::BrokenPipe` error.  A stream error reported by the service
+/// is returned as an `io::ErrorKind::ConnectionReset` error, with the
+/// `gain::stream::ErrorCode` as the inner error.
+pub struct ConnWriter {
+    state: WriteState,
+    staged: Option<Vec<u8>>,
+    counters: Rc<Counters>,
+}
+
+impl ConnWriter {
+    /// Stage subsequent writes in memory instead of writing them to the
+    /// stream.  The staged data is written as a single stream write by the
+    /// next flush (which also ends staging), or by closing.  Staged data is
+    /// lost if the writer is dropped before that.
+    pub fn cork(&mut self) {
+        self.staged.get_or_insert_with(Vec::new);
+    }
+
+    /// Write the staged data (if any) and wait until all data has been
+    /// written.  Ends staging.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        poll_fn(|cx| self.poll_uncork(cx)).await
+    }
+
+    /// Wait for the write in progress (if any) to end, ignoring its result,
+    /// and release the stream.  A started write can't be abandoned.
+    pub(crate) async fn discard(mut self) {
+        if let WriteState::Writing(future) = mem::replace(&mut self.state, WriteState::Closed) {
+            let _ = future.await;
+        }
+    }
+
+    /// Finish the write which is in progress.
+    fn poll_idle(&mut self, cx: &mut Context) -> Poll<io::Result<()>> {
+        if let WriteState::Writing(future) = &mut self.state {
+            let (stream, result) = ready!(future.as_mut().poll(cx));
+            self.state = WriteState::Idle(stream);
+            result?;
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    /// Start writing the staged data, and finish all writes.
+    fn poll_uncork(&mut self, cx: &mut Context) -> Poll<io::Result<()>> {
+        if self.staged.is_some() {
+            ready!(self.poll_idle(cx))?;
+            let data = self.staged.take().unwrap_or_default();
+            self.start_write(cx, data)?;
+        }
+
+        self.poll_idle(cx)
+    }
+
+    /// Start writing a copy of the data, or stage it if corked.  The buffers
+    /// are coalesced into a single stream write.
+    fn poll_write_bufs(&mut self, cx: &mut Context, bufs: &[&[u8]]) -> Poll<io::Result<usize>> {
+        if let Some(staged) = &mut self.staged {
+            if let WriteState::Closing(_) | WriteState::Closed = self.state {
+                return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+            }
+
+            let len = staged.len();
+            for buf in bufs {
+                staged.extend_from_slice(buf);
+            }
+            return Poll::Ready(Ok(staged.len() - len));
+        }
+
+        ready!(self.poll_idle(cx))?;
+        Poll::Ready(self.start_write(cx, bufs.concat()))
+    }
+
+    /// Start writing the data.  Any previous write must have been finished.
+    fn start_write(&mut self, cx: &mut Context, data: Vec<u8>) -> io::Result<usize> {
+        let mut stream = match mem::replace(&mut self.state, WriteState::Closed) {
+            WriteState::Idle(stream) => stream,
+            state => {
+                self.state = state;
+                return Err(io::ErrorKind::BrokenPipe.into());
+            }
+        };
+
+        let len = data.len();
+
+        if len == 0 {
+            self.state = WriteState::Idle(stream);
+            return Ok(0);
+        }
+
+        let counters = self.counters.clone();
+
+        let mut future: WriteFuture = Box::pin(async move {
+            let result = stream.write_all(&data).await.map_err(write_error);
+            if result.is_ok() {
+                counters.add_written(data.len());
+            }
+            (stream, result)
+        });
+
+        // Start writing immediately.
+        match future.as_mut().poll(cx) {
+            Poll::Pending => self.state = WriteState::Writing(future),
+            Poll::Ready((stream, result)) => {
+                self.state = WriteState::Idle(stream);
+                result?;
+            }
+        }
+
+        Ok(len)
+    }
+}
+
+impl AsyncWrite for ConnWriter {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        self.get_mut().poll_write_bufs(cx, &[buf])
+    }
+
+    /// All buffers are written at once.
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        bufs: &[IoSlice],
+    ) -> Poll<io::Result<usize>> {
+        let bufs: Vec<&[u8]> = bufs.iter().map(|b| &**b).collect();
+        self.get_mut().poll_write_bufs(cx, &bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        self.get_mut().poll_uncork(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        ready!(this.poll_uncork(cx))?;
+
+        loop {
+            match mem::replace(&mut this.state, WriteState::Closed) {
+                WriteState::Idle(mut stream) => this.state = WriteState::Closing(stream.close()),
+
+                WriteState::Closing(mut future) => {
+                    if Pin::new(&mut future).poll(cx).is_pending() {
+                        this.state = WriteState::Closing(future);
+                        return Poll::Pending;
+                    }
+                }
+
+                WriteState::Writing(_) => unreachable!(),
+
+                WriteState::Closed => return Poll::Ready(Ok(())),
+            }
+        }
+    }
+}
+
+impl Drop for ConnWriter {
+    fn drop(&mut self) {
+        // Started writes and closes must be polled to completion.
+        match mem::replace(&mut self.state, WriteState::Closed) {
+            WriteState::Writing(future) => drive::detach(async move {
+                let _ = future.await;
+            }),
+            WriteState::Closing(future) => drive::detach(async move {
+                let _ = future.await;
+            }),
+            WriteState::Idle(_) | WriteState::Closed => {}
+        }
+    }
+}
+
+/// Error for a stream which was closed with an error code.
+pub(crate) fn stream_error(code: ErrorCode) -> io::Error {
+    io::Error::new(io::ErrorKind::ConnectionReset, code)
+}
+
+/// Give a failed stream write a meaningful error kind.  The runtime reports
+/// a closed stream as a zero-length write, and other failures as opaque
+/// errors carrying the stream error code.
+pub(crate) fn write_error(e: io::Error) -> io::Error {
+    if e.kind() == io::ErrorKind::WriteZero {
+        return io::ErrorKind::BrokenPipe.into();
+    }
+
+    match e
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<ErrorCode>())
+    {
+        Some(code) => stream_error(*code),
+        None => e,
+    }
+}
+
+/// Connection I/O through the `futures_io` traits.
+pub(crate) struct ConnIo {
+    pub(crate) reader: ConnReader,
+    pub(crate) writer: ConnWriter,
+}
+
+impl ConnIo {
+    fn new(stream: RecvWriteStream, options: RecvOptions, counters: &Rc<Counters>) -> Box<Self> {
+        let (r, w) = stream.split();
+
+        Box::new(Self {
+            reader: ConnReader {
+                stream: Receiver::new(r, options),
+                counters: counters.clone(),
+            },
+            writer: ConnWriter {
+                state: WriteState::Idle(w),
+                staged: None,
+                counters: counters.clone(),
+            },
+        })
+    }
+}
+
+impl Conn {
+    /// Borrow the reading and writing halves of the connection, which
+    /// implement `AsyncRead` and `AsyncWrite`.  The `stream` field is taken
+    /// over (left closed) when this is called for the first time.
+    pub fn split(&mut self) -> (&mut ConnReader, &mut ConnWriter) {
+        let io = self.io.get_or_insert_with(|| {
+            ConnIo::new(mem::take(&mut self.stream), self.recv, &self.counters)
+        });
+
+        (&mut io.reader, &mut io.writer)
+    }
+
+    /// Split the connection into halves which can be moved into separate
+    /// tasks.  The connection permit (if any) is released when both halves
+    /// have been dropped.  Data which was buffered by a previous `split`
+    /// call is preserved.
+    pub fn into_split(mut self) -> (OwnedReadHalf, OwnedWriteHalf) {
+        let io = match self.io.take() {
+            Some(io) => io,
+            None => ConnIo::new(mem::take(&mut self.stream), self.recv, &self.counters),
+        };
+
+        let permit = self.permit.take().map(Rc::new);
+
+        (
+            OwnedReadHalf {
+                _internal: (),
+                reader: io.reader,
+                peer_addr: self.peer_addr,
+                _permit: permit.clone(),
+            },
+            OwnedWriteHalf {
+                _internal: (),
+                writer: io.writer,
+                peer_addr: self.peer_addr,
+                _permit: permit,
+            },
+        )
+    }
+
+    /// Write the buffers in a single stream write, e.g. a response header
+    /// and body without concatenating them first.  All data is accepted
+    /// unless an error is returned.  Takes over the `stream` field like
+    /// `Conn::split`.
+    pub async fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let writer = self.split().1;
+        poll_fn(|cx| Pin::new(&mut *writer).poll_write_vectored(cx, bufs)).await
+    }
+
+    /// Stage subsequent writes until `Conn::flush`, e.g. a response's status
+    /// line, headers and the first part of its body, so that they are sent
+    /// as a single stream write.  See `ConnWriter::cork`.  Takes over the
+    /// `stream` field like `Conn::split`.
+    pub fn cork(&mut self) {
+        self.split().1.cork();
+    }
+
+    /// Write the staged data (if any) and wait until all data has been
+    /// written.  Ends staging.  Takes over the `stream` field like
+    /// `Conn::split`.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        self.split().1.flush().await
+    }
+
+    /// Signal end of stream to the client after pending writes have been
+    /// flushed.  The connection can still be read from.  Subsequent writes
+    /// fail with `io::ErrorKind::BrokenPipe`.  Takes over the `stream` field
+    /// like `Conn::split`.
+    pub async fn shutdown_write(&mut self) -> io::Result<()> {
+        let writer = self.split().1;
+        poll_fn(|cx| Pin::new(&mut *writer).poll_close(cx)).await
+    }
+
+    /// Read exactly enough data to fill the buffer.  An
+    /// `io::ErrorKind::UnexpectedEof` error is returned if the stream ends
+    /// first; the contents of the buffer are unspecified in that case.  Takes
+    /// over the `stream` field like `Conn::split`.
+    pub async fn read_exact(&mut self, mut buf: &mut [u8]) -> io::Result<()> {
+        let reader = self.split().0;
+
+        while !buf.is_empty() {
+            match poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, buf)).await {
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => buf = &mut buf[n..],
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write all data in the buffer and wait until it has been written.  An
+    /// `io::ErrorKind::WriteZero` error is returned if the stream stops
+    /// accepting data.  Staged data is written too (see `Conn::cork`).  Takes
+    /// over the `stream` field like `Conn::split`.
+    pub async fn write_all(&mut self, mut buf: &[u8]) -> io::Result<()> {
+        let writer = self.split().1;
+
+        while !buf.is_empty() {
+            match poll_fn(|cx| Pin::new(&mut *writer).poll_write(cx, buf)).await {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(n) => buf = &buf[n..],
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        writer.flush().await
+    }
+}
+
+/// Reading half of a connection which owns its stream.  See
+/// `Conn::into_split`.
+pub struct OwnedReadHalf {
+    _internal: (),
+    pub(crate) reader: ConnReader,
+    _permit: Option<Rc<ConnPermit>>,
+
+    /// The client connection's address.
+    pub peer_addr: SocketAddr,
+}
+
+impl OwnedReadHalf {
+    /// See `ConnReader::set_recv_options`.
+    pub fn set_recv_options(&mut self, options: RecvOptions) {
+        self.reader.set_recv_options(options);
+    }
+
+    /// See `ConnReader::read_vectored`.
+    pub async fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.reader.read_vectored(bufs).await
+    }
+
+    /// See `ConnReader::read_uninit`.
+    pub async fn read_uninit(&mut self, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
+        self.reader.read_uninit(buf).await
+    }
+
+    /// See `ConnReader::read_buf`.
+    pub async fn read_buf(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        self.reader.read_buf(buf).await
+    }
+}
+
+impl AsyncRead for OwnedReadHalf {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().reader).poll_read(cx, buf)
+    }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        bufs: &mut [IoSliceMut],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().reader).poll_read_vectored(cx, bufs)
+    }
+}
+
+/// Writing half of a connection which owns its stream.  See
+/// `Conn::into_split`.
+pub struct OwnedWriteHalf {
+    _internal: (),
+    writer: ConnWriter,
+    _permit: Option<Rc<ConnPermit>>,
+
+    /// The client connection's address.
+    pub peer_addr: SocketAddr,
+}
+
+impl OwnedWriteHalf {
+    /// See `ConnWriter::cork`.
+    pub fn cork(&mut self) {
+        self.writer.cork();
+    }
+
+    /// See `ConnWriter::flush`.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush().await
+    }
+}
+
+impl AsyncWrite for OwnedWriteHalf {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().writer).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        bufs: &[IoSlice],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().writer).poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().writer).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().writer).poll_close(cx)
+    }
+}
+
+/// Takes over the `stream` field; see `Conn::split`.
+impl AsyncRead for Conn {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(self.get_mut().split().0).poll_read(cx, buf)
+    }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        bufs: &mut [IoSliceMut],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(self.get_mut().split().0).poll_read_vectored(cx, bufs)
+    }
+}
+
+/// Takes over the `stream` field; see `Conn::split`.
+impl AsyncWrite for Conn {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(self.get_mut().split().1).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        bufs: &[IoSlice],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(self.get_mut().split().1).poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(self.get_mut().split().1).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(self.get_mut().split().1).poll_close(cx)
+    }
+}
+
+/// Plain service stream which implements `AsyncRead` and `AsyncWrite`, e.g.
+/// for use with `copy_bidirectional`.
+pub struct StreamIo {
+    reader: ConnReader,
+    writer: ConnWriter,
+}
+
+impl StreamIo {
+    pub fn new(stream: RecvWriteStream) -> Self {
+        let io = ConnIo::new(stream, RecvOptions::default(), &Rc::default());
+
+        Self {
+            reader: io.reader,
+            writer: io.writer,
+        }
+    }
+
+    /// Borrow the reading and writing halves.
+    pub fn split(&mut self) -> (&mut ConnReader, &mut ConnWriter) {
+        (&mut self.reader, &mut self.writer)
+    }
+}
+
+impl AsyncRead for StreamIo {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().reader).poll_read(cx, buf)
+    }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        bufs: &mut [IoSliceMut],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().reader).poll_read_vectored(cx, bufs)
+    }
+}
+
+impl AsyncWrite for StreamIo {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().writer).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        bufs: &[IoSlice],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().writer).poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().writer).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().writer).poll_close(cx)
+    }
+}
+
+const COPY_BUF_SIZE: usize = 8192;
+
+/// State of one direction of `copy_bidirectional`.
+struct Transfer {
+    buf: Box<[u8]>,
+    pos: usize,
+    cap: usize,
+    eof: bool,
+    unflushed: bool,
+    amount: u64,
+}
+
+impl Transfer {
+    fn new() -> Self {
+        Self {
+            buf: vec![0; COPY_BUF_SIZE].into_boxed_slice(),
+            pos: 0,
+            cap: 0,
+            eof: false,
+            unflushed: false,
+            amount: 0,
+        }
+    }
+
+    /// Copy until the reader reaches end of stream, and then close the
+    /// writer.
+    fn poll_copy<R, W>(&mut self, cx: &mut Context, r: &mut R, w: &mut W) -> Poll<io::Result<u64>>
+    where
+        R: AsyncRead + Unpin + ?Sized,
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        loop {
+            if self.pos == self.cap && !self.eof {
+                match Pin::new(&mut *r).poll_read(cx, &mut self.buf) {
+                    Poll::Ready(Ok(0)) => self.eof = true,
+                    Poll::Ready(Ok(n)) => {
+                        self.pos = 0;
+                        self.cap = n;
+                    }
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => {
+                        // Don't hold on to written data while waiting for more.
+                        if self.unflushed {
+                            ready!(Pin::new(&mut *w).poll_flush(cx))?;
+                            self.unflushed = false;
+                        }
+                        return Poll::Pending;
+                    }
+                }
+            }
+
+            while self.pos < self.cap {
+                let n = ready!(Pin::new(&mut *w).poll_write(cx, &self.buf[self.pos..self.cap]))?;
+                if n == 0 {
+                    return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+                }
+                self.pos += n;
+                self.amount += n as u64;
+                self.unflushed = true;
+            }
+
+            if self.eof {
+                ready!(Pin::new(&mut *w).poll_close(cx))?;
+                return Poll::Ready(Ok(self.amount));
+            }
+        }
+    }
+}
+
+/// Copy data in both directions between `a` and `b`, e.g. an accepted
+/// connection and a `StreamIo` of another service.  When one side reaches
+/// end of stream, the other side's writing direction is closed.  Returns
+/// the number of bytes copied from `a` to `b` and from `b` to `a` after both
+/// directions have been closed.  An error in either direction ends both.
+pub async fn copy_bidirectional<A, B>(a: &mut A, b: &mut B) -> io::Result<(u64, u64)>
+where
+    A: AsyncRead + AsyncWrite + Unpin + ?Sized,
+    B: AsyncRead + AsyncWrite + Unpin + ?Sized,
+{
+    let mut a_to_b = Transfer::new();
+    let mut b_to_a = Transfer::new();
+    let mut a_to_b_done = None;
+    let mut b_to_a_done = None;
+
+    poll_fn(|cx| {
+        if a_to_b_done.is_none() {
+            if let Poll::Ready(n) = a_to_b.poll_copy(cx, &mut *a, &mut *b) {
+                a_to_b_done = Some(n?);
+            }
+        }
+
+        if b_to_a_done.is_none() {
+            if let Poll::Ready(n) = b_to_a.poll_copy(cx, &mut *b, &mut *a) {
+                b_to_a_done = Some(n?);
+            }
+        }
+
+        match (a_to_b_done, b_to_a_done) {
+            (Some(x), Some(y)) => Poll::Ready(Ok((x, y))),
+            _ => Poll::Pending,
+        }
+    })
+    .await
+}
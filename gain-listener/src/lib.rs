@@ -2,56 +2,585 @@
 // Use of this source code is governed by the MIT
 // license that can be found in the LICENSE file.
 
-//! Implement TLS servers.
+//! Implement TLS, TCP, UDP, DTLS and QUIC servers.
 
 #[macro_use]
 extern crate lazy_static;
 
-use flatbuffers::{root, FlatBufferBuilder};
+use events::EventSink;
+use filter::{reject_conn, Filter};
+use flatbuffers::{
+    root, size_prefixed_root, FlatBufferBuilder, ForwardsUOffset, Vector, WIPOffset,
+};
 use gain::service::Service;
-use gain::stream::{CloseStream, Recv, RecvOnlyStream, RecvStream, RecvWriteStream};
-use std::cell::{Cell, RefCell};
+use gain::stream::{Close as _, CloseStream, Recv, RecvOnlyStream, RecvStream, RecvWriteStream};
+use io::ConnIo;
+use rate::RateLimiter;
+use stats::Counters;
+use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::fmt;
-use std::net::{Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::future::{poll_fn, Future};
+use std::mem;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, SystemTime};
+
+mod buf;
+#[cfg(feature = "bytes")]
+mod chunk;
+mod close;
+#[cfg(feature = "tokio")]
+mod compat;
+mod datagram;
+mod drive;
+#[cfg(feature = "embedded-io-async")]
+mod embedded;
+mod events;
+mod ext;
+mod filter;
+#[cfg(feature = "http")]
+pub mod http;
+mod incoming;
+pub mod io;
+mod limit;
+mod multi;
+mod policy;
+mod queue;
+mod quic;
+mod quota;
+mod rate;
+mod recv;
+mod redirect;
+mod resilient;
+mod set;
+mod shutdown;
+mod stats;
+mod suspend;
+mod tls;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 // The schema file can be found at https://gateservice.net/listener
 #[allow(unused, unused_imports)]
 #[path = "listener_generated.rs"]
 mod flat;
 
-const ACCEPT_SIZE: usize = flat::AcceptSize::Basic.0 as usize;
+pub use buf::BufConn;
+#[cfg(feature = "tokio")]
+pub use compat::Compat;
+pub use datagram::{DatagramListener, DtlsAcceptor, DtlsAssociation, DtlsListener};
+pub use events::{ListenerEvent, ListenerEvents, SniRejection};
+pub use ext::Extensions;
+pub use filter::Verdict;
+pub use incoming::Incoming;
+pub use io::{ConnReader, ConnWriter, OwnedReadHalf, OwnedWriteHalf, StreamIo};
+pub use limit::{ConnLimiter, ConnPermit, PeerLimits};
+pub use multi::MultiAcceptor;
+pub use policy::{CidrError, IpPolicy};
+pub use queue::AcceptQueue;
+pub use quic::{QuicAcceptor, QuicConn, QuicListener, QuicRecvStream, QuicSendStream, QuicStream};
+pub use quota::{quota, Quota};
+pub use rate::RateLimit;
+pub use recv::RecvOptions;
+pub use resilient::{ResilientAccept, ResilientError, ResilientListener};
+pub use set::ListenerSet;
+pub use shutdown::{ConnHandle, ConnTracker};
+pub use stats::{ConnStats, ConnStatsHandle};
+pub use suspend::ListenerState;
+pub use tls::{ClientCertPolicy, ClientIdentity, HandshakeKind, TlsDetails, TlsInfo, TlsVersion};
+
+const SIZE_PREFIX: usize = 4;
+
+/// Largest size-prefixed frame accepted from the service, excluding prefix.
+const MAX_FRAME_SIZE: usize = 1 << 20;
 
 lazy_static! {
     static ref SERVICE: Service = Service::register("gateservice.net/listener");
 }
 
 /// Binding options.
+#[derive(Clone)]
 pub struct BindOptions<'a> {
     _internal: (),
 
-    /// Listening port.
+    /// Listening port.  Zero lets the service choose an available port.
     pub port: u16,
 
     /// Server name prefix.
     pub prefix: Option<&'a str>,
+
+    /// User-owned domain name to serve instead of a generated name.
+    pub domain: Option<&'a str>,
+
+    /// Route all subdomains of the server name to the listener.
+    pub wildcard: bool,
+
+    /// Additional server name prefixes served by the same listener.
+    pub prefixes: &'a [&'a str],
+
+    /// Lease duration.  The binding is released automatically unless it is
+    /// renewed before the lease expires.
+    pub ttl: Option<Duration>,
+
+    /// Address families of the clients which may connect.
+    pub ip_family: IpFamily,
+
+    /// Requested length of the pending connection queue.  The service may
+    /// grant a different length.
+    pub backlog: Option<u32>,
+
+    /// Client certificate verification policy.  Applies to TLS, QUIC and
+    /// DTLS listeners.
+    pub client_cert: ClientCertPolicy,
+
+    /// Application-layer protocols in order of preference (e.g. `["h2",
+    /// "http/1.1"]`).  Applies to TLS, QUIC and DTLS listeners.
+    pub alpn: &'a [&'a str],
+
+    /// Oldest TLS version accepted from clients.  Applies to TLS and DTLS
+    /// listeners.  If the service can't enforce it, binding fails with
+    /// `BindErrorKind::UnsupportedTlsPolicy`.  A service which doesn't support
+    /// size-prefixed accept frames predates the option and would ignore it,
+    /// so binding fails with `BindErrorKind::ProtocolMismatch` instead of
+    /// falling back to a fixed accept frame size.
+    pub min_tls_version: Option<TlsVersion>,
+
+    /// Reject TLS handshakes whose server name indication doesn't match the
+    /// binding, instead of completing them with the default certificate.
+    /// Rejections are reported via `Listener::events`.
+    pub strict_sni: bool,
+
+    /// Accept TLS 1.3 early data (0-RTT) from resuming clients.  Early data
+    /// can be replayed by an attacker; see `Conn::early_data`.
+    pub early_data: bool,
+
+    /// Arbitrary label (e.g. `"tenant=acme"`) which is attached to the
+    /// binding and to every connection accepted from it.  The label is kept
+    /// by this program; it isn't sent to the service.
+    pub label: Option<&'a str>,
 }
 
 impl<'a> BindOptions<'a> {
-    /// Default binding options.
+    /// Default binding options.  If `port` is zero, the service assigns a
+    /// port; it can be discovered from the `Binding::port` field.
     pub fn new(port: u16) -> Self {
         Self {
             _internal: (),
             port,
             prefix: None,
+            domain: None,
+            wildcard: false,
+            prefixes: &[],
+            ttl: None,
+            ip_family: IpFamily::Dual,
+            backlog: None,
+            client_cert: ClientCertPolicy::None,
+            alpn: &[],
+            min_tls_version: None,
+            strict_sni: false,
+            early_data: false,
+            label: None,
         }
     }
 
     /// Opt for a more descriptive server name.
     pub fn with_prefix(prefix: &'a str, port: u16) -> Self {
         Self {
-            _internal: (),
-            port,
             prefix: Some(prefix),
+            ..Self::new(port)
+        }
+    }
+
+    /// Serve a domain name owned by the user.  The service must be able to
+    /// verify the ownership of the domain; otherwise binding fails with
+    /// `BindErrorKind::DomainNotVerified`.
+    pub fn with_custom_domain(domain: &'a str, port: u16) -> Self {
+        Self {
+            domain: Some(domain),
+            ..Self::new(port)
+        }
+    }
+
+    /// Like `with_prefix`, but connections to any subdomain of the server
+    /// name are routed to the listener as well.  The requested subdomain can
+    /// be discovered from the `Conn::subdomain` field.
+    pub fn with_wildcard_prefix(prefix: &'a str, port: u16) -> Self {
+        Self {
+            prefix: Some(prefix),
+            wildcard: true,
+            ..Self::new(port)
+        }
+    }
+
+    /// Serve several server names at the same port.  The prefix requested by
+    /// the client can be discovered from the `Conn::prefix` field.
+    pub fn with_prefixes(prefixes: &'a [&'a str], port: u16) -> Self {
+        Self {
+            prefixes,
+            ..Self::new(port)
+        }
+    }
+
+    /// Make the binding a lease.  The listener must be renewed with
+    /// `Listener::renew` before `ttl` elapses; otherwise the service releases
+    /// the server name and accepting fails with `AcceptErrorKind::LeaseExpired`.
+    pub fn with_ttl(self, ttl: Duration) -> Self {
+        Self {
+            ttl: Some(ttl),
+            ..self
+        }
+    }
+
+    /// Restrict the address families of the clients.
+    pub fn with_ip_family(self, ip_family: IpFamily) -> Self {
+        Self { ip_family, ..self }
+    }
+
+    /// Request a deeper (or shallower) pending connection queue.
+    pub fn with_backlog(self, backlog: u32) -> Self {
+        Self {
+            backlog: Some(backlog),
+            ..self
+        }
+    }
+
+    /// Ask clients for TLS certificates.  The verified certificate chain can
+    /// be discovered from the `Conn::client_identity` field.
+    pub fn require_client_cert(self, client_cert: ClientCertPolicy) -> Self {
+        Self {
+            client_cert,
+            ..self
+        }
+    }
+
+    /// Negotiate an application-layer protocol with clients.  The negotiated
+    /// protocol can be discovered from the `Conn::alpn` field.
+    pub fn with_alpn(self, alpn: &'a [&'a str]) -> Self {
+        Self { alpn, ..self }
+    }
+
+    /// Refuse clients which don't support `version` or newer.
+    pub fn min_tls_version(self, version: TlsVersion) -> Self {
+        Self {
+            min_tls_version: Some(version),
+            ..self
+        }
+    }
+
+    /// Reject clients which don't indicate a server name served by the
+    /// listener.
+    pub fn with_strict_sni(self) -> Self {
+        Self {
+            strict_sni: true,
+            ..self
+        }
+    }
+
+    /// Allow or refuse TLS 1.3 early data (0-RTT).  It is refused by default.
+    pub fn allow_early_data(self, early_data: bool) -> Self {
+        Self { early_data, ..self }
+    }
+
+    /// Label the binding and its connections.
+    pub fn with_label(self, label: &'a str) -> Self {
+        Self {
+            label: Some(label),
+            ..self
+        }
+    }
+
+    /// Build binding options field by field.  The options are validated by
+    /// `BindOptionsBuilder::build`.
+    pub fn builder() -> BindOptionsBuilder<'a> {
+        BindOptionsBuilder { opt: Self::new(0) }
+    }
+
+    fn lease_ms(&self) -> u32 {
+        match self.ttl {
+            Some(d) => d.as_millis().clamp(1, u32::MAX as u128) as u32,
+            None => 0,
+        }
+    }
+
+    /// The preferred accept frame variant.  The service may not support it,
+    /// in which case a smaller one is negotiated (see `fallback_accept_size`).
+    fn accept_size(&self, protocol: Protocol) -> flat::AcceptSize {
+        if self.client_cert != ClientCertPolicy::None {
+            flat::AcceptSize::Extended
+        } else if self.wildcard
+            || !self.prefixes.is_empty()
+            || !self.alpn.is_empty()
+            || self.early_data
+            || protocol != Protocol::Tcp
+        {
+            flat::AcceptSize::Named
+        } else {
+            flat::AcceptSize::Basic
+        }
+    }
+}
+
+/// Binding options builder.
+pub struct BindOptionsBuilder<'a> {
+    opt: BindOptions<'a>,
+}
+
+impl<'a> BindOptionsBuilder<'a> {
+    /// Listening port.  Zero (the default) lets the service choose an
+    /// available port.
+    pub fn port(mut self, port: u16) -> Self {
+        self.opt.port = port;
+        self
+    }
+
+    /// Server name prefix.
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.opt.prefix = Some(prefix);
+        self
+    }
+
+    /// Additional server name prefixes.
+    pub fn prefixes(mut self, prefixes: &'a [&'a str]) -> Self {
+        self.opt.prefixes = prefixes;
+        self
+    }
+
+    /// User-owned domain name.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.opt.domain = Some(domain);
+        self
+    }
+
+    /// Route all subdomains of the server name to the listener.
+    pub fn wildcard(mut self, wildcard: bool) -> Self {
+        self.opt.wildcard = wildcard;
+        self
+    }
+
+    /// Lease duration.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.opt.ttl = Some(ttl);
+        self
+    }
+
+    /// Address families of the clients which may connect.
+    pub fn ip_family(mut self, ip_family: IpFamily) -> Self {
+        self.opt.ip_family = ip_family;
+        self
+    }
+
+    /// Requested length of the pending connection queue.
+    pub fn backlog(mut self, backlog: u32) -> Self {
+        self.opt.backlog = Some(backlog);
+        self
+    }
+
+    /// Client certificate verification policy.
+    pub fn require_client_cert(mut self, client_cert: ClientCertPolicy) -> Self {
+        self.opt.client_cert = client_cert;
+        self
+    }
+
+    /// Application-layer protocols in order of preference.
+    pub fn alpn(mut self, alpn: &'a [&'a str]) -> Self {
+        self.opt.alpn = alpn;
+        self
+    }
+
+    /// Oldest TLS version accepted from clients.
+    pub fn min_tls_version(mut self, version: TlsVersion) -> Self {
+        self.opt.min_tls_version = Some(version);
+        self
+    }
+
+    /// Reject clients which don't indicate a server name served by the
+    /// listener.
+    pub fn strict_sni(mut self, strict_sni: bool) -> Self {
+        self.opt.strict_sni = strict_sni;
+        self
+    }
+
+    /// Accept TLS 1.3 early data (0-RTT).
+    pub fn early_data(mut self, early_data: bool) -> Self {
+        self.opt.early_data = early_data;
+        self
+    }
+
+    /// Label the binding and its connections.
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.opt.label = Some(label);
+        self
+    }
+
+    /// Validate the options without contacting the service.  The prefixes
+    /// must follow the rules documented at `Listener::bind_tls`; a violation
+    /// is reported as `BindErrorKind::InvalidName` or
+    /// `BindErrorKind::NameTooLong`.  An empty domain name is reported as
+    /// `BindErrorKind::InvalidName`.
+    pub fn build(self) -> Result<BindOptions<'a>, BindError> {
+        if let Some(prefix) = self.opt.prefix {
+            check_prefix(prefix)?;
+        }
+
+        for prefix in self.opt.prefixes {
+            check_prefix(prefix)?;
+        }
+
+        if self.opt.domain == Some("") {
+            return Err(BindError::new(flat::BindError::InvalidName));
+        }
+
+        Ok(self.opt)
+    }
+}
+
+const MAX_PREFIX_LEN: usize = 31;
+
+/// Check that a server name prefix follows the rules documented at
+/// `Listener::bind_tls`.  The service is not contacted.
+pub fn validate_prefix(prefix: &str) -> Result<(), PrefixError> {
+    if prefix.is_empty() {
+        return Err(PrefixError::Empty);
+    }
+
+    if prefix.len() > MAX_PREFIX_LEN {
+        return Err(PrefixError::TooLong);
+    }
+
+    if !prefix
+        .bytes()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-')
+    {
+        return Err(PrefixError::InvalidChar);
+    }
+
+    if prefix.starts_with('-') || prefix.ends_with('-') {
+        return Err(PrefixError::EdgeDash);
+    }
+
+    if prefix.starts_with("xn--") {
+        return Err(PrefixError::Punycode);
+    }
+
+    if prefix.contains("--") {
+        return Err(PrefixError::ConsecutiveDashes);
+    }
+
+    Ok(())
+}
+
+fn check_prefix(prefix: &str) -> Result<(), BindError> {
+    match validate_prefix(prefix) {
+        Ok(()) => Ok(()),
+        Err(PrefixError::TooLong) => Err(BindError::new(flat::BindError::NameTooLong)),
+        Err(_) => Err(BindError::new(flat::BindError::InvalidName)),
+    }
+}
+
+/// Server name prefix validation error.
+#[derive(Debug, Eq, PartialEq)]
+pub enum PrefixError {
+    /// The prefix is empty.
+    Empty,
+
+    /// The prefix is longer than 31 characters.
+    TooLong,
+
+    /// The prefix contains a character other than a lowercase alphanumeric
+    /// ASCII character or a dash.
+    InvalidChar,
+
+    /// The prefix starts or ends with a dash.
+    EdgeDash,
+
+    /// The prefix starts with the IDNA ACE prefix `xn--`.
+    Punycode,
+
+    /// The prefix contains multiple consecutive dashes.
+    ConsecutiveDashes,
+}
+
+impl fmt::Display for PrefixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            PrefixError::Empty => f.write_str("empty prefix"),
+            PrefixError::TooLong => f.write_str("prefix too long"),
+            PrefixError::InvalidChar => f.write_str("invalid character in prefix"),
+            PrefixError::EdgeDash => f.write_str("prefix starts or ends with a dash"),
+            PrefixError::Punycode => f.write_str("punycode prefix"),
+            PrefixError::ConsecutiveDashes => f.write_str("consecutive dashes in prefix"),
+        }
+    }
+}
+
+/// Address family selection.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum IpFamily {
+    /// Accept both IPv4 and IPv6 clients.
+    #[default]
+    Dual,
+
+    /// Accept only IPv4 clients.
+    V4Only,
+
+    /// Accept only IPv6 clients.
+    V6Only,
+}
+
+impl IpFamily {
+    fn new(flat: flat::IPFamily) -> Self {
+        match flat {
+            flat::IPFamily::V4Only => IpFamily::V4Only,
+            flat::IPFamily::V6Only => IpFamily::V6Only,
+            _ => IpFamily::Dual,
+        }
+    }
+
+    fn flat(self) -> flat::IPFamily {
+        match self {
+            IpFamily::Dual => flat::IPFamily::Dual,
+            IpFamily::V4Only => flat::IPFamily::V4Only,
+            IpFamily::V6Only => flat::IPFamily::V6Only,
+        }
+    }
+}
+
+/// Transport protocol of a listener.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Protocol {
+    /// Raw TCP connections.
+    Tcp,
+
+    /// TLS connections terminated by the service.
+    Tls,
+
+    /// UDP datagrams.
+    Udp,
+
+    /// QUIC connections.
+    Quic,
+
+    /// DTLS associations terminated by the service.
+    Dtls,
+
+    /// TLS connections routed by server name, but not terminated by the
+    /// service.
+    TlsPassthrough,
+}
+
+impl Protocol {
+    fn new(flat: flat::Protocol) -> Option<Self> {
+        match flat {
+            flat::Protocol::TLS => Some(Self::Tls),
+            flat::Protocol::TCP => Some(Self::Tcp),
+            flat::Protocol::UDP => Some(Self::Udp),
+            flat::Protocol::QUIC => Some(Self::Quic),
+            flat::Protocol::DTLS => Some(Self::Dtls),
+            flat::Protocol::TLSPassthrough => Some(Self::TlsPassthrough),
+            _ => None,
         }
     }
 }
@@ -61,173 +590,1597 @@ pub struct Binding {
     /// Fully-qualified DNS name of the server.
     pub hostname: String,
 
-    /// The listening port.
-    pub port: u16,
-}
+    /// The listening port.  If a service-assigned port was requested, this is
+    /// the actual port.
+    pub port: u16,
+
+    /// Transport protocol of the connections.
+    pub protocol: Protocol,
+
+    /// Fully-qualified DNS names of all server names served by the listener.
+    /// The first one is the same as `hostname`.
+    pub hostnames: Vec<String>,
+
+    /// Lease duration granted by the service, if the binding is a lease.
+    pub lease: Option<Duration>,
+
+    /// Address families of the clients which may connect.
+    pub ip_family: IpFamily,
+
+    /// Length of the pending connection queue granted by the service.  Zero
+    /// means that the service didn't report it.
+    pub backlog: u32,
+
+    /// SHA-256 fingerprint of the certificate presented to clients, if the
+    /// service terminates TLS and reported it.
+    pub cert_fingerprint: Option<[u8; 32]>,
+
+    /// Expiration time (notAfter) of the certificate presented to clients.
+    pub cert_expires: Option<SystemTime>,
+
+    /// IPv4 and IPv6 addresses which the hostname resolves to, as reported
+    /// by the service.  Empty if the service didn't report them.
+    pub public_addrs: Vec<IpAddr>,
+
+    /// The label specified with `BindOptions::label`.
+    pub label: Option<Rc<str>>,
+}
+
+impl Binding {
+    /// None is returned if the reply lacks required fields.
+    fn new(r: &flat::Binding, protocol: Protocol) -> Option<Self> {
+        let hostname: String = r.host()?.into();
+
+        let hostnames = match r.hosts() {
+            Some(v) => v.iter().map(Into::into).collect(),
+            None => vec![hostname.clone()],
+        };
+
+        let lease = match r.lease_ms() {
+            0 => None,
+            ms => Some(Duration::from_millis(ms.into())),
+        };
+
+        let cert_fingerprint = r.cert_sha256().and_then(|v| v.bytes().try_into().ok());
+
+        let cert_expires = match r.cert_not_after_ms() {
+            0 => None,
+            ms => Some(SystemTime::UNIX_EPOCH + Duration::from_millis(ms)),
+        };
+
+        let public_addrs = match r.public_addrs() {
+            Some(v) => v.iter().map(ip_addr).collect(),
+            None => Vec::new(),
+        };
+
+        Some(Self {
+            hostname,
+            port: r.port(),
+            protocol,
+            hostnames,
+            lease,
+            ip_family: IpFamily::new(r.ip_family()),
+            backlog: r.backlog(),
+            cert_fingerprint,
+            cert_expires,
+            public_addrs,
+            label: None,
+        })
+    }
+}
+
+/// Description of an active binding.
+pub struct BindingInfo {
+    _internal: (),
+
+    /// Listener address.
+    pub addr: Binding,
+
+    /// Server name prefixes of the binding.
+    pub prefixes: Vec<String>,
+}
+
+/// Persistent identity of a generated server name.  The token is opaque; it
+/// can be stored as bytes and restored with `BindingToken::from_bytes`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BindingToken(Vec<u8>);
+
+impl BindingToken {
+    /// Restore a token which was previously stored.
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// Serialized form of the token.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Convert the token into its serialized form.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+/// Connection listener.
+pub struct Listener {
+    listen_id: i32,
+    stream: RecvStream,
+    accept_size: flat::AcceptSize,
+    events: EventSink,
+    pub addr: Binding,
+}
+
+impl Listener {
+    /// Listen to TLS connections at `BindOptions::port`.  The fully-qualified
+    /// DNS name can be discovered from the `Listener::addr.hostname` field.
+    ///
+    /// If specified, `BindOptions::prefix` is prepended to the server name.
+    /// Its length must be between 1 and 31 characters (inclusive), and it must
+    /// consist of lowercase alphanumeric ASCII characters and dashes (`-`).
+    /// It must not start or end with a dash, nor contain multiple consecutive
+    /// dashes (so it can't start with `xn--`).  The rules can be checked in
+    /// advance with `validate_prefix`.
+    ///
+    /// If `BindOptions::domain` is specified, it is used as the server name
+    /// as is, and the prefix is ignored.
+    ///
+    /// Each of `BindOptions::prefixes` is subject to the same rules as the
+    /// prefix.  All of the resulting server names are served by the listener.
+    pub async fn bind_tls(opt: BindOptions<'_>) -> Result<Self, BindError> {
+        let (listen_id, accept_size, addr, _) = bind(opt, Protocol::Tls, None).await?;
+        Ok(Self::new(listen_id, accept_size, addr))
+    }
+
+    /// Like `bind_tls`, but the server name is tied to a `BindingToken`.  If
+    /// a token from a previous binding is specified, the same hostname is
+    /// reclaimed; otherwise a new one is generated.  The returned token can be
+    /// persisted and used after the program is restarted or restored.
+    ///
+    /// A token which wasn't issued to this program causes a
+    /// `BindErrorKind::InvalidToken` error.
+    pub async fn bind_tls_with_token(
+        opt: BindOptions<'_>,
+        token: Option<BindingToken>,
+    ) -> Result<(Self, BindingToken), BindError> {
+        let (listen_id, accept_size, addr, token) =
+            bind(opt, Protocol::Tls, token.as_ref()).await?;
+        Ok((Self::new(listen_id, accept_size, addr), token))
+    }
+
+    /// Listen to plain TCP connections at `BindOptions::port`.  The client
+    /// connection's stream carries raw application data; the service doesn't
+    /// terminate TLS.
+    ///
+    /// `BindOptions::prefix` has the same semantics as with `bind_tls`.
+    pub async fn bind_tcp(opt: BindOptions<'_>) -> Result<Self, BindError> {
+        let (listen_id, accept_size, addr, _) = bind(opt, Protocol::Tcp, None).await?;
+        Ok(Self::new(listen_id, accept_size, addr))
+    }
+
+    /// Listen to TLS connections at `BindOptions::port` without terminating
+    /// them.  The service routes connections by the server name indicated by
+    /// the client, and the connection's stream carries the TLS records as is,
+    /// starting with the ClientHello.  The program is responsible for the
+    /// handshake, so no certificate is issued for the binding.
+    ///
+    /// `BindOptions::prefix` has the same semantics as with `bind_tls`.
+    /// Options which concern TLS termination are ignored.
+    pub async fn bind_tls_passthrough(opt: BindOptions<'_>) -> Result<Self, BindError> {
+        let (listen_id, accept_size, addr, _) = bind(opt, Protocol::TlsPassthrough, None).await?;
+        Ok(Self::new(listen_id, accept_size, addr))
+    }
+
+    /// Listen to TLS connections at multiple ports.  The ports are bound in a
+    /// single operation: either all of them succeed or none of them do.  The
+    /// listeners are returned in the same order as the options.
+    ///
+    /// `BindOptions::prefix` has the same semantics as with `bind_tls`.
+    pub async fn bind_tls_many(opts: &[BindOptions<'_>]) -> Result<Vec<Self>, BindError> {
+        let mut max_accept_size = flat::AcceptSize::Variable;
+
+        loop {
+            match Self::bind_tls_many_with_accept_size(opts, max_accept_size).await {
+                Err(e) if e.flat == flat::BindError::InvalidAcceptSize => {
+                    if opts.iter().any(|opt| opt.min_tls_version.is_some()) {
+                        return Err(BindError::protocol_mismatch());
+                    }
+
+                    match fallback_accept_size(max_accept_size) {
+                        Some(size) => max_accept_size = size,
+                        None => return Err(BindError::protocol_mismatch()),
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn bind_tls_many_with_accept_size(
+        opts: &[BindOptions<'_>],
+        max_accept_size: flat::AcceptSize,
+    ) -> Result<Vec<Self>, BindError> {
+        let accept_size =
+            |opt: &BindOptions| min_accept_size(opt.accept_size(Protocol::Tls), max_accept_size);
+
+        let mut b = FlatBufferBuilder::new();
+
+        let mut bindings = Vec::with_capacity(opts.len());
+        for opt in opts {
+            let prefix = opt.prefix.map(|s| b.create_string(s));
+            let domain = opt.domain.map(|s| b.create_string(s));
+            let names = create_names(&mut b, opt.prefixes);
+            let alpn = create_names(&mut b, opt.alpn);
+
+            bindings.push(flat::BindTLS::create(
+                &mut b,
+                &flat::BindTLSArgs {
+                    accept_size: accept_size(opt),
+                    name: prefix,
+                    port: opt.port,
+                    domain,
+                    wildcard: opt.wildcard,
+                    names,
+                    token: None,
+                    lease_ms: opt.lease_ms(),
+                    ip_family: opt.ip_family.flat(),
+                    backlog: opt.backlog.unwrap_or(0),
+                    client_cert: opt.client_cert.flat(),
+                    alpn,
+                    min_tls_version: TlsVersion::flat(opt.min_tls_version),
+                    strict_sni: opt.strict_sni,
+                    early_data: opt.early_data,
+                },
+            ));
+        }
+        let bindings = b.create_vector(&bindings);
+
+        let function = flat::BindTLSMany::create(
+            &mut b,
+            &flat::BindTLSManyArgs {
+                bindings: Some(bindings),
+            },
+        );
+
+        let call = flat::Call::create(
+            &mut b,
+            &flat::CallArgs {
+                function_type: flat::Function::BindTLSMany,
+                function: Some(function.as_union_value()),
+            },
+        );
+
+        b.finish_minimal(call);
+
+        SERVICE
+            .call(b.finished_data(), |reply: &[u8]| {
+                if reply.is_empty() {
+                    return Err(BindError::unsupported_call());
+                }
+
+                let r = match root::<flat::Bindings>(reply) {
+                    Ok(r) => r,
+                    Err(_) => return Err(BindError::decode()),
+                };
+
+                if r.error() != flat::BindError::None {
+                    return Err(BindError::new(r.error()));
+                }
+
+                let bindings = match r.bindings() {
+                    Some(v) if v.len() == opts.len() => v,
+                    _ => return Err(BindError::protocol_mismatch()),
+                };
+
+                bindings
+                    .iter()
+                    .zip(opts)
+                    .map(|(r, opt)| {
+                        let mut addr = Binding::new(&r, Protocol::Tls)
+                            .ok_or_else(BindError::protocol_mismatch)?;
+                        addr.label = opt.label.map(Into::into);
+                        Ok(Self::new(r.listen_id(), accept_size(opt), addr))
+                    })
+                    .collect()
+            })
+            .await
+    }
+
+    /// Enumerate the bindings currently held by the program, including those
+    /// created by other modules or before the program was restored.  The
+    /// list is empty if the service doesn't support the query.
+    pub async fn list_bindings() -> Vec<BindingInfo> {
+        let mut b = FlatBufferBuilder::new();
+
+        let function = flat::ListBindings::create(&mut b, &flat::ListBindingsArgs {});
+
+        let call = flat::Call::create(
+            &mut b,
+            &flat::CallArgs {
+                function_type: flat::Function::ListBindings,
+                function: Some(function.as_union_value()),
+            },
+        );
+
+        b.finish_minimal(call);
+
+        SERVICE
+            .call(b.finished_data(), |reply: &[u8]| {
+                if reply.is_empty() {
+                    return Vec::new();
+                }
+
+                let r = match root::<flat::Bindings>(reply) {
+                    Ok(r) => r,
+                    Err(_) => return Vec::new(),
+                };
+
+                let bindings = match r.bindings() {
+                    Some(v) => v,
+                    None => return Vec::new(),
+                };
+
+                bindings
+                    .iter()
+                    .filter_map(|r| {
+                        let protocol = Protocol::new(r.protocol())?;
+
+                        let prefixes = match r.names() {
+                            Some(v) => v.iter().map(Into::into).collect(),
+                            None => Vec::new(),
+                        };
+
+                        Some(BindingInfo {
+                            _internal: (),
+                            addr: Binding::new(&r, protocol)?,
+                            prefixes,
+                        })
+                    })
+                    .collect()
+            })
+            .await
+    }
+
+    /// Identifier of the listener's service stream.  It appears in Gate
+    /// runtime logs, and can be used to correlate them with application logs.
+    pub fn id(&self) -> i32 {
+        self.listen_id
+    }
+
+    /// Size of the accept frame variant negotiated with the service, in
+    /// bytes.  It determines how much connection metadata is available.
+    /// `usize::MAX` means that the frames are size-prefixed, so all metadata
+    /// is available.
+    pub fn accept_size(&self) -> usize {
+        match self.accept_size {
+            flat::AcceptSize::Variable => usize::MAX,
+            size => size.0 as usize,
+        }
+    }
+
+    fn new(listen_id: i32, accept_size: flat::AcceptSize, addr: Binding) -> Self {
+        Self {
+            listen_id,
+            stream: SERVICE.input_stream(listen_id),
+            accept_size,
+            events: EventSink::default(),
+            addr,
+        }
+    }
+
+    /// Subscribe to events which don't result in connections.  Events are
+    /// produced while `accept` is being called.
+    pub fn events(&self) -> ListenerEvents {
+        self.events.subscribe()
+    }
+
+    /// Replace the server name prefix of the binding.  Existing connections
+    /// are not affected.  On success, `addr.hostname` is updated to the new
+    /// server name; on failure, the old name remains in use.
+    pub async fn rename_prefix(&mut self, new_prefix: &str) -> Result<(), BindError> {
+        check_prefix(new_prefix)?;
+
+        let mut b = FlatBufferBuilder::new();
+
+        let name = b.create_string(new_prefix);
+
+        let function = flat::RenameBinding::create(
+            &mut b,
+            &flat::RenameBindingArgs {
+                listen_id: self.listen_id,
+                name: Some(name),
+            },
+        );
+
+        let call = flat::Call::create(
+            &mut b,
+            &flat::CallArgs {
+                function_type: flat::Function::RenameBinding,
+                function: Some(function.as_union_value()),
+            },
+        );
+
+        b.finish_minimal(call);
+
+        self.addr = update_binding(b.finished_data(), &self.addr).await?;
+        Ok(())
+    }
+
+    /// Extend the lease of the binding by the TTL which was specified when
+    /// binding.  The new lease is reflected in `addr.lease`.  If the lease has
+    /// already expired, a `BindErrorKind::LeaseExpired` error is returned.
+    pub async fn renew(&mut self) -> Result<(), BindError> {
+        let mut b = FlatBufferBuilder::new();
+
+        let function = flat::RenewBinding::create(
+            &mut b,
+            &flat::RenewBindingArgs {
+                listen_id: self.listen_id,
+            },
+        );
+
+        let call = flat::Call::create(
+            &mut b,
+            &flat::CallArgs {
+                function_type: flat::Function::RenewBinding,
+                function: Some(function.as_union_value()),
+            },
+        );
+
+        b.finish_minimal(call);
+
+        self.addr = update_binding(b.finished_data(), &self.addr).await?;
+        Ok(())
+    }
+
+    /// Release the binding and wait until the service has confirmed it.  The
+    /// same address can be bound again immediately afterwards without
+    /// racing into `BindErrorKind::AlreadyBound`.
+    pub async fn unbind(self) -> Result<(), UnbindError> {
+        unbind(self.listen_id).await
+    }
+
+    /// Accept a client connection.  An `AcceptErrorKind::Closed` error may
+    /// occur due to environmental causes.
+    pub async fn accept(&mut self) -> Result<Conn, AcceptError> {
+        let mut conn = accept(&mut self.stream, self.accept_size, Some(&self.events)).await?;
+        conn.attach(&self.addr);
+        Ok(conn)
+    }
+
+    /// Detach the closing functionality.  When the `CloseStream` is closed or
+    /// dropped, the `Acceptor` will return an `AcceptErrorKind::Closed` error.
+    pub fn split(self) -> (Acceptor, CloseStream) {
+        let (stream, c) = self.stream.split();
+        (
+            Acceptor {
+                listen_id: self.listen_id,
+                state: AcceptState::Idle(stream),
+                reader: Rc::new(RefCell::new(FrameReader::accept(self.accept_size))),
+                decoding: None,
+                filter: None,
+                limiter: None,
+                peer_limits: None,
+                rate: None,
+                ip_policy: None,
+                prefetch: false,
+                accept_size: self.accept_size,
+                events: self.events,
+                addr: self.addr,
+            },
+            c,
+        )
+    }
+
+    /// Like `split`, but the closing functionality is wrapped so that the
+    /// release of the binding can be awaited.
+    pub fn split_acked(self) -> (Acceptor, ListenerCloser) {
+        let listen_id = self.listen_id;
+        let (acc, stream) = self.split();
+        (acc, ListenerCloser { listen_id, stream })
+    }
+}
+
+/// Bind using the largest accept frame variant supported by the service.
+async fn bind(
+    opt: BindOptions<'_>,
+    protocol: Protocol,
+    token: Option<&BindingToken>,
+) -> Result<(i32, flat::AcceptSize, Binding, BindingToken), BindError> {
+    let preferred = opt.accept_size(protocol);
+    let mut accept_size = flat::AcceptSize::Variable;
+
+    loop {
+        match bind_with_accept_size(&opt, protocol, token, accept_size).await {
+            Err(e) if e.flat == flat::BindError::InvalidAcceptSize => {
+                // Older services would ignore the minimum TLS version.
+                if opt.min_tls_version.is_some()
+                    && matches!(protocol, Protocol::Tls | Protocol::Dtls)
+                {
+                    return Err(BindError::protocol_mismatch());
+                }
+
+                match fallback_accept_size(accept_size) {
+                    Some(size) => accept_size = min_accept_size(preferred, size),
+                    None => return Err(BindError::protocol_mismatch()),
+                }
+            }
+            result => {
+                return result.map(|(listen_id, addr, token)| (listen_id, accept_size, addr, token))
+            }
+        }
+    }
+}
+
+async fn bind_with_accept_size(
+    opt: &BindOptions<'_>,
+    protocol: Protocol,
+    token: Option<&BindingToken>,
+    accept_size: flat::AcceptSize,
+) -> Result<(i32, Binding, BindingToken), BindError> {
+    let mut b = FlatBufferBuilder::new();
+
+    let prefix = match opt.prefix {
+        Some(s) => Some(b.create_string(s)),
+        None => None,
+    };
+    let domain = opt.domain.map(|s| b.create_string(s));
+    let names = create_names(&mut b, opt.prefixes);
+    let alpn = create_names(&mut b, opt.alpn);
+    let token = token.map(|t| b.create_vector(&t.0));
+
+    let (function_type, function) = match protocol {
+        Protocol::Tls => (
+            flat::Function::BindTLS,
+            flat::BindTLS::create(
+                &mut b,
+                &flat::BindTLSArgs {
+                    accept_size,
+                    name: prefix,
+                    port: opt.port,
+                    domain,
+                    wildcard: opt.wildcard,
+                    names,
+                    token,
+                    lease_ms: opt.lease_ms(),
+                    ip_family: opt.ip_family.flat(),
+                    backlog: opt.backlog.unwrap_or(0),
+                    client_cert: opt.client_cert.flat(),
+                    alpn,
+                    min_tls_version: TlsVersion::flat(opt.min_tls_version),
+                    strict_sni: opt.strict_sni,
+                    early_data: opt.early_data,
+                },
+            )
+            .as_union_value(),
+        ),
+
+        Protocol::Tcp => (
+            flat::Function::BindTCP,
+            flat::BindTCP::create(
+                &mut b,
+                &flat::BindTCPArgs {
+                    accept_size,
+                    name: prefix,
+                    port: opt.port,
+                    domain,
+                    wildcard: opt.wildcard,
+                    names,
+                    token,
+                    lease_ms: opt.lease_ms(),
+                    ip_family: opt.ip_family.flat(),
+                    backlog: opt.backlog.unwrap_or(0),
+                },
+            )
+            .as_union_value(),
+        ),
+
+        Protocol::TlsPassthrough => (
+            flat::Function::BindTLSPassthrough,
+            flat::BindTLSPassthrough::create(
+                &mut b,
+                &flat::BindTLSPassthroughArgs {
+                    accept_size,
+                    name: prefix,
+                    port: opt.port,
+                    domain,
+                    wildcard: opt.wildcard,
+                    names,
+                    token,
+                    lease_ms: opt.lease_ms(),
+                    ip_family: opt.ip_family.flat(),
+                    backlog: opt.backlog.unwrap_or(0),
+                },
+            )
+            .as_union_value(),
+        ),
+
+        Protocol::Udp => (
+            flat::Function::BindUDP,
+            flat::BindUDP::create(
+                &mut b,
+                &flat::BindUDPArgs {
+                    name: prefix,
+                    port: opt.port,
+                    domain,
+                    wildcard: opt.wildcard,
+                    names,
+                    token,
+                    lease_ms: opt.lease_ms(),
+                    ip_family: opt.ip_family.flat(),
+                    backlog: opt.backlog.unwrap_or(0),
+                },
+            )
+            .as_union_value(),
+        ),
+
+        Protocol::Quic => (
+            flat::Function::BindQUIC,
+            flat::BindQUIC::create(
+                &mut b,
+                &flat::BindQUICArgs {
+                    accept_size,
+                    name: prefix,
+                    port: opt.port,
+                    domain,
+                    wildcard: opt.wildcard,
+                    names,
+                    token,
+                    lease_ms: opt.lease_ms(),
+                    ip_family: opt.ip_family.flat(),
+                    backlog: opt.backlog.unwrap_or(0),
+                    client_cert: opt.client_cert.flat(),
+                    alpn,
+                },
+            )
+            .as_union_value(),
+        ),
+
+        Protocol::Dtls => (
+            flat::Function::BindDTLS,
+            flat::BindDTLS::create(
+                &mut b,
+                &flat::BindDTLSArgs {
+                    accept_size,
+                    name: prefix,
+                    port: opt.port,
+                    domain,
+                    wildcard: opt.wildcard,
+                    names,
+                    token,
+                    lease_ms: opt.lease_ms(),
+                    ip_family: opt.ip_family.flat(),
+                    backlog: opt.backlog.unwrap_or(0),
+                    client_cert: opt.client_cert.flat(),
+                    alpn,
+                    min_tls_version: TlsVersion::flat(opt.min_tls_version),
+                },
+            )
+            .as_union_value(),
+        ),
+    };
+
+    let call = flat::Call::create(
+        &mut b,
+        &flat::CallArgs {
+            function_type,
+            function: Some(function),
+        },
+    );
+
+    b.finish_minimal(call);
+
+    SERVICE
+        .call(b.finished_data(), |reply: &[u8]| {
+            if reply.is_empty() {
+                return Err(BindError::unsupported_call());
+            }
+
+            let r = match root::<flat::Binding>(reply) {
+                Ok(r) => r,
+                Err(_) => return Err(BindError::decode()),
+            };
+
+            if r.error() != flat::BindError::None {
+                return Err(BindError::new(r.error()));
+            }
+
+            let token = match r.token() {
+                Some(v) => v.bytes().to_vec(),
+                None => Vec::new(),
+            };
+
+            let mut addr = Binding::new(&r, protocol).ok_or_else(BindError::protocol_mismatch)?;
+            addr.label = opt.label.map(Into::into);
+
+            Ok((r.listen_id(), addr, BindingToken(token)))
+        })
+        .await
+}
+
+/// The updated binding inherits the protocol and the label of the old one.
+async fn update_binding(call: &[u8], old: &Binding) -> Result<Binding, BindError> {
+    SERVICE
+        .call(call, |reply: &[u8]| {
+            if reply.is_empty() {
+                return Err(BindError::unsupported_call());
+            }
+
+            let r = match root::<flat::Binding>(reply) {
+                Ok(r) => r,
+                Err(_) => return Err(BindError::decode()),
+            };
+
+            if r.error() != flat::BindError::None {
+                return Err(BindError::new(r.error()));
+            }
+
+            let mut addr =
+                Binding::new(&r, old.protocol).ok_or_else(BindError::protocol_mismatch)?;
+            addr.label = old.label.clone();
+            Ok(addr)
+        })
+        .await
+}
+
+/// The next smaller accept frame variant, for services which don't support
+/// the larger ones.  Metadata which doesn't fit is not delivered.  Services
+/// which support size-prefixed frames never truncate metadata.
+fn fallback_accept_size(size: flat::AcceptSize) -> Option<flat::AcceptSize> {
+    match size {
+        flat::AcceptSize::Variable => Some(flat::AcceptSize::Extended),
+        flat::AcceptSize::Extended => Some(flat::AcceptSize::Named),
+        flat::AcceptSize::Named => Some(flat::AcceptSize::Basic),
+        _ => None,
+    }
+}
+
+/// Limit a preferred fixed accept frame size.  A variable size isn't a limit;
+/// it replaces the fixed size.
+fn min_accept_size(size: flat::AcceptSize, max: flat::AcceptSize) -> flat::AcceptSize {
+    if max == flat::AcceptSize::Variable || max.0 < size.0 {
+        max
+    } else {
+        size
+    }
+}
+
+fn create_names<'b>(
+    b: &mut FlatBufferBuilder<'b>,
+    names: &[&str],
+) -> Option<WIPOffset<Vector<'b, ForwardsUOffset<&'b str>>>> {
+    if names.is_empty() {
+        return None;
+    }
+
+    let names: Vec<_> = names.iter().map(|s| b.create_string(s)).collect();
+    Some(b.create_vector(&names))
+}
+
+/// Resolves to the stream and whether it was closed.
+type ReceiveFuture = Pin<Box<dyn Future<Output = (RecvOnlyStream, bool)>>>;
+
+type DecodeFuture = Pin<Box<dyn Future<Output = Result<Accepted, AcceptError>>>>;
+
+/// The stream is moved into the reception future while a batch of accept
+/// frames is being received, so that reception can be resumed by a later
+/// poll.  Subscribed capacity can't be withdrawn, so the future is kept until
+/// the whole batch has arrived.  Complete frames are queued in the shared
+/// reader in the meantime.
+enum AcceptState {
+    Idle(RecvOnlyStream),
+    Receiving(ReceiveFuture),
+    Polling,
+}
+
+/// Connection acceptor.
+///
+/// Acceptors (like connections) are not `Send` or `Sync`, and can't be made
+/// so: the runtime's streams which they are built on keep their buffers in
+/// `Rc<RefCell<_>>`, and the runtime itself is single-threaded.  Tasks
+/// spawned with `gain::task::spawn_local` run on the same thread, so an
+/// acceptor can be moved into any task, or shared between tasks via
+/// `Rc<RefCell<_>>`.
+pub struct Acceptor {
+    listen_id: i32,
+    state: AcceptState,
+    reader: Rc<RefCell<FrameReader>>,
+    decoding: Option<DecodeFuture>,
+    filter: Option<Filter>,
+    limiter: Option<ConnLimiter>,
+    peer_limits: Option<PeerLimits>,
+    rate: Option<RateLimiter>,
+    ip_policy: Option<IpPolicy>,
+    prefetch: bool,
+    accept_size: flat::AcceptSize,
+    events: EventSink,
+    pub addr: Binding,
+}
+
+impl Acceptor {
+    /// Accept a client connection.  An `AcceptErrorKind::Closed` error may be
+    /// caused by the associated `CloseStream`, or other environmental reasons.
+    pub async fn accept(&mut self) -> Result<Conn, AcceptError> {
+        poll_fn(|cx| self.poll_accept(cx)).await
+    }
+
+    /// Wait for a client connection, and then accept up to `max - 1` more
+    /// without waiting.  Reception capacity for `max` connections is
+    /// subscribed at once (if no reception is already in progress), and
+    /// connections which arrive after this returns are picked up by
+    /// subsequent calls.  Collection stops at the first error, which is
+    /// included as the last item.  The result is empty only if `max` is zero.
+    pub async fn accept_many(&mut self, max: usize) -> Vec<Result<Conn, AcceptError>> {
+        let mut results = Vec::new();
+
+        if max == 0 {
+            return results;
+        }
+
+        poll_fn(|cx| {
+            while results.len() < max {
+                match self.poll_accept_batch(cx, max - results.len()) {
+                    Poll::Ready(Ok(conn)) => results.push(Ok(conn)),
+                    Poll::Ready(Err(e)) => {
+                        results.push(Err(e));
+                        break;
+                    }
+                    Poll::Pending if results.is_empty() => return Poll::Pending,
+                    Poll::Pending => break,
+                }
+            }
+
+            Poll::Ready(())
+        })
+        .await;
+
+        results
+    }
+
+    /// Accept a client connection if one has already been received.  None is
+    /// returned immediately if no accept frame is buffered.  Reception is
+    /// initiated by the first call, so data which arrives in the meantime is
+    /// picked up by a subsequent call (or by `accept`).
+    pub fn try_accept(&mut self) -> Result<Option<Conn>, AcceptError> {
+        match self.poll_accept(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(result) => result.map(Some),
+            Poll::Pending => Ok(None),
+        }
+    }
+
+    /// Poll for a client connection.  This is the non-async variant of
+    /// `accept`.  Reception of a connection which is in progress is not
+    /// cancelled if `poll_accept` isn't called again; it resumes on the next
+    /// call to either method.
+    pub fn poll_accept(&mut self, cx: &mut Context) -> Poll<Result<Conn, AcceptError>> {
+        self.poll_accept_batch(cx, 1)
+    }
+
+    /// Like `poll_accept`, but if reception needs to be started, subscribe
+    /// capacity for `batch` connections.
+    fn poll_accept_batch(
+        &mut self,
+        cx: &mut Context,
+        batch: usize,
+    ) -> Poll<Result<Conn, AcceptError>> {
+        loop {
+            let result = match self.decoding.as_mut() {
+                Some(future) => match future.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(result) => {
+                        self.decoding = None;
+                        result
+                    }
+                },
+
+                None => {
+                    // Received frames are held while the connection limit is
+                    // reached, but reception is kept pending so that the
+                    // limit is checked again when the runtime wakes us.
+                    let frame = if self.has_room() {
+                        self.reader.borrow_mut().pop()
+                    } else {
+                        None
+                    };
+                    let frame = match frame {
+                        Some(frame) => frame,
+                        None => match self.poll_receive(cx, batch) {
+                            Poll::Ready(true) if self.has_room() => continue,
+                            Poll::Ready(true) => return Poll::Pending,
+                            Poll::Ready(false) => Err(FrameError::Closed),
+                            Poll::Pending => return Poll::Pending,
+                        },
+                    };
+
+                    self.decoding = Some(self.decode(frame));
+                    continue;
+                }
+            };
+
+            match result {
+                Ok(Accepted::Conn(mut conn)) => {
+                    conn.permit = ConnPermit::new(
+                        self.limiter.as_ref(),
+                        self.peer_limits.as_ref(),
+                        conn.peer_addr.ip(),
+                    );
+                    conn.attach(&self.addr);
+
+                    if self.prefetch {
+                        self.prefetch_next(cx);
+                    }
+
+                    return Poll::Ready(Ok(*conn));
+                }
+                Ok(Accepted::Event(event)) => self.events.push(event),
+                Ok(Accepted::Rejected) => {}
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+    }
+
+    /// Drive reception until at least one frame has been queued (true) or the
+    /// stream has been closed (false).  Reception of a new batch of up to
+    /// `batch` frames (limited by the available connection permits, but at
+    /// least one) is started if necessary.
+    fn poll_receive(&mut self, cx: &mut Context, batch: usize) -> Poll<bool> {
+        loop {
+            let stream = match mem::replace(&mut self.state, AcceptState::Polling) {
+                AcceptState::Idle(stream) => {
+                    if !self.reader.borrow().frames.is_empty() {
+                        self.state = AcceptState::Idle(stream);
+                        return Poll::Ready(true);
+                    }
+                    stream
+                }
+
+                AcceptState::Receiving(mut future) => match future.as_mut().poll(cx) {
+                    Poll::Pending => {
+                        self.state = AcceptState::Receiving(future);
+                        if self.reader.borrow().frames.is_empty() {
+                            return Poll::Pending;
+                        }
+                        return Poll::Ready(true);
+                    }
+
+                    Poll::Ready((stream, closed)) => {
+                        self.state = AcceptState::Idle(stream);
+                        if !self.reader.borrow().frames.is_empty() {
+                            return Poll::Ready(true);
+                        }
+                        if closed {
+                            return Poll::Ready(false);
+                        }
+                        continue;
+                    }
+                },
+
+                AcceptState::Polling => unreachable!("acceptor polled recursively"),
+            };
+
+            let count = match &self.limiter {
+                Some(limiter) => batch.min(limiter.available()).max(1),
+                None => batch.max(1),
+            };
+
+            self.state = AcceptState::Receiving(self.receive(stream, count));
+        }
+    }
+
+    /// Start receiving the next connection as soon as one has been accepted,
+    /// so that the service round-trip overlaps with the handling of the
+    /// accepted connection.  Limits and filters are applied as usual.
+    pub fn set_prefetch(&mut self, prefetch: bool) {
+        self.prefetch = prefetch;
+    }
+
+    fn prefetch_next(&mut self, cx: &mut Context) {
+        if matches!(self.state, AcceptState::Idle(_)) {
+            let _ = self.poll_receive(cx, 1);
+        }
+    }
+
+    /// Check the connection limit.
+    fn has_room(&self) -> bool {
+        match &self.limiter {
+            Some(limiter) => limiter.available() > 0,
+            None => true,
+        }
+    }
+
+    fn receive(&self, mut stream: RecvOnlyStream, count: usize) -> ReceiveFuture {
+        let reader = self.reader.clone();
+        let capacity = reader.borrow_mut().start(count);
+
+        Box::pin(async move {
+            let closed = stream
+                .recv(capacity, move |data: &[u8], _: i32| {
+                    reader.borrow_mut().feed(data)
+                })
+                .await
+                .is_some();
+
+            (stream, closed)
+        })
+    }
+
+    fn decode(&self, frame: Result<Vec<u8>, FrameError>) -> DecodeFuture {
+        let size = self.accept_size;
+        let ip_policy = self.ip_policy.clone();
+        let filter = self.filter.clone();
+        let peer_limits = self.peer_limits.clone();
+        let rate = self.rate.clone();
+
+        Box::pin(async move {
+            let check = |addr: &SocketAddr| {
+                if let Some(policy) = &ip_policy {
+                    if policy.check(addr.ip()) == Verdict::Reject {
+                        return Verdict::Reject;
+                    }
+                }
+
+                if let Some(f) = &filter {
+                    if f(addr) == Verdict::Reject {
+                        return Verdict::Reject;
+                    }
+                }
+
+                if let Some(limits) = &peer_limits {
+                    if limits.check(addr.ip()) == Verdict::Reject {
+                        return Verdict::Reject;
+                    }
+                }
+
+                match &rate {
+                    Some(rate) => rate.check(),
+                    None => Verdict::Accept,
+                }
+            };
+
+            decode_accept(frame, size, Some(&check)).await
+        })
+    }
+
+    /// Identifier of the listener's service stream.  See `Listener::id`.
+    pub fn id(&self) -> i32 {
+        self.listen_id
+    }
+
+    /// Subscribe to events which don't result in connections.  See
+    /// `Listener::events`.
+    pub fn events(&self) -> ListenerEvents {
+        self.events.subscribe()
+    }
+
+    /// Release the binding and wait until the service has confirmed it.
+    /// Unlike closing the associated `CloseStream`, this doesn't return
+    /// before the address can be bound again.
+    pub async fn close_acked(self) -> Result<(), UnbindError> {
+        unbind(self.listen_id).await
+    }
+}
+
+/// Detached closing functionality of a listener.  See `Listener::split_acked`.
+pub struct ListenerCloser {
+    listen_id: i32,
+    stream: CloseStream,
+}
+
+impl ListenerCloser {
+    /// Close the listener and wait until the service has confirmed that the
+    /// binding has been released, so that the address can be bound again.
+    pub async fn close_and_wait(mut self) -> Result<(), UnbindError> {
+        self.stream.close().await;
+
+        match unbind(self.listen_id).await {
+            Err(e) if e.kind() == UnbindErrorKind::NotBound => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Unwrap the plain `CloseStream`.
+    pub fn into_inner(self) -> CloseStream {
+        self.stream
+    }
+}
+
+async fn unbind(listen_id: i32) -> Result<(), UnbindError> {
+    let mut b = FlatBufferBuilder::new();
+
+    let function = flat::Unbind::create(&mut b, &flat::UnbindArgs { listen_id });
+
+    let call = flat::Call::create(
+        &mut b,
+        &flat::CallArgs {
+            function_type: flat::Function::Unbind,
+            function: Some(function.as_union_value()),
+        },
+    );
+
+    b.finish_minimal(call);
+
+    SERVICE
+        .call(b.finished_data(), |reply: &[u8]| {
+            if reply.is_empty() {
+                return Err(UnbindError::unsupported_call());
+            }
+
+            let r = match root::<flat::Unbinding>(reply) {
+                Ok(r) => r,
+                Err(_) => return Err(UnbindError::decode()),
+            };
+
+            if r.error() != flat::UnbindError::None {
+                return Err(UnbindError::new(r.error()));
+            }
+
+            Ok(())
+        })
+        .await
+}
+
+/// Receive accept frames until a connection is accepted.  Frames which don't
+/// represent connections are reported to `events` and skipped.
+async fn accept<R: Recv>(
+    stream: &mut R,
+    size: flat::AcceptSize,
+    events: Option<&EventSink>,
+) -> Result<Conn, AcceptError> {
+    loop {
+        match accept_frame(stream, size, None).await? {
+            Accepted::Conn(conn) => return Ok(*conn),
+            Accepted::Rejected => {}
+            Accepted::Event(event) => {
+                if let Some(events) = events {
+                    events.push(event);
+                }
+            }
+        }
+    }
+}
+
+enum Accepted {
+    Conn(Box<Conn>),
+    Event(ListenerEvent),
+    Rejected,
+}
+
+async fn accept_frame<R: Recv>(
+    stream: &mut R,
+    size: flat::AcceptSize,
+    filter: Option<&dyn Fn(&SocketAddr) -> Verdict>,
+) -> Result<Accepted, AcceptError> {
+    let frame = recv_one(stream, FrameReader::accept(size)).await;
+    decode_accept(frame, size, filter).await
+}
+
+async fn decode_accept(
+    frame: Result<Vec<u8>, FrameError>,
+    size: flat::AcceptSize,
+    filter: Option<&dyn Fn(&SocketAddr) -> Verdict>,
+) -> Result<Accepted, AcceptError> {
+    // Size-prefixed frames may be larger than what this version of the crate
+    // knows about; unknown fields are ignored by the flatbuffer parser.
+    let frame = match frame {
+        Ok(b) => b,
+        Err(FrameError::Closed) => return Err(AcceptError::listener_closed()),
+        Err(FrameError::TooLarge) => return Err(AcceptError::decode()),
+    };
+
+    let a = match size {
+        flat::AcceptSize::Variable => size_prefixed_root::<flat::Accept>(&frame),
+        _ => root::<flat::Accept>(&frame),
+    };
+    let a = match a {
+        Ok(a) => a,
+        Err(_) => return Err(AcceptError::decode()),
+    };
+    let r = match a.basic() {
+        Some(r) => r,
+        None => return Err(AcceptError::protocol()),
+    };
+    let peer_addr = socket_addr(r.addr(), r.port());
+
+    match r.error() {
+        flat::AcceptError::None if filter.is_some_and(|f| f(&peer_addr) == Verdict::Reject) => {
+            reject_conn(r.conn_id()).await;
+            Ok(Accepted::Rejected)
+        }
+
+        flat::AcceptError::None => Ok(Accepted::Conn(Box::new(Conn {
+            _internal: (),
+            id: r.conn_id(),
+            stream: SERVICE.stream(r.conn_id()),
+            peer_addr,
+            subdomain: a.subdomain().map(Into::into),
+            prefix: a.prefix().map(Into::into),
+            client_identity: ClientIdentity::new(&a),
+            alpn: a.alpn().map(Into::into),
+            meta: ConnMeta::new(&a),
+            early_data: a.early_data(),
+            permit: None,
+            label: None,
+            extensions: Extensions::new(),
+            local_addr: a.local_addr().map(|ip| socket_addr(ip, a.local_port())),
+            accepted: SystemTime::now(),
+            hostname: String::new(),
+            port: 0,
+            recv: Default::default(),
+            counters: Default::default(),
+            io: None,
+        }))),
+
+        flat::AcceptError::SNIRejected => Ok(Accepted::Event(ListenerEvent::SniRejected(
+            SniRejection::new(peer_addr, a.server_name().map(Into::into)),
+        ))),
+
+        error => Err(AcceptError::new(error)),
+    }
+}
+
+/// Why a size-prefixed frame wasn't received.
+#[derive(Debug, PartialEq)]
+enum FrameError {
+    /// The stream was closed before a complete frame was received.
+    Closed,
+    /// The size prefix exceeded `MAX_FRAME_SIZE`.  The frame was received
+    /// and discarded, so the stream is still in sync.
+    TooLarge,
+}
+
+/// Incremental reader for a batch of accept or size-prefixed frames.
+/// Received data is fed to it and it tells how much more reception capacity
+/// to subscribe; complete frames are queued.  Size-prefixed frames are
+/// subscribed one at a time (the prefix of the next frame together with the
+/// body of the current one), so that capacity never exceeds the batch.
+struct FrameReader {
+    fixed: Option<usize>, // Frame size if not size-prefixed.
+    unstarted: usize,     // Frames in the batch not subscribed yet.
+    buffer: Vec<u8>,
+    size: Option<usize>, // Including prefix, once known.
+    received: usize,     // Of the current frame.
+    too_large: bool,
+    frames: VecDeque<Result<Vec<u8>, FrameError>>,
+}
+
+impl FrameReader {
+    /// Reader for size-prefixed frames.
+    fn prefixed() -> Self {
+        Self::new(None)
+    }
+
+    /// Reader for accept frames of the given size.
+    fn accept(size: flat::AcceptSize) -> Self {
+        match size {
+            flat::AcceptSize::Variable => Self::new(None),
+            _ => Self::new(Some(size.0 as usize)),
+        }
+    }
+
+    fn new(fixed: Option<usize>) -> Self {
+        Self {
+            fixed,
+            unstarted: 0,
+            buffer: Vec::new(),
+            size: fixed,
+            received: 0,
+            too_large: false,
+            frames: VecDeque::new(),
+        }
+    }
+
+    /// Start receiving a batch of frames.  The previous batch must have been
+    /// received in full (or the stream closed).  Returns the initial
+    /// reception capacity.
+    fn start(&mut self, count: usize) -> usize {
+        self.buffer = Vec::new();
+        self.size = self.fixed;
+        self.received = 0;
+        self.too_large = false;
+
+        match self.fixed {
+            Some(size) => {
+                self.unstarted = 0;
+                count * size
+            }
+            None => {
+                self.unstarted = count.saturating_sub(1);
+                if count > 0 {
+                    SIZE_PREFIX
+                } else {
+                    0
+                }
+            }
+        }
+    }
+
+    /// Consume received data.  Returns additional reception capacity.
+    fn feed(&mut self, mut data: &[u8]) -> usize {
+        let mut more = 0;
+
+        while !data.is_empty() {
+            let n = (self.size.unwrap_or(SIZE_PREFIX) - self.received).min(data.len());
+            if !self.too_large {
+                self.buffer.extend_from_slice(&data[..n]);
+            }
+            self.received += n;
+            data = &data[n..];
+
+            if self.size.is_none() && self.received == SIZE_PREFIX {
+                let mut prefix = [0; SIZE_PREFIX];
+                prefix.copy_from_slice(&self.buffer[..SIZE_PREFIX]);
+                let size = u32::from_le_bytes(prefix) as usize;
+                if size > MAX_FRAME_SIZE {
+                    self.too_large = true;
+                    self.buffer = Vec::new();
+                } else {
+                    self.buffer.reserve_exact(size);
+                }
+                self.size = Some(SIZE_PREFIX + size);
+
+                more += size;
+                if self.unstarted > 0 {
+                    self.unstarted -= 1;
+                    more += SIZE_PREFIX;
+                }
+            }
+
+            if self.size == Some(self.received) {
+                let frame = mem::take(&mut self.buffer);
+                self.frames.push_back(if self.too_large {
+                    Err(FrameError::TooLarge)
+                } else {
+                    Ok(frame)
+                });
+
+                self.size = self.fixed;
+                self.received = 0;
+                self.too_large = false;
+            }
+        }
+
+        more
+    }
+
+    /// Take the oldest complete frame.
+    fn pop(&mut self) -> Option<Result<Vec<u8>, FrameError>> {
+        self.frames.pop_front()
+    }
+}
+
+/// Receive a size-prefixed frame.  The frame is returned including its
+/// prefix.
+async fn recv_frame<R: Recv>(stream: &mut R) -> Result<Vec<u8>, FrameError> {
+    recv_one(stream, FrameReader::prefixed()).await
+}
+
+async fn recv_one<R: Recv>(stream: &mut R, reader: FrameReader) -> Result<Vec<u8>, FrameError> {
+    let reader = RefCell::new(reader);
+    let capacity = reader.borrow_mut().start(1);
+
+    let _ = stream
+        .recv(capacity, |data: &[u8], _: i32| {
+            reader.borrow_mut().feed(data)
+        })
+        .await;
+
+    reader.into_inner().pop().unwrap_or(Err(FrameError::Closed))
+}
+
+fn socket_addr(ip: &flat::IPAddr, port: u16) -> SocketAddr {
+    SocketAddr::new(ip_addr(ip), port)
+}
+
+fn ip_addr(ip: &flat::IPAddr) -> IpAddr {
+    if ip.b() == 0 && ip.c() == 0 && ip.d() == 0 {
+        IpAddr::V4(Ipv4Addr::from(ip.a()))
+    } else {
+        let ipv6 = Ipv6Addr::new(
+            (ip.a() >> 16) as u16,
+            (ip.a() >> 0) as u16,
+            (ip.b() >> 16) as u16,
+            (ip.b() >> 0) as u16,
+            (ip.c() >> 16) as u16,
+            (ip.c() >> 0) as u16,
+            (ip.d() >> 16) as u16,
+            (ip.d() >> 0) as u16,
+        );
+        IpAddr::V6(ipv6)
+    }
+}
+
+fn flat_addr(addr: &SocketAddr) -> flat::IPAddr {
+    match addr {
+        SocketAddr::V4(a) => flat::IPAddr::new((*a.ip()).into(), 0, 0, 0),
+        SocketAddr::V6(a) => {
+            let s = a.ip().segments();
+            flat::IPAddr::new(
+                (s[0] as u32) << 16 | s[1] as u32,
+                (s[2] as u32) << 16 | s[3] as u32,
+                (s[4] as u32) << 16 | s[5] as u32,
+                (s[6] as u32) << 16 | s[7] as u32,
+            )
+        }
+    }
+}
+
+/// Client connection.
+pub struct Conn {
+    _internal: (),
+    id: i32,
+
+    /// I/O stream for exchanging data with the client.  It is taken over by
+    /// `Conn::split` and the `AsyncRead`/`AsyncWrite` implementations.
+    pub stream: RecvWriteStream,
 
-/// Connection listener.
-pub struct Listener {
-    stream: RecvStream,
-    pub addr: Binding,
-}
+    /// The client connection's address.
+    pub peer_addr: SocketAddr,
 
-impl Listener {
-    /// Listen to TLS connections at `BindOptions::port`.  The fully-qualified
-    /// DNS name can be discovered from the `Listener::addr.hostname` field.
-    ///
-    /// If specified, `BindOptions::prefix` is prepended to the server name.
-    /// Its length must be between 1 and 31 characters (inclusive), and it must
-    /// consist of lowercase alphanumeric ASCII characters and dashes (`-`).
-    /// It must not start or end with a dash, nor contain multiple consecutive
-    /// dashes.
-    pub async fn bind_tls(opt: BindOptions<'_>) -> Result<Self, BindError> {
-        let mut b = FlatBufferBuilder::new();
+    /// The subdomain requested by the client, if the listener was bound with
+    /// a wildcard prefix.
+    pub subdomain: Option<String>,
 
-        let prefix = match opt.prefix {
-            Some(s) => Some(b.create_string(s)),
-            None => None,
-        };
+    /// The server name prefix requested by the client, if the listener was
+    /// bound with multiple prefixes.
+    pub prefix: Option<String>,
 
-        let function = flat::BindTLS::create(
-            &mut b,
-            &flat::BindTLSArgs {
-                accept_size: flat::AcceptSize::Basic,
-                name: prefix,
-                port: opt.port,
-            },
-        );
+    /// The client's verified certificate, if one was requested with
+    /// `BindOptions::client_cert` and presented by the client.
+    pub client_identity: Option<ClientIdentity>,
 
-        let call = flat::Call::create(
-            &mut b,
-            &flat::CallArgs {
-                function_type: flat::Function::BindTLS,
-                function: Some(function.as_union_value()),
-            },
-        );
+    /// Application-layer protocol negotiated with the client, if
+    /// `BindOptions::alpn` was specified.
+    pub alpn: Option<String>,
 
-        b.finish_minimal(call);
+    /// Additional information about the connection.
+    pub meta: ConnMeta,
 
-        SERVICE
-            .call(b.finished_data(), |reply: &[u8]| {
-                if reply.is_empty() {
-                    return Err(BindError::unsupported_call());
-                }
+    /// The client sent data before the TLS handshake was complete.  Such data
+    /// may have been replayed, so requests with side effects should be
+    /// refused (e.g. with HTTP status 425).  Can be true only if
+    /// `BindOptions::early_data` is enabled.
+    pub early_data: bool,
 
-                let r = root::<flat::Binding>(reply).unwrap();
+    /// Connection limit share, if the acceptor has a `ConnLimiter`.  It
+    /// should be kept alive for as long as the connection is being served.
+    pub permit: Option<ConnPermit>,
 
-                if r.error() != flat::BindError::None {
-                    if r.error() == flat::BindError::InvalidAcceptSize {
-                        panic!("invalid accept size");
-                    }
-                    return Err(BindError::new(r.error()));
-                }
+    /// The label of the binding which the connection was accepted from.
+    pub label: Option<Rc<str>>,
 
-                let stream = SERVICE.input_stream(r.listen_id());
+    /// Per-connection state attached by the application.
+    pub extensions: Extensions,
+    local_addr: Option<SocketAddr>,
+    accepted: SystemTime,
+    hostname: String,
+    port: u16,
+    recv: RecvOptions,
+    counters: Rc<Counters>,
+    io: Option<Box<ConnIo>>,
+}
 
-                Ok(Self {
-                    stream: stream,
-                    addr: Binding {
-                        hostname: r.host().unwrap().into(),
-                        port: r.port(),
-                    },
-                })
-            })
-            .await
+impl Conn {
+    /// Identifier of the connection's service stream.  It appears in Gate
+    /// runtime logs and is known to the other end of the service.
+    pub fn id(&self) -> i32 {
+        self.id
     }
 
-    /// Accept a client connection.  An `AcceptErrorKind::Closed` error may
-    /// occur due to environmental causes.
-    pub async fn accept(&mut self) -> Result<Conn, AcceptError> {
-        accept(&mut self.stream).await
+    /// The service-side address which the client connected to, if reported
+    /// by the service.  It distinguishes between the endpoints of a binding
+    /// with multiple public addresses.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
     }
 
-    /// Detach the closing functionality.  When the `CloseStream` is closed or
-    /// dropped, the `Acceptor` will return an `AcceptErrorKind::Closed` error.
-    pub fn split(self) -> (Acceptor, CloseStream) {
-        let (stream, c) = self.stream.split();
-        (
-            Acceptor {
-                stream,
-                addr: self.addr,
-            },
-            c,
-        )
+    /// The server name requested by the client.  It is reported by the
+    /// service with extended accept frames (see `ConnMeta::server_name`).
+    /// Otherwise it is derived from the binding's hostname and the requested
+    /// subdomain; None is returned if that isn't possible, i.e. when the
+    /// listener serves multiple prefixes.
+    pub fn server_name(&self) -> Option<String> {
+        if let Some(name) = &self.meta.server_name {
+            return Some(name.clone());
+        }
+
+        if self.hostname.is_empty() || self.prefix.is_some() {
+            return None;
+        }
+
+        match &self.subdomain {
+            Some(sub) => Some(format!("{}.{}", sub, self.hostname)),
+            None => Some(self.hostname.clone()),
+        }
     }
-}
 
-/// Connection acceptor.
-pub struct Acceptor {
-    stream: RecvOnlyStream,
-    pub addr: Binding,
-}
+    /// Summary of the connection for logging and diagnostics.
+    pub fn info(&self) -> ConnInfo {
+        ConnInfo {
+            _internal: (),
+            peer_addr: self.peer_addr,
+            accepted: self.accepted,
+            hostname: self.hostname.clone(),
+            port: self.port,
+            id: self.id,
+        }
+    }
 
-impl Acceptor {
-    /// Accept a client connection.  An `AcceptErrorKind::Closed` error may be
-    /// caused by the associated `CloseStream`, or other environmental reasons.
-    pub async fn accept(&mut self) -> Result<Conn, AcceptError> {
-        accept(&mut self.stream).await
+    /// Associate the connection with the binding it was accepted from.
+    fn attach(&mut self, addr: &Binding) {
+        self.label = addr.label.clone();
+        self.hostname = addr.hostname.clone();
+        self.port = addr.port;
     }
 }
 
-async fn accept<R: Recv>(stream: &mut R) -> Result<Conn, AcceptError> {
-    let result = Cell::new(Some(Err(AcceptError::listener_closed())));
-    let buffer = RefCell::new(Vec::with_capacity(ACCEPT_SIZE));
-
-    let _ = stream
-        .recv(ACCEPT_SIZE, |data: &[u8], _: i32| {
-            let mut b = buffer.borrow_mut();
-            b.extend_from_slice(data);
+impl fmt::Debug for Conn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("Conn")
+            .field("id", &self.id)
+            .field("peer_addr", &self.peer_addr)
+            .field("local_addr", &self.local_addr)
+            .field("hostname", &self.hostname)
+            .field("port", &self.port)
+            .field("subdomain", &self.subdomain)
+            .field("prefix", &self.prefix)
+            .field("alpn", &self.alpn)
+            .field("early_data", &self.early_data)
+            .field("label", &self.label)
+            .finish_non_exhaustive()
+    }
+}
 
-            let more = ACCEPT_SIZE - b.len();
-            if more == 0 {
-                let r = root::<flat::Accept>(b.as_slice()).unwrap().basic().unwrap();
+/// Information about a connection.  See `Conn::info`.
+#[derive(Clone, Debug)]
+pub struct ConnInfo {
+    _internal: (),
 
-                result.set(Some(if r.error() == flat::AcceptError::None {
-                    let stream = SERVICE.stream(r.conn_id());
+    /// The client connection's address.
+    pub peer_addr: SocketAddr,
 
-                    let ip = r.addr();
-                    let addr = if ip.b() == 0 && ip.c() == 0 && ip.d() == 0 {
-                        SocketAddr::V4(SocketAddrV4::new(ip.a().into(), r.port()))
-                    } else {
-                        let ipv6 = Ipv6Addr::new(
-                            (ip.a() >> 16) as u16,
-                            (ip.a() >> 0) as u16,
-                            (ip.b() >> 16) as u16,
-                            (ip.b() >> 0) as u16,
-                            (ip.c() >> 16) as u16,
-                            (ip.c() >> 0) as u16,
-                            (ip.d() >> 16) as u16,
-                            (ip.d() >> 0) as u16,
-                        );
-                        SocketAddr::V6(SocketAddrV6::new(ipv6, r.port(), 0, 0))
-                    };
+    /// When the connection was accepted.
+    pub accepted: SystemTime,
 
-                    Ok(Conn {
-                        _internal: (),
-                        stream: stream,
-                        peer_addr: addr,
-                    })
-                } else {
-                    Err(AcceptError::new(r.error()))
-                }));
-            }
+    /// Hostname of the binding which the connection was accepted from.
+    /// Empty if it isn't known.
+    pub hostname: String,
 
-            more
-        })
-        .await;
+    /// Port of the binding which the connection was accepted from.  Zero if
+    /// it isn't known.
+    pub port: u16,
 
-    result.take().unwrap()
+    /// Identifier of the connection's service stream.  See `Conn::id`.
+    pub id: i32,
 }
 
-/// Client connection.
-pub struct Conn {
+/// Connection metadata.  Fields are populated only if the service supports
+/// an accept frame variant which carries them; see `Listener::accept_size`.
+pub struct ConnMeta {
     _internal: (),
 
-    /// I/O stream for exchanging data with the client.
-    pub stream: RecvWriteStream,
+    /// Server name indicated by the client during TLS handshake.
+    pub server_name: Option<String>,
 
-    /// The client connection's address.
-    pub peer_addr: SocketAddr,
+    /// TLS version negotiated with the client.
+    pub tls_version: Option<TlsVersion>,
+
+    /// IANA identifier of the TLS cipher suite negotiated with the client.
+    pub cipher_suite: Option<u16>,
+
+    /// Kind of TLS handshake performed with the client.
+    pub handshake: Option<HandshakeKind>,
+}
+
+impl ConnMeta {
+    fn new(a: &flat::Accept) -> Self {
+        Self {
+            _internal: (),
+            server_name: a.server_name().map(Into::into),
+            tls_version: TlsVersion::new(a.tls_version()),
+            cipher_suite: Some(a.cipher_suite()).filter(|&x| x != 0),
+            handshake: HandshakeKind::new(a.handshake()),
+        }
+    }
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -238,8 +2191,18 @@ pub enum BindErrorKind {
     InvalidName,
     NameTooLong,
     UnsupportedPort,
+    DomainNotVerified,
+    InvalidToken,
+    LeaseExpired,
+    UnsupportedTlsPolicy,
+    ProtocolMismatch,
+    Decode,
 }
 
+/// Error codes which are not used by the service.
+const BIND_PROTOCOL_MISMATCH: flat::BindError = flat::BindError(-1);
+const BIND_DECODE: flat::BindError = flat::BindError(-2);
+
 #[derive(Debug)]
 pub struct BindError {
     flat: flat::BindError,
@@ -254,6 +2217,14 @@ impl BindError {
         Self::new(flat::BindError::None)
     }
 
+    fn protocol_mismatch() -> Self {
+        Self::new(BIND_PROTOCOL_MISMATCH)
+    }
+
+    fn decode() -> Self {
+        Self::new(BIND_DECODE)
+    }
+
     pub fn kind(&self) -> BindErrorKind {
         match self.flat {
             flat::BindError::TooManyBindings => BindErrorKind::TooManyBindings,
@@ -261,6 +2232,13 @@ impl BindError {
             flat::BindError::InvalidName => BindErrorKind::InvalidName,
             flat::BindError::NameTooLong => BindErrorKind::NameTooLong,
             flat::BindError::UnsupportedPort => BindErrorKind::UnsupportedPort,
+            flat::BindError::DomainNotVerified => BindErrorKind::DomainNotVerified,
+            flat::BindError::InvalidToken => BindErrorKind::InvalidToken,
+            flat::BindError::LeaseExpired => BindErrorKind::LeaseExpired,
+            flat::BindError::UnsupportedTLSPolicy => BindErrorKind::UnsupportedTlsPolicy,
+            flat::BindError::InvalidAcceptSize => BindErrorKind::ProtocolMismatch,
+            BIND_PROTOCOL_MISMATCH => BindErrorKind::ProtocolMismatch,
+            BIND_DECODE => BindErrorKind::Decode,
             _ => BindErrorKind::Other,
         }
     }
@@ -278,6 +2256,182 @@ impl fmt::Display for BindError {
             BindErrorKind::InvalidName => f.write_str("invalid name"),
             BindErrorKind::NameTooLong => f.write_str("name too long"),
             BindErrorKind::UnsupportedPort => f.write_str("unsupported port"),
+            BindErrorKind::DomainNotVerified => f.write_str("domain not verified"),
+            BindErrorKind::InvalidToken => f.write_str("invalid token"),
+            BindErrorKind::LeaseExpired => f.write_str("lease expired"),
+            BindErrorKind::UnsupportedTlsPolicy => f.write_str("unsupported TLS policy"),
+            BindErrorKind::ProtocolMismatch => f.write_str("protocol mismatch"),
+            BindErrorKind::Decode => f.write_str("malformed reply"),
+            _ => self.as_i16().fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum UnbindErrorKind {
+    Other,
+    NotBound,
+    Decode,
+}
+
+/// Error code which is not used by the service.
+const UNBIND_DECODE: flat::UnbindError = flat::UnbindError(-1);
+
+#[derive(Debug)]
+pub struct UnbindError {
+    flat: flat::UnbindError,
+}
+
+impl UnbindError {
+    fn new(flat: flat::UnbindError) -> Self {
+        Self { flat }
+    }
+
+    fn unsupported_call() -> Self {
+        Self::new(flat::UnbindError::None)
+    }
+
+    fn decode() -> Self {
+        Self::new(UNBIND_DECODE)
+    }
+
+    pub fn kind(&self) -> UnbindErrorKind {
+        match self.flat {
+            flat::UnbindError::NotBound => UnbindErrorKind::NotBound,
+            UNBIND_DECODE => UnbindErrorKind::Decode,
+            _ => UnbindErrorKind::Other,
+        }
+    }
+
+    pub fn as_i16(&self) -> i16 {
+        self.flat.0
+    }
+}
+
+impl fmt::Display for UnbindError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.kind() {
+            UnbindErrorKind::NotBound => f.write_str("not bound"),
+            UnbindErrorKind::Decode => f.write_str("malformed reply"),
+            _ => self.as_i16().fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum CloseErrorKind {
+    Other,
+    NotConnected,
+    NotAcknowledged,
+    Write,
+    Decode,
+}
+
+/// Error codes which are not used by the service.
+const CLOSE_WRITE: flat::CloseError = flat::CloseError(-1);
+const CLOSE_DECODE: flat::CloseError = flat::CloseError(-2);
+
+#[derive(Debug)]
+pub struct CloseError {
+    flat: flat::CloseError,
+}
+
+impl CloseError {
+    fn new(flat: flat::CloseError) -> Self {
+        Self { flat }
+    }
+
+    fn unsupported_call() -> Self {
+        Self::new(flat::CloseError::None)
+    }
+
+    fn write() -> Self {
+        Self::new(CLOSE_WRITE)
+    }
+
+    fn decode() -> Self {
+        Self::new(CLOSE_DECODE)
+    }
+
+    pub fn kind(&self) -> CloseErrorKind {
+        match self.flat {
+            flat::CloseError::NotConnected => CloseErrorKind::NotConnected,
+            flat::CloseError::NotAcknowledged => CloseErrorKind::NotAcknowledged,
+            CLOSE_WRITE => CloseErrorKind::Write,
+            CLOSE_DECODE => CloseErrorKind::Decode,
+            _ => CloseErrorKind::Other,
+        }
+    }
+
+    pub fn as_i16(&self) -> i16 {
+        self.flat.0
+    }
+}
+
+impl fmt::Display for CloseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.kind() {
+            CloseErrorKind::NotConnected => f.write_str("not connected"),
+            CloseErrorKind::NotAcknowledged => f.write_str("close not acknowledged by peer"),
+            CloseErrorKind::Write => f.write_str("write failed"),
+            CloseErrorKind::Decode => f.write_str("malformed reply"),
+            _ => self.as_i16().fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ExportErrorKind {
+    Other,
+    NotConnected,
+    NotTls,
+    InvalidLength,
+    Decode,
+}
+
+/// Error code which is not used by the service.
+const EXPORT_DECODE: flat::ExportError = flat::ExportError(-1);
+
+#[derive(Debug)]
+pub struct ExportError {
+    flat: flat::ExportError,
+}
+
+impl ExportError {
+    fn new(flat: flat::ExportError) -> Self {
+        Self { flat }
+    }
+
+    fn unsupported_call() -> Self {
+        Self::new(flat::ExportError::None)
+    }
+
+    fn decode() -> Self {
+        Self::new(EXPORT_DECODE)
+    }
+
+    pub fn kind(&self) -> ExportErrorKind {
+        match self.flat {
+            flat::ExportError::NotConnected => ExportErrorKind::NotConnected,
+            flat::ExportError::NotTLS => ExportErrorKind::NotTls,
+            flat::ExportError::InvalidLength => ExportErrorKind::InvalidLength,
+            EXPORT_DECODE => ExportErrorKind::Decode,
+            _ => ExportErrorKind::Other,
+        }
+    }
+
+    pub fn as_i16(&self) -> i16 {
+        self.flat.0
+    }
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.kind() {
+            ExportErrorKind::NotConnected => f.write_str("not connected"),
+            ExportErrorKind::NotTls => f.write_str("connection is not terminated as TLS"),
+            ExportErrorKind::InvalidLength => f.write_str("invalid keying material length"),
+            ExportErrorKind::Decode => f.write_str("malformed reply"),
             _ => self.as_i16().fmt(f),
         }
     }
@@ -287,8 +2441,20 @@ impl fmt::Display for BindError {
 pub enum AcceptErrorKind {
     Closed,
     Other,
+    LeaseExpired,
+    ResourceExhausted,
+    HandshakeFailed,
+    PolicyRejected,
+    Suspended,
+    Protocol,
+    Decode,
 }
 
+/// Error codes which are not used by the service.
+const ACCEPT_PROTOCOL: flat::AcceptError = flat::AcceptError(-2);
+const ACCEPT_DECODE: flat::AcceptError = flat::AcceptError(-3);
+const ACCEPT_UNSUPPORTED_CALL: flat::AcceptError = flat::AcceptError(-4);
+
 #[derive(Debug)]
 pub struct AcceptError {
     flat: flat::AcceptError,
@@ -303,10 +2469,29 @@ impl AcceptError {
         Self::new(flat::AcceptError::None)
     }
 
+    pub(crate) fn protocol() -> Self {
+        Self::new(ACCEPT_PROTOCOL)
+    }
+
+    pub(crate) fn decode() -> Self {
+        Self::new(ACCEPT_DECODE)
+    }
+
+    pub(crate) fn unsupported_call() -> Self {
+        Self::new(ACCEPT_UNSUPPORTED_CALL)
+    }
+
     pub fn kind(&self) -> AcceptErrorKind {
         #[allow(unreachable_patterns)]
         match self.flat {
             flat::AcceptError::None => AcceptErrorKind::Closed,
+            flat::AcceptError::LeaseExpired => AcceptErrorKind::LeaseExpired,
+            flat::AcceptError::ResourceExhausted => AcceptErrorKind::ResourceExhausted,
+            flat::AcceptError::HandshakeFailed => AcceptErrorKind::HandshakeFailed,
+            flat::AcceptError::PolicyRejected => AcceptErrorKind::PolicyRejected,
+            flat::AcceptError::Suspended => AcceptErrorKind::Suspended,
+            ACCEPT_PROTOCOL => AcceptErrorKind::Protocol,
+            ACCEPT_DECODE => AcceptErrorKind::Decode,
             _ => AcceptErrorKind::Other,
         }
     }
@@ -320,7 +2505,180 @@ impl fmt::Display for AcceptError {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self.kind() {
             AcceptErrorKind::Closed => f.write_str("closed"),
+            AcceptErrorKind::LeaseExpired => f.write_str("lease expired"),
+            AcceptErrorKind::ResourceExhausted => f.write_str("resource exhausted"),
+            AcceptErrorKind::HandshakeFailed => f.write_str("handshake failed"),
+            AcceptErrorKind::PolicyRejected => f.write_str("rejected by policy"),
+            AcceptErrorKind::Suspended => f.write_str("suspended"),
+            AcceptErrorKind::Protocol => f.write_str("protocol error"),
+            AcceptErrorKind::Decode => f.write_str("malformed frame"),
             _ => self.as_i16().fmt(f),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepted_prefixes() {
+        for prefix in [
+            "a",
+            "0",
+            "api",
+            "api-v2",
+            "a-b-c",
+            &"x".repeat(MAX_PREFIX_LEN),
+        ] {
+            assert_eq!(validate_prefix(prefix), Ok(()), "{prefix}");
+            assert!(check_prefix(prefix).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejected_prefixes() {
+        let long = "x".repeat(MAX_PREFIX_LEN + 1);
+
+        for (prefix, error) in [
+            ("", PrefixError::Empty),
+            (&long, PrefixError::TooLong),
+            ("Api", PrefixError::InvalidChar),
+            ("a.b", PrefixError::InvalidChar),
+            ("a_b", PrefixError::InvalidChar),
+            ("\u{e4}", PrefixError::InvalidChar),
+            ("-a", PrefixError::EdgeDash),
+            ("a-", PrefixError::EdgeDash),
+            ("-", PrefixError::EdgeDash),
+            ("xn--nxa", PrefixError::Punycode),
+            ("a--b", PrefixError::ConsecutiveDashes),
+        ] {
+            assert_eq!(validate_prefix(prefix), Err(error), "{prefix}");
+        }
+
+        assert_eq!(
+            check_prefix(&long).unwrap_err().kind(),
+            BindErrorKind::NameTooLong
+        );
+        assert_eq!(
+            check_prefix("a--b").unwrap_err().kind(),
+            BindErrorKind::InvalidName
+        );
+    }
+
+    /// Start a batch of `count` frames and feed `data` to the reader `step`
+    /// bytes at a time, never more than has been subscribed.  Returns the
+    /// unreceived capacity.
+    fn feed(r: &mut FrameReader, count: usize, data: &[u8], step: usize) -> usize {
+        let mut capacity = r.start(count);
+        let mut data = data;
+        while capacity > 0 && !data.is_empty() {
+            let n = step.min(capacity).min(data.len());
+            capacity -= n;
+            capacity += r.feed(&data[..n]);
+            data = &data[n..];
+        }
+        capacity
+    }
+
+    fn frame(body: &[u8]) -> Vec<u8> {
+        let mut b = (body.len() as u32).to_le_bytes().to_vec();
+        b.extend_from_slice(body);
+        b
+    }
+
+    #[test]
+    fn frame_reader_subscribes_exact_frame() {
+        let f = frame(b"hello");
+        for step in [1, 2, 3, 64] {
+            let mut data = f.clone();
+            data.extend_from_slice(&frame(b"next"));
+
+            let mut r = FrameReader::prefixed();
+            assert_eq!(feed(&mut r, 1, &data, step), 0);
+            assert_eq!(r.pop(), Some(Ok(f.clone())));
+            assert_eq!(r.pop(), None);
+        }
+    }
+
+    #[test]
+    fn frame_reader_empty_frame() {
+        let mut r = FrameReader::prefixed();
+        assert_eq!(feed(&mut r, 1, &frame(b""), 1), 0);
+        assert_eq!(r.pop(), Some(Ok(frame(b""))));
+    }
+
+    #[test]
+    fn frame_reader_closed() {
+        let f = frame(b"hello");
+        for n in 0..f.len() {
+            let mut r = FrameReader::prefixed();
+            feed(&mut r, 1, &f[..n], 1);
+            assert_eq!(r.pop(), None);
+        }
+    }
+
+    #[test]
+    fn frame_reader_discards_too_large() {
+        let f = frame(&vec![0; MAX_FRAME_SIZE]);
+        let mut r = FrameReader::prefixed();
+        feed(&mut r, 1, &f, 4096);
+        assert_eq!(r.pop(), Some(Ok(f)));
+
+        let mut f = frame(&vec![0; MAX_FRAME_SIZE + 1]);
+        f.extend_from_slice(&frame(b"next"));
+        let mut r = FrameReader::prefixed();
+        assert_eq!(feed(&mut r, 2, &f, 4096), 0);
+        assert_eq!(r.pop(), Some(Err(FrameError::TooLarge)));
+        assert_eq!(r.pop(), Some(Ok(frame(b"next"))));
+
+        let mut r = FrameReader::prefixed();
+        assert_eq!(
+            feed(&mut r, 1, &u32::MAX.to_le_bytes(), 1),
+            u32::MAX as usize
+        );
+        assert_eq!(r.pop(), None);
+    }
+
+    #[test]
+    fn frame_reader_batch() {
+        let frames = [frame(b"a"), frame(b""), frame(b"bcd"), frame(b"e")];
+        let data = frames.concat();
+
+        for step in [1, 5, data.len()] {
+            let mut r = FrameReader::prefixed();
+            assert_eq!(feed(&mut r, 3, &data, step), 0);
+            for f in &frames[..3] {
+                assert_eq!(r.pop().as_ref(), Some(&Ok(f.clone())));
+            }
+            assert_eq!(r.pop(), None);
+        }
+
+        // Only part of the batch arrives; capacity is left for the rest.
+        let mut r = FrameReader::prefixed();
+        assert_eq!(feed(&mut r, 8, &data, data.len()), SIZE_PREFIX);
+        assert_eq!(r.frames.len(), frames.len());
+
+        // A new batch after the stream was closed mid-frame.
+        let mut r = FrameReader::prefixed();
+        feed(&mut r, 2, &data[..7], 1);
+        assert_eq!(r.pop(), Some(Ok(frames[0].clone())));
+        assert_eq!(feed(&mut r, 1, &frames[3], 1), 0);
+        assert_eq!(r.pop(), Some(Ok(frames[3].clone())));
+    }
+
+    #[test]
+    fn frame_reader_fixed_batch() {
+        let data: Vec<u8> = (0..10).collect();
+
+        let mut r = FrameReader::new(Some(3));
+        assert_eq!(r.start(3), 9);
+        assert_eq!(r.feed(&data[..2]), 0);
+        assert_eq!(r.pop(), None);
+        assert_eq!(r.feed(&data[2..9]), 0);
+        assert_eq!(r.pop(), Some(Ok(vec![0, 1, 2])));
+        assert_eq!(r.pop(), Some(Ok(vec![3, 4, 5])));
+        assert_eq!(r.pop(), Some(Ok(vec![6, 7, 8])));
+        assert_eq!(r.pop(), None);
+    }
+}
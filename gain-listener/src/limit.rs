@@ -0,0 +1,244 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{Acceptor, Verdict};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::rc::Rc;
+
+struct Shared {
+    max: usize,
+    active: usize,
+}
+
+/// Limit on the number of concurrent connections.  Clones share the limit,
+/// so a single limiter can be installed on multiple acceptors.
+#[derive(Clone)]
+pub struct ConnLimiter {
+    shared: Rc<RefCell<Shared>>,
+}
+
+impl ConnLimiter {
+    /// Allow `max` concurrent connections.
+    pub fn new(max: usize) -> Self {
+        Self {
+            shared: Rc::new(RefCell::new(Shared { max, active: 0 })),
+        }
+    }
+
+    /// Number of connections whose permits haven't been dropped.
+    pub fn active(&self) -> usize {
+        self.shared.borrow().active
+    }
+
+    /// Maximum number of concurrent connections.
+    pub fn max(&self) -> usize {
+        self.shared.borrow().max
+    }
+
+    /// Number of connections which can be accepted before reaching the limit.
+    pub(crate) fn available(&self) -> usize {
+        let shared = self.shared.borrow();
+        shared.max.saturating_sub(shared.active)
+    }
+
+    fn acquire(&self) -> Rc<RefCell<Shared>> {
+        self.shared.borrow_mut().active += 1;
+        self.shared.clone()
+    }
+}
+
+#[derive(Default)]
+struct PeerShared {
+    max: usize,
+    active: HashMap<IpAddr, usize>,
+    rejected: u64,
+}
+
+/// Limit on the number of concurrent connections per client IP address.
+/// Clones share the counters.
+#[derive(Clone)]
+pub struct PeerLimits {
+    shared: Rc<RefCell<PeerShared>>,
+}
+
+impl PeerLimits {
+    /// Allow `max` concurrent connections from each address.
+    pub fn new(max: usize) -> Self {
+        Self {
+            shared: Rc::new(RefCell::new(PeerShared {
+                max,
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Number of active connections from an address.
+    pub fn active(&self, ip: IpAddr) -> usize {
+        self.shared.borrow().active.get(&ip).copied().unwrap_or(0)
+    }
+
+    /// Number of distinct addresses with active connections.
+    pub fn peers(&self) -> usize {
+        self.shared.borrow().active.len()
+    }
+
+    /// Number of connections rejected due to the limit.
+    pub fn rejected(&self) -> u64 {
+        self.shared.borrow().rejected
+    }
+
+    pub(crate) fn check(&self, ip: IpAddr) -> Verdict {
+        let mut shared = self.shared.borrow_mut();
+
+        if shared.active.get(&ip).copied().unwrap_or(0) < shared.max {
+            Verdict::Accept
+        } else {
+            shared.rejected += 1;
+            Verdict::Reject
+        }
+    }
+
+    fn acquire(&self, ip: IpAddr) -> Rc<RefCell<PeerShared>> {
+        *self.shared.borrow_mut().active.entry(ip).or_insert(0) += 1;
+        self.shared.clone()
+    }
+}
+
+/// Share of connection limits.  The connection counts against the limits of
+/// its acceptor until the permit is dropped.
+pub struct ConnPermit {
+    limiter: Option<Rc<RefCell<Shared>>>,
+    peer: Option<(Rc<RefCell<PeerShared>>, IpAddr)>,
+}
+
+impl ConnPermit {
+    pub(crate) fn new(
+        limiter: Option<&ConnLimiter>,
+        peer_limits: Option<&PeerLimits>,
+        ip: IpAddr,
+    ) -> Option<Self> {
+        if limiter.is_none() && peer_limits.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            limiter: limiter.map(ConnLimiter::acquire),
+            peer: peer_limits.map(|p| (p.acquire(ip), ip)),
+        })
+    }
+}
+
+impl Drop for ConnPermit {
+    fn drop(&mut self) {
+        if let Some((shared, ip)) = &self.peer {
+            let mut shared = shared.borrow_mut();
+            if let Some(n) = shared.active.get_mut(ip) {
+                *n -= 1;
+                if *n == 0 {
+                    shared.active.remove(ip);
+                }
+            }
+        }
+
+        // The acceptor isn't woken: the permit is typically dropped by a
+        // spawned task, which may not wake other tasks.
+        if let Some(shared) = &self.limiter {
+            shared.borrow_mut().active -= 1;
+        }
+    }
+}
+
+impl Acceptor {
+    /// Stop accepting connections while `max` of them are active.  Each
+    /// accepted `Conn` carries a permit which is released when the permit
+    /// (or the whole `Conn`) is dropped.  The returned limiter may be
+    /// installed on other acceptors with `set_limiter` to share the limit.
+    ///
+    /// Releasing a permit doesn't wake the acceptor, because the runtime
+    /// doesn't allow a spawned task to wake other tasks.  Instead, the limit
+    /// is checked whenever the acceptor is polled.  While the limit is
+    /// reached, reception of one connection is kept pending, so that the
+    /// runtime wakes the acceptor when it arrives.  That connection is held
+    /// until the acceptor is polled with room under the limit, e.g. on the
+    /// next I/O wakeup of the main future, which is polled after every one.
+    pub fn limit_connections(&mut self, max: usize) -> ConnLimiter {
+        let limiter = ConnLimiter::new(max);
+        self.limiter = Some(limiter.clone());
+        limiter
+    }
+
+    /// Install a connection limiter, or remove it.
+    pub fn set_limiter(&mut self, limiter: Option<ConnLimiter>) {
+        self.limiter = limiter;
+    }
+
+    /// Reject connections from client addresses which already have `max`
+    /// active connections.  Rejected connections are closed without opening
+    /// streams for them (see `set_filter`).  The returned handle exposes the
+    /// counters, and may be installed on other acceptors with
+    /// `set_peer_limits` to share them.
+    pub fn limit_per_peer(&mut self, max: usize) -> PeerLimits {
+        let limits = PeerLimits::new(max);
+        self.peer_limits = Some(limits.clone());
+        limits
+    }
+
+    /// Install per-address connection limits, or remove them.
+    pub fn set_peer_limits(&mut self, limits: Option<PeerLimits>) {
+        self.peer_limits = limits;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    const A: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+    const B: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2));
+
+    #[test]
+    fn conn_limiter() {
+        let limiter = ConnLimiter::new(2);
+        let shared = limiter.clone();
+        assert_eq!(limiter.available(), 2);
+
+        let p1 = ConnPermit::new(Some(&limiter), None, A);
+        let p2 = ConnPermit::new(Some(&shared), None, B);
+        assert_eq!(limiter.active(), 2);
+        assert_eq!(limiter.available(), 0);
+
+        // Acceptors sharing the limiter may exceed it.
+        let p3 = ConnPermit::new(Some(&shared), None, B);
+        assert_eq!(limiter.available(), 0);
+
+        drop(p1);
+        drop(p3);
+        assert_eq!(limiter.active(), 1);
+        assert_eq!(limiter.available(), 1);
+
+        drop(p2);
+        assert_eq!(limiter.available(), 2);
+    }
+
+    #[test]
+    fn peer_limits() {
+        let limits = PeerLimits::new(1);
+        assert!(ConnPermit::new(None, None, A).is_none());
+
+        let p = ConnPermit::new(None, Some(&limits), A);
+        assert_eq!(limits.active(A), 1);
+        assert_eq!(limits.peers(), 1);
+        assert_eq!(limits.check(A), Verdict::Reject);
+        assert_eq!(limits.check(B), Verdict::Accept);
+        assert_eq!(limits.rejected(), 1);
+
+        drop(p);
+        assert_eq!(limits.active(A), 0);
+        assert_eq!(limits.peers(), 0);
+        assert_eq!(limits.check(A), Verdict::Accept);
+    }
+}
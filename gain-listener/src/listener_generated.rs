@@ -12,10 +12,10 @@ use self::flatbuffers::{EndianScalar, Follow};
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_BIND_ERROR: i16 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_BIND_ERROR: i16 = 6;
+pub const ENUM_MAX_BIND_ERROR: i16 = 10;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_BIND_ERROR: [BindError; 7] = [
+pub const ENUM_VALUES_BIND_ERROR: [BindError; 11] = [
   BindError::None,
   BindError::TooManyBindings,
   BindError::AlreadyBound,
@@ -23,6 +23,10 @@ pub const ENUM_VALUES_BIND_ERROR: [BindError; 7] = [
   BindError::InvalidName,
   BindError::NameTooLong,
   BindError::UnsupportedPort,
+  BindError::DomainNotVerified,
+  BindError::InvalidToken,
+  BindError::LeaseExpired,
+  BindError::UnsupportedTLSPolicy,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -37,9 +41,13 @@ impl BindError {
   pub const InvalidName: Self = Self(4);
   pub const NameTooLong: Self = Self(5);
   pub const UnsupportedPort: Self = Self(6);
+  pub const DomainNotVerified: Self = Self(7);
+  pub const InvalidToken: Self = Self(8);
+  pub const LeaseExpired: Self = Self(9);
+  pub const UnsupportedTLSPolicy: Self = Self(10);
 
   pub const ENUM_MIN: i16 = 0;
-  pub const ENUM_MAX: i16 = 6;
+  pub const ENUM_MAX: i16 = 10;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::None,
     Self::TooManyBindings,
@@ -48,6 +56,10 @@ impl BindError {
     Self::InvalidName,
     Self::NameTooLong,
     Self::UnsupportedPort,
+    Self::DomainNotVerified,
+    Self::InvalidToken,
+    Self::LeaseExpired,
+    Self::UnsupportedTLSPolicy,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -59,6 +71,10 @@ impl BindError {
       Self::InvalidName => Some("InvalidName"),
       Self::NameTooLong => Some("NameTooLong"),
       Self::UnsupportedPort => Some("UnsupportedPort"),
+      Self::DomainNotVerified => Some("DomainNotVerified"),
+      Self::InvalidToken => Some("InvalidToken"),
+      Self::LeaseExpired => Some("LeaseExpired"),
+      Self::UnsupportedTLSPolicy => Some("UnsupportedTLSPolicy"),
       _ => None,
     }
   }
@@ -117,11 +133,17 @@ impl flatbuffers::SimpleToVerifyInSlice for BindError {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_ACCEPT_ERROR: i16 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_ACCEPT_ERROR: i16 = 0;
+pub const ENUM_MAX_ACCEPT_ERROR: i16 = 6;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_ACCEPT_ERROR: [AcceptError; 1] = [
+pub const ENUM_VALUES_ACCEPT_ERROR: [AcceptError; 7] = [
   AcceptError::None,
+  AcceptError::LeaseExpired,
+  AcceptError::SNIRejected,
+  AcceptError::ResourceExhausted,
+  AcceptError::HandshakeFailed,
+  AcceptError::PolicyRejected,
+  AcceptError::Suspended,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -130,16 +152,34 @@ pub struct AcceptError(pub i16);
 #[allow(non_upper_case_globals)]
 impl AcceptError {
   pub const None: Self = Self(0);
+  pub const LeaseExpired: Self = Self(1);
+  pub const SNIRejected: Self = Self(2);
+  pub const ResourceExhausted: Self = Self(3);
+  pub const HandshakeFailed: Self = Self(4);
+  pub const PolicyRejected: Self = Self(5);
+  pub const Suspended: Self = Self(6);
 
   pub const ENUM_MIN: i16 = 0;
-  pub const ENUM_MAX: i16 = 0;
+  pub const ENUM_MAX: i16 = 6;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::None,
+    Self::LeaseExpired,
+    Self::SNIRejected,
+    Self::ResourceExhausted,
+    Self::HandshakeFailed,
+    Self::PolicyRejected,
+    Self::Suspended,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
       Self::None => Some("None"),
+      Self::LeaseExpired => Some("LeaseExpired"),
+      Self::SNIRejected => Some("SNIRejected"),
+      Self::ResourceExhausted => Some("ResourceExhausted"),
+      Self::HandshakeFailed => Some("HandshakeFailed"),
+      Self::PolicyRejected => Some("PolicyRejected"),
+      Self::Suspended => Some("Suspended"),
       _ => None,
     }
   }
@@ -196,40 +236,40 @@ impl<'a> flatbuffers::Verifiable for AcceptError {
 
 impl flatbuffers::SimpleToVerifyInSlice for AcceptError {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MIN_ACCEPT_SIZE: i16 = 0;
+pub const ENUM_MIN_UNBIND_ERROR: i16 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_ACCEPT_SIZE: i16 = 44;
+pub const ENUM_MAX_UNBIND_ERROR: i16 = 1;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_ACCEPT_SIZE: [AcceptSize; 2] = [
-  AcceptSize::Invalid,
-  AcceptSize::Basic,
+pub const ENUM_VALUES_UNBIND_ERROR: [UnbindError; 2] = [
+  UnbindError::None,
+  UnbindError::NotBound,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct AcceptSize(pub i16);
+pub struct UnbindError(pub i16);
 #[allow(non_upper_case_globals)]
-impl AcceptSize {
-  pub const Invalid: Self = Self(0);
-  pub const Basic: Self = Self(44);
+impl UnbindError {
+  pub const None: Self = Self(0);
+  pub const NotBound: Self = Self(1);
 
   pub const ENUM_MIN: i16 = 0;
-  pub const ENUM_MAX: i16 = 44;
+  pub const ENUM_MAX: i16 = 1;
   pub const ENUM_VALUES: &'static [Self] = &[
-    Self::Invalid,
-    Self::Basic,
+    Self::None,
+    Self::NotBound,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
-      Self::Invalid => Some("Invalid"),
-      Self::Basic => Some("Basic"),
+      Self::None => Some("None"),
+      Self::NotBound => Some("NotBound"),
       _ => None,
     }
   }
 }
-impl core::fmt::Debug for AcceptSize {
+impl core::fmt::Debug for UnbindError {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
     if let Some(name) = self.variant_name() {
       f.write_str(name)
@@ -238,7 +278,7 @@ impl core::fmt::Debug for AcceptSize {
     }
   }
 }
-impl<'a> flatbuffers::Follow<'a> for AcceptSize {
+impl<'a> flatbuffers::Follow<'a> for UnbindError {
   type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -247,15 +287,15 @@ impl<'a> flatbuffers::Follow<'a> for AcceptSize {
   }
 }
 
-impl flatbuffers::Push for AcceptSize {
-    type Output = AcceptSize;
+impl flatbuffers::Push for UnbindError {
+    type Output = UnbindError;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<i16>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for AcceptSize {
+impl flatbuffers::EndianScalar for UnbindError {
   type Scalar = i16;
   #[inline]
   fn to_little_endian(self) -> i16 {
@@ -269,7 +309,7 @@ impl flatbuffers::EndianScalar for AcceptSize {
   }
 }
 
-impl<'a> flatbuffers::Verifiable for AcceptSize {
+impl<'a> flatbuffers::Verifiable for UnbindError {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
@@ -279,42 +319,46 @@ impl<'a> flatbuffers::Verifiable for AcceptSize {
   }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for AcceptSize {}
+impl flatbuffers::SimpleToVerifyInSlice for UnbindError {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MIN_FUNCTION: u8 = 0;
+pub const ENUM_MIN_CLOSE_ERROR: i16 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_FUNCTION: u8 = 1;
+pub const ENUM_MAX_CLOSE_ERROR: i16 = 2;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_FUNCTION: [Function; 2] = [
-  Function::NONE,
-  Function::BindTLS,
+pub const ENUM_VALUES_CLOSE_ERROR: [CloseError; 3] = [
+  CloseError::None,
+  CloseError::NotConnected,
+  CloseError::NotAcknowledged,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct Function(pub u8);
+pub struct CloseError(pub i16);
 #[allow(non_upper_case_globals)]
-impl Function {
-  pub const NONE: Self = Self(0);
-  pub const BindTLS: Self = Self(1);
+impl CloseError {
+  pub const None: Self = Self(0);
+  pub const NotConnected: Self = Self(1);
+  pub const NotAcknowledged: Self = Self(2);
 
-  pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 1;
+  pub const ENUM_MIN: i16 = 0;
+  pub const ENUM_MAX: i16 = 2;
   pub const ENUM_VALUES: &'static [Self] = &[
-    Self::NONE,
-    Self::BindTLS,
+    Self::None,
+    Self::NotConnected,
+    Self::NotAcknowledged,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
-      Self::NONE => Some("NONE"),
-      Self::BindTLS => Some("BindTLS"),
+      Self::None => Some("None"),
+      Self::NotConnected => Some("NotConnected"),
+      Self::NotAcknowledged => Some("NotAcknowledged"),
       _ => None,
     }
   }
 }
-impl core::fmt::Debug for Function {
+impl core::fmt::Debug for CloseError {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
     if let Some(name) = self.variant_name() {
       f.write_str(name)
@@ -323,843 +367,6560 @@ impl core::fmt::Debug for Function {
     }
   }
 }
-impl<'a> flatbuffers::Follow<'a> for Function {
+impl<'a> flatbuffers::Follow<'a> for CloseError {
   type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    let b = flatbuffers::read_scalar_at::<i16>(buf, loc);
     Self(b)
   }
 }
 
-impl flatbuffers::Push for Function {
-    type Output = Function;
+impl flatbuffers::Push for CloseError {
+    type Output = CloseError;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+        flatbuffers::emplace_scalar::<i16>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for Function {
-  type Scalar = u8;
+impl flatbuffers::EndianScalar for CloseError {
+  type Scalar = i16;
   #[inline]
-  fn to_little_endian(self) -> u8 {
+  fn to_little_endian(self) -> i16 {
     self.0.to_le()
   }
   #[inline]
   #[allow(clippy::wrong_self_convention)]
-  fn from_little_endian(v: u8) -> Self {
-    let b = u8::from_le(v);
+  fn from_little_endian(v: i16) -> Self {
+    let b = i16::from_le(v);
     Self(b)
   }
 }
 
-impl<'a> flatbuffers::Verifiable for Function {
+impl<'a> flatbuffers::Verifiable for CloseError {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    u8::run_verifier(v, pos)
+    i16::run_verifier(v, pos)
   }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for Function {}
-pub struct FunctionUnionTableOffset {}
+impl flatbuffers::SimpleToVerifyInSlice for CloseError {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_EXPORT_ERROR: i16 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_EXPORT_ERROR: i16 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_EXPORT_ERROR: [ExportError; 4] = [
+  ExportError::None,
+  ExportError::NotConnected,
+  ExportError::NotTLS,
+  ExportError::InvalidLength,
+];
 
-// struct IPAddr, aligned to 4
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-#[derive(Clone, Copy, PartialEq)]
-pub struct IPAddr(pub [u8; 16]);
-impl Default for IPAddr { 
-  fn default() -> Self { 
-    Self([0; 16])
+pub struct ExportError(pub i16);
+#[allow(non_upper_case_globals)]
+impl ExportError {
+  pub const None: Self = Self(0);
+  pub const NotConnected: Self = Self(1);
+  pub const NotTLS: Self = Self(2);
+  pub const InvalidLength: Self = Self(3);
+
+  pub const ENUM_MIN: i16 = 0;
+  pub const ENUM_MAX: i16 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::None,
+    Self::NotConnected,
+    Self::NotTLS,
+    Self::InvalidLength,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::None => Some("None"),
+      Self::NotConnected => Some("NotConnected"),
+      Self::NotTLS => Some("NotTLS"),
+      Self::InvalidLength => Some("InvalidLength"),
+      _ => None,
+    }
   }
 }
-impl core::fmt::Debug for IPAddr {
+impl core::fmt::Debug for ExportError {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    f.debug_struct("IPAddr")
-      .field("a", &self.a())
-      .field("b", &self.b())
-      .field("c", &self.c())
-      .field("d", &self.d())
-      .finish()
-  }
-}
-
-impl flatbuffers::SimpleToVerifyInSlice for IPAddr {}
-impl<'a> flatbuffers::Follow<'a> for IPAddr {
-  type Inner = &'a IPAddr;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    <&'a IPAddr>::follow(buf, loc)
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
   }
 }
-impl<'a> flatbuffers::Follow<'a> for &'a IPAddr {
-  type Inner = &'a IPAddr;
+impl<'a> flatbuffers::Follow<'a> for ExportError {
+  type Inner = Self;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    flatbuffers::follow_cast_ref::<IPAddr>(buf, loc)
+    let b = flatbuffers::read_scalar_at::<i16>(buf, loc);
+    Self(b)
   }
 }
-impl<'b> flatbuffers::Push for IPAddr {
-    type Output = IPAddr;
+
+impl flatbuffers::Push for ExportError {
+    type Output = ExportError;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = ::core::slice::from_raw_parts(self as *const IPAddr as *const u8, Self::size());
-        dst.copy_from_slice(src);
+        flatbuffers::emplace_scalar::<i16>(dst, self.0);
     }
 }
 
-impl<'a> flatbuffers::Verifiable for IPAddr {
+impl flatbuffers::EndianScalar for ExportError {
+  type Scalar = i16;
+  #[inline]
+  fn to_little_endian(self) -> i16 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i16) -> Self {
+    let b = i16::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for ExportError {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.in_buffer::<Self>(pos)
+    i16::run_verifier(v, pos)
   }
 }
 
-impl<'a> IPAddr {
-  #[allow(clippy::too_many_arguments)]
-  pub fn new(
-    a: u32,
-    b: u32,
-    c: u32,
-    d: u32,
-  ) -> Self {
-    let mut s = Self([0; 16]);
-    s.set_a(a);
-    s.set_b(b);
-    s.set_c(c);
-    s.set_d(d);
-    s
-  }
+impl flatbuffers::SimpleToVerifyInSlice for ExportError {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ACCEPT_SIZE: i16 = -1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ACCEPT_SIZE: i16 = 16384;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ACCEPT_SIZE: [AcceptSize; 5] = [
+  AcceptSize::Variable,
+  AcceptSize::Invalid,
+  AcceptSize::Basic,
+  AcceptSize::Named,
+  AcceptSize::Extended,
+];
 
-  pub fn a(&self) -> u32 {
-    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    EndianScalar::from_little_endian(unsafe {
-      core::ptr::copy_nonoverlapping(
-        self.0[0..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
-  }
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct AcceptSize(pub i16);
+#[allow(non_upper_case_globals)]
+impl AcceptSize {
+  pub const Variable: Self = Self(-1);
+  pub const Invalid: Self = Self(0);
+  pub const Basic: Self = Self(44);
+  pub const Named: Self = Self(320);
+  pub const Extended: Self = Self(16384);
 
-  pub fn set_a(&mut self, x: u32) {
-    let x_le = x.to_little_endian();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[0..].as_mut_ptr(),
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
+  pub const ENUM_MIN: i16 = -1;
+  pub const ENUM_MAX: i16 = 16384;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Variable,
+    Self::Invalid,
+    Self::Basic,
+    Self::Named,
+    Self::Extended,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Variable => Some("Variable"),
+      Self::Invalid => Some("Invalid"),
+      Self::Basic => Some("Basic"),
+      Self::Named => Some("Named"),
+      Self::Extended => Some("Extended"),
+      _ => None,
     }
   }
-
-  pub fn b(&self) -> u32 {
-    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    EndianScalar::from_little_endian(unsafe {
-      core::ptr::copy_nonoverlapping(
-        self.0[4..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
-  }
-
-  pub fn set_b(&mut self, x: u32) {
-    let x_le = x.to_little_endian();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[4..].as_mut_ptr(),
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
+}
+impl core::fmt::Debug for AcceptSize {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
     }
   }
-
-  pub fn c(&self) -> u32 {
-    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    EndianScalar::from_little_endian(unsafe {
-      core::ptr::copy_nonoverlapping(
-        self.0[8..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
+}
+impl<'a> flatbuffers::Follow<'a> for AcceptSize {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i16>(buf, loc);
+    Self(b)
   }
+}
 
-  pub fn set_c(&mut self, x: u32) {
-    let x_le = x.to_little_endian();
+impl flatbuffers::Push for AcceptSize {
+    type Output = AcceptSize;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i16>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for AcceptSize {
+  type Scalar = i16;
+  #[inline]
+  fn to_little_endian(self) -> i16 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i16) -> Self {
+    let b = i16::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for AcceptSize {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i16::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for AcceptSize {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_PROTOCOL: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_PROTOCOL: i8 = 5;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_PROTOCOL: [Protocol; 6] = [
+  Protocol::TLS,
+  Protocol::TCP,
+  Protocol::UDP,
+  Protocol::QUIC,
+  Protocol::DTLS,
+  Protocol::TLSPassthrough,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Protocol(pub i8);
+#[allow(non_upper_case_globals)]
+impl Protocol {
+  pub const TLS: Self = Self(0);
+  pub const TCP: Self = Self(1);
+  pub const UDP: Self = Self(2);
+  pub const QUIC: Self = Self(3);
+  pub const DTLS: Self = Self(4);
+  pub const TLSPassthrough: Self = Self(5);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 5;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::TLS,
+    Self::TCP,
+    Self::UDP,
+    Self::QUIC,
+    Self::DTLS,
+    Self::TLSPassthrough,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::TLS => Some("TLS"),
+      Self::TCP => Some("TCP"),
+      Self::UDP => Some("UDP"),
+      Self::QUIC => Some("QUIC"),
+      Self::DTLS => Some("DTLS"),
+      Self::TLSPassthrough => Some("TLSPassthrough"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for Protocol {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for Protocol {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for Protocol {
+    type Output = Protocol;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for Protocol {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Protocol {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Protocol {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_IP_FAMILY: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_IP_FAMILY: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_IP_FAMILY: [IPFamily; 3] = [
+  IPFamily::Dual,
+  IPFamily::V4Only,
+  IPFamily::V6Only,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct IPFamily(pub i8);
+#[allow(non_upper_case_globals)]
+impl IPFamily {
+  pub const Dual: Self = Self(0);
+  pub const V4Only: Self = Self(1);
+  pub const V6Only: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Dual,
+    Self::V4Only,
+    Self::V6Only,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Dual => Some("Dual"),
+      Self::V4Only => Some("V4Only"),
+      Self::V6Only => Some("V6Only"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for IPFamily {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for IPFamily {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for IPFamily {
+    type Output = IPFamily;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for IPFamily {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for IPFamily {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for IPFamily {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_CLIENT_CERT_POLICY: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_CLIENT_CERT_POLICY: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CLIENT_CERT_POLICY: [ClientCertPolicy; 3] = [
+  ClientCertPolicy::None,
+  ClientCertPolicy::Optional,
+  ClientCertPolicy::Required,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ClientCertPolicy(pub i8);
+#[allow(non_upper_case_globals)]
+impl ClientCertPolicy {
+  pub const None: Self = Self(0);
+  pub const Optional: Self = Self(1);
+  pub const Required: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::None,
+    Self::Optional,
+    Self::Required,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::None => Some("None"),
+      Self::Optional => Some("Optional"),
+      Self::Required => Some("Required"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for ClientCertPolicy {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for ClientCertPolicy {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for ClientCertPolicy {
+    type Output = ClientCertPolicy;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for ClientCertPolicy {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for ClientCertPolicy {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ClientCertPolicy {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_TLS_VERSION: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_TLS_VERSION: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TLS_VERSION: [TLSVersion; 3] = [
+  TLSVersion::Any,
+  TLSVersion::TLS12,
+  TLSVersion::TLS13,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TLSVersion(pub i8);
+#[allow(non_upper_case_globals)]
+impl TLSVersion {
+  pub const Any: Self = Self(0);
+  pub const TLS12: Self = Self(1);
+  pub const TLS13: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Any,
+    Self::TLS12,
+    Self::TLS13,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Any => Some("Any"),
+      Self::TLS12 => Some("TLS12"),
+      Self::TLS13 => Some("TLS13"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for TLSVersion {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for TLSVersion {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for TLSVersion {
+    type Output = TLSVersion;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for TLSVersion {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for TLSVersion {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for TLSVersion {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_HANDSHAKE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_HANDSHAKE: i8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_HANDSHAKE: [Handshake; 4] = [
+  Handshake::Unknown,
+  Handshake::Full,
+  Handshake::Resumed,
+  Handshake::ZeroRTT,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Handshake(pub i8);
+#[allow(non_upper_case_globals)]
+impl Handshake {
+  pub const Unknown: Self = Self(0);
+  pub const Full: Self = Self(1);
+  pub const Resumed: Self = Self(2);
+  pub const ZeroRTT: Self = Self(3);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Unknown,
+    Self::Full,
+    Self::Resumed,
+    Self::ZeroRTT,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Unknown => Some("Unknown"),
+      Self::Full => Some("Full"),
+      Self::Resumed => Some("Resumed"),
+      Self::ZeroRTT => Some("ZeroRTT"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for Handshake {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for Handshake {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for Handshake {
+    type Output = Handshake;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for Handshake {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Handshake {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Handshake {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_FUNCTION: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_FUNCTION: u8 = 20;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_FUNCTION: [Function; 21] = [
+  Function::NONE,
+  Function::BindTLS,
+  Function::BindTCP,
+  Function::BindUDP,
+  Function::BindQUIC,
+  Function::OpenQUICStream,
+  Function::BindDTLS,
+  Function::BindTLSMany,
+  Function::GetQuota,
+  Function::ListBindings,
+  Function::Unbind,
+  Function::RenameBinding,
+  Function::RenewBinding,
+  Function::GetTLSDetails,
+  Function::BindTLSPassthrough,
+  Function::RejectConn,
+  Function::SuspendBinding,
+  Function::ResumeBinding,
+  Function::CloseConn,
+  Function::AbortConn,
+  Function::ExportKeyingMaterial,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Function(pub u8);
+#[allow(non_upper_case_globals)]
+impl Function {
+  pub const NONE: Self = Self(0);
+  pub const BindTLS: Self = Self(1);
+  pub const BindTCP: Self = Self(2);
+  pub const BindUDP: Self = Self(3);
+  pub const BindQUIC: Self = Self(4);
+  pub const OpenQUICStream: Self = Self(5);
+  pub const BindDTLS: Self = Self(6);
+  pub const BindTLSMany: Self = Self(7);
+  pub const GetQuota: Self = Self(8);
+  pub const ListBindings: Self = Self(9);
+  pub const Unbind: Self = Self(10);
+  pub const RenameBinding: Self = Self(11);
+  pub const RenewBinding: Self = Self(12);
+  pub const GetTLSDetails: Self = Self(13);
+  pub const BindTLSPassthrough: Self = Self(14);
+  pub const RejectConn: Self = Self(15);
+  pub const SuspendBinding: Self = Self(16);
+  pub const ResumeBinding: Self = Self(17);
+  pub const CloseConn: Self = Self(18);
+  pub const AbortConn: Self = Self(19);
+  pub const ExportKeyingMaterial: Self = Self(20);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 20;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::BindTLS,
+    Self::BindTCP,
+    Self::BindUDP,
+    Self::BindQUIC,
+    Self::OpenQUICStream,
+    Self::BindDTLS,
+    Self::BindTLSMany,
+    Self::GetQuota,
+    Self::ListBindings,
+    Self::Unbind,
+    Self::RenameBinding,
+    Self::RenewBinding,
+    Self::GetTLSDetails,
+    Self::BindTLSPassthrough,
+    Self::RejectConn,
+    Self::SuspendBinding,
+    Self::ResumeBinding,
+    Self::CloseConn,
+    Self::AbortConn,
+    Self::ExportKeyingMaterial,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::BindTLS => Some("BindTLS"),
+      Self::BindTCP => Some("BindTCP"),
+      Self::BindUDP => Some("BindUDP"),
+      Self::BindQUIC => Some("BindQUIC"),
+      Self::OpenQUICStream => Some("OpenQUICStream"),
+      Self::BindDTLS => Some("BindDTLS"),
+      Self::BindTLSMany => Some("BindTLSMany"),
+      Self::GetQuota => Some("GetQuota"),
+      Self::ListBindings => Some("ListBindings"),
+      Self::Unbind => Some("Unbind"),
+      Self::RenameBinding => Some("RenameBinding"),
+      Self::RenewBinding => Some("RenewBinding"),
+      Self::GetTLSDetails => Some("GetTLSDetails"),
+      Self::BindTLSPassthrough => Some("BindTLSPassthrough"),
+      Self::RejectConn => Some("RejectConn"),
+      Self::SuspendBinding => Some("SuspendBinding"),
+      Self::ResumeBinding => Some("ResumeBinding"),
+      Self::CloseConn => Some("CloseConn"),
+      Self::AbortConn => Some("AbortConn"),
+      Self::ExportKeyingMaterial => Some("ExportKeyingMaterial"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for Function {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for Function {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for Function {
+    type Output = Function;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for Function {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Function {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Function {}
+pub struct FunctionUnionTableOffset {}
+
+// struct IPAddr, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct IPAddr(pub [u8; 16]);
+impl Default for IPAddr { 
+  fn default() -> Self { 
+    Self([0; 16])
+  }
+}
+impl core::fmt::Debug for IPAddr {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("IPAddr")
+      .field("a", &self.a())
+      .field("b", &self.b())
+      .field("c", &self.c())
+      .field("d", &self.d())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for IPAddr {}
+impl<'a> flatbuffers::Follow<'a> for IPAddr {
+  type Inner = &'a IPAddr;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a IPAddr>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a IPAddr {
+  type Inner = &'a IPAddr;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<IPAddr>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for IPAddr {
+    type Output = IPAddr;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const IPAddr as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for IPAddr {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> IPAddr {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    a: u32,
+    b: u32,
+    c: u32,
+    d: u32,
+  ) -> Self {
+    let mut s = Self([0; 16]);
+    s.set_a(a);
+    s.set_b(b);
+    s.set_c(c);
+    s.set_d(d);
+    s
+  }
+
+  pub fn a(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_a(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn b(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_b(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn c(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_c(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn d(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[12..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_d(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[12..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct AcceptBasic, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct AcceptBasic(pub [u8; 28]);
+impl Default for AcceptBasic { 
+  fn default() -> Self { 
+    Self([0; 28])
+  }
+}
+impl core::fmt::Debug for AcceptBasic {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("AcceptBasic")
+      .field("error", &self.error())
+      .field("conn_id", &self.conn_id())
+      .field("addr", &self.addr())
+      .field("port", &self.port())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for AcceptBasic {}
+impl<'a> flatbuffers::Follow<'a> for AcceptBasic {
+  type Inner = &'a AcceptBasic;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a AcceptBasic>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a AcceptBasic {
+  type Inner = &'a AcceptBasic;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<AcceptBasic>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for AcceptBasic {
+    type Output = AcceptBasic;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const AcceptBasic as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for AcceptBasic {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> AcceptBasic {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    error: AcceptError,
+    conn_id: i32,
+    addr: &IPAddr,
+    port: u16,
+  ) -> Self {
+    let mut s = Self([0; 28]);
+    s.set_error(error);
+    s.set_conn_id(conn_id);
+    s.set_addr(addr);
+    s.set_port(port);
+    s
+  }
+
+  pub fn error(&self) -> AcceptError {
+    let mut mem = core::mem::MaybeUninit::<<AcceptError as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<AcceptError as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_error(&mut self, x: AcceptError) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<AcceptError as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn conn_id(&self) -> i32 {
+    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_conn_id(&mut self, x: i32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn addr(&self) -> &IPAddr {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid struct in this slot
+    unsafe { &*(self.0[8..].as_ptr() as *const IPAddr) }
+  }
+
+  #[allow(clippy::identity_op)]
+  pub fn set_addr(&mut self, x: &IPAddr) {
+    self.0[8..8 + 16].copy_from_slice(&x.0)
+  }
+
+  pub fn port(&self) -> u16 {
+    let mut mem = core::mem::MaybeUninit::<<u16 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[24..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_port(&mut self, x: u16) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[24..].as_mut_ptr(),
+        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct PortRange, aligned to 2
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct PortRange(pub [u8; 4]);
+impl Default for PortRange { 
+  fn default() -> Self { 
+    Self([0; 4])
+  }
+}
+impl core::fmt::Debug for PortRange {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("PortRange")
+      .field("first", &self.first())
+      .field("last", &self.last())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for PortRange {}
+impl<'a> flatbuffers::Follow<'a> for PortRange {
+  type Inner = &'a PortRange;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a PortRange>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a PortRange {
+  type Inner = &'a PortRange;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<PortRange>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for PortRange {
+    type Output = PortRange;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const PortRange as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for PortRange {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> PortRange {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    first: u16,
+    last: u16,
+  ) -> Self {
+    let mut s = Self([0; 4]);
+    s.set_first(first);
+    s.set_last(last);
+    s
+  }
+
+  pub fn first(&self) -> u16 {
+    let mut mem = core::mem::MaybeUninit::<<u16 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_first(&mut self, x: u16) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn last(&self) -> u16 {
+    let mut mem = core::mem::MaybeUninit::<<u16 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[2..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_last(&mut self, x: u16) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[2..].as_mut_ptr(),
+        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+pub enum CertificateOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Certificate<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Certificate<'a> {
+  type Inner = Certificate<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Certificate<'a> {
+  pub const VT_DER: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Certificate { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CertificateArgs<'args>
+  ) -> flatbuffers::WIPOffset<Certificate<'bldr>> {
+    let mut builder = CertificateBuilder::new(_fbb);
+    if let Some(x) = args.der { builder.add_der(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn der(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Certificate::VT_DER, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Certificate<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("der", Self::VT_DER, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CertificateArgs<'a> {
+    pub der: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for CertificateArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CertificateArgs {
+      der: None,
+    }
+  }
+}
+
+pub struct CertificateBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CertificateBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_der(&mut self, der: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Certificate::VT_DER, der);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CertificateBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CertificateBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Certificate<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Certificate<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Certificate");
+      ds.field("der", &self.der());
+      ds.finish()
+  }
+}
+pub enum AcceptOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Accept<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Accept<'a> {
+  type Inner = Accept<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Accept<'a> {
+  pub const VT_BASIC: flatbuffers::VOffsetT = 4;
+  pub const VT_SUBDOMAIN: flatbuffers::VOffsetT = 6;
+  pub const VT_PREFIX: flatbuffers::VOffsetT = 8;
+  pub const VT_CLIENT_CHAIN: flatbuffers::VOffsetT = 10;
+  pub const VT_CLIENT_SUBJECT: flatbuffers::VOffsetT = 12;
+  pub const VT_ALPN: flatbuffers::VOffsetT = 14;
+  pub const VT_SERVER_NAME: flatbuffers::VOffsetT = 16;
+  pub const VT_TLS_VERSION: flatbuffers::VOffsetT = 18;
+  pub const VT_EARLY_DATA: flatbuffers::VOffsetT = 20;
+  pub const VT_CIPHER_SUITE: flatbuffers::VOffsetT = 22;
+  pub const VT_CLIENT_SANS: flatbuffers::VOffsetT = 24;
+  pub const VT_HANDSHAKE: flatbuffers::VOffsetT = 26;
+  pub const VT_LOCAL_ADDR: flatbuffers::VOffsetT = 28;
+  pub const VT_LOCAL_PORT: flatbuffers::VOffsetT = 30;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Accept { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args AcceptArgs<'args>
+  ) -> flatbuffers::WIPOffset<Accept<'bldr>> {
+    let mut builder = AcceptBuilder::new(_fbb);
+    if let Some(x) = args.local_addr { builder.add_local_addr(x); }
+    if let Some(x) = args.client_sans { builder.add_client_sans(x); }
+    if let Some(x) = args.server_name { builder.add_server_name(x); }
+    if let Some(x) = args.alpn { builder.add_alpn(x); }
+    if let Some(x) = args.client_subject { builder.add_client_subject(x); }
+    if let Some(x) = args.client_chain { builder.add_client_chain(x); }
+    if let Some(x) = args.prefix { builder.add_prefix(x); }
+    if let Some(x) = args.subdomain { builder.add_subdomain(x); }
+    if let Some(x) = args.basic { builder.add_basic(x); }
+    builder.add_local_port(args.local_port);
+    builder.add_cipher_suite(args.cipher_suite);
+    builder.add_handshake(args.handshake);
+    builder.add_early_data(args.early_data);
+    builder.add_tls_version(args.tls_version);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn basic(&self) -> Option<&'a AcceptBasic> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AcceptBasic>(Accept::VT_BASIC, None)}
+  }
+  #[inline]
+  pub fn subdomain(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Accept::VT_SUBDOMAIN, None)}
+  }
+  #[inline]
+  pub fn prefix(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Accept::VT_PREFIX, None)}
+  }
+  #[inline]
+  pub fn client_chain(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Certificate<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Certificate<'a>>>>>(Accept::VT_CLIENT_CHAIN, None)}
+  }
+  #[inline]
+  pub fn client_subject(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Accept::VT_CLIENT_SUBJECT, None)}
+  }
+  #[inline]
+  pub fn alpn(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Accept::VT_ALPN, None)}
+  }
+  #[inline]
+  pub fn server_name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Accept::VT_SERVER_NAME, None)}
+  }
+  #[inline]
+  pub fn tls_version(&self) -> TLSVersion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TLSVersion>(Accept::VT_TLS_VERSION, Some(TLSVersion::Any)).unwrap()}
+  }
+  #[inline]
+  pub fn early_data(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Accept::VT_EARLY_DATA, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn cipher_suite(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Accept::VT_CIPHER_SUITE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn client_sans(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Accept::VT_CLIENT_SANS, None)}
+  }
+  #[inline]
+  pub fn handshake(&self) -> Handshake {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Handshake>(Accept::VT_HANDSHAKE, Some(Handshake::Unknown)).unwrap()}
+  }
+  #[inline]
+  pub fn local_addr(&self) -> Option<&'a IPAddr> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<IPAddr>(Accept::VT_LOCAL_ADDR, None)}
+  }
+  #[inline]
+  pub fn local_port(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Accept::VT_LOCAL_PORT, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Accept<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<AcceptBasic>("basic", Self::VT_BASIC, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("subdomain", Self::VT_SUBDOMAIN, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("prefix", Self::VT_PREFIX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Certificate>>>>("client_chain", Self::VT_CLIENT_CHAIN, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("client_subject", Self::VT_CLIENT_SUBJECT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("alpn", Self::VT_ALPN, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("server_name", Self::VT_SERVER_NAME, false)?
+     .visit_field::<TLSVersion>("tls_version", Self::VT_TLS_VERSION, false)?
+     .visit_field::<bool>("early_data", Self::VT_EARLY_DATA, false)?
+     .visit_field::<u16>("cipher_suite", Self::VT_CIPHER_SUITE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("client_sans", Self::VT_CLIENT_SANS, false)?
+     .visit_field::<Handshake>("handshake", Self::VT_HANDSHAKE, false)?
+     .visit_field::<IPAddr>("local_addr", Self::VT_LOCAL_ADDR, false)?
+     .visit_field::<u16>("local_port", Self::VT_LOCAL_PORT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AcceptArgs<'a> {
+    pub basic: Option<&'a AcceptBasic>,
+    pub subdomain: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub prefix: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub client_chain: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Certificate<'a>>>>>,
+    pub client_subject: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub alpn: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub server_name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub tls_version: TLSVersion,
+    pub early_data: bool,
+    pub cipher_suite: u16,
+    pub client_sans: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub handshake: Handshake,
+    pub local_addr: Option<&'a IPAddr>,
+    pub local_port: u16,
+}
+impl<'a> Default for AcceptArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AcceptArgs {
+      basic: None,
+      subdomain: None,
+      prefix: None,
+      client_chain: None,
+      client_subject: None,
+      alpn: None,
+      server_name: None,
+      tls_version: TLSVersion::Any,
+      early_data: false,
+      cipher_suite: 0,
+      client_sans: None,
+      handshake: Handshake::Unknown,
+      local_addr: None,
+      local_port: 0,
+    }
+  }
+}
+
+pub struct AcceptBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AcceptBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_basic(&mut self, basic: &AcceptBasic) {
+    self.fbb_.push_slot_always::<&AcceptBasic>(Accept::VT_BASIC, basic);
+  }
+  #[inline]
+  pub fn add_subdomain(&mut self, subdomain: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Accept::VT_SUBDOMAIN, subdomain);
+  }
+  #[inline]
+  pub fn add_prefix(&mut self, prefix: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Accept::VT_PREFIX, prefix);
+  }
+  #[inline]
+  pub fn add_client_chain(&mut self, client_chain: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Certificate<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Accept::VT_CLIENT_CHAIN, client_chain);
+  }
+  #[inline]
+  pub fn add_client_subject(&mut self, client_subject: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Accept::VT_CLIENT_SUBJECT, client_subject);
+  }
+  #[inline]
+  pub fn add_alpn(&mut self, alpn: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Accept::VT_ALPN, alpn);
+  }
+  #[inline]
+  pub fn add_server_name(&mut self, server_name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Accept::VT_SERVER_NAME, server_name);
+  }
+  #[inline]
+  pub fn add_tls_version(&mut self, tls_version: TLSVersion) {
+    self.fbb_.push_slot::<TLSVersion>(Accept::VT_TLS_VERSION, tls_version, TLSVersion::Any);
+  }
+  #[inline]
+  pub fn add_early_data(&mut self, early_data: bool) {
+    self.fbb_.push_slot::<bool>(Accept::VT_EARLY_DATA, early_data, false);
+  }
+  #[inline]
+  pub fn add_cipher_suite(&mut self, cipher_suite: u16) {
+    self.fbb_.push_slot::<u16>(Accept::VT_CIPHER_SUITE, cipher_suite, 0);
+  }
+  #[inline]
+  pub fn add_client_sans(&mut self, client_sans: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Accept::VT_CLIENT_SANS, client_sans);
+  }
+  #[inline]
+  pub fn add_handshake(&mut self, handshake: Handshake) {
+    self.fbb_.push_slot::<Handshake>(Accept::VT_HANDSHAKE, handshake, Handshake::Unknown);
+  }
+  #[inline]
+  pub fn add_local_addr(&mut self, local_addr: &IPAddr) {
+    self.fbb_.push_slot_always::<&IPAddr>(Accept::VT_LOCAL_ADDR, local_addr);
+  }
+  #[inline]
+  pub fn add_local_port(&mut self, local_port: u16) {
+    self.fbb_.push_slot::<u16>(Accept::VT_LOCAL_PORT, local_port, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AcceptBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AcceptBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Accept<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Accept<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Accept");
+      ds.field("basic", &self.basic());
+      ds.field("subdomain", &self.subdomain());
+      ds.field("prefix", &self.prefix());
+      ds.field("client_chain", &self.client_chain());
+      ds.field("client_subject", &self.client_subject());
+      ds.field("alpn", &self.alpn());
+      ds.field("server_name", &self.server_name());
+      ds.field("tls_version", &self.tls_version());
+      ds.field("early_data", &self.early_data());
+      ds.field("cipher_suite", &self.cipher_suite());
+      ds.field("client_sans", &self.client_sans());
+      ds.field("handshake", &self.handshake());
+      ds.field("local_addr", &self.local_addr());
+      ds.field("local_port", &self.local_port());
+      ds.finish()
+  }
+}
+pub enum BindTLSOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BindTLS<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BindTLS<'a> {
+  type Inner = BindTLS<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> BindTLS<'a> {
+  pub const VT_ACCEPT_SIZE: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_PORT: flatbuffers::VOffsetT = 8;
+  pub const VT_DOMAIN: flatbuffers::VOffsetT = 10;
+  pub const VT_WILDCARD: flatbuffers::VOffsetT = 12;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 14;
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 16;
+  pub const VT_LEASE_MS: flatbuffers::VOffsetT = 18;
+  pub const VT_IP_FAMILY: flatbuffers::VOffsetT = 20;
+  pub const VT_BACKLOG: flatbuffers::VOffsetT = 22;
+  pub const VT_CLIENT_CERT: flatbuffers::VOffsetT = 24;
+  pub const VT_ALPN: flatbuffers::VOffsetT = 26;
+  pub const VT_MIN_TLS_VERSION: flatbuffers::VOffsetT = 28;
+  pub const VT_STRICT_SNI: flatbuffers::VOffsetT = 30;
+  pub const VT_EARLY_DATA: flatbuffers::VOffsetT = 32;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BindTLS { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BindTLSArgs<'args>
+  ) -> flatbuffers::WIPOffset<BindTLS<'bldr>> {
+    let mut builder = BindTLSBuilder::new(_fbb);
+    if let Some(x) = args.alpn { builder.add_alpn(x); }
+    builder.add_backlog(args.backlog);
+    builder.add_lease_ms(args.lease_ms);
+    if let Some(x) = args.token { builder.add_token(x); }
+    if let Some(x) = args.names { builder.add_names(x); }
+    if let Some(x) = args.domain { builder.add_domain(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_port(args.port);
+    builder.add_accept_size(args.accept_size);
+    builder.add_early_data(args.early_data);
+    builder.add_strict_sni(args.strict_sni);
+    builder.add_min_tls_version(args.min_tls_version);
+    builder.add_client_cert(args.client_cert);
+    builder.add_ip_family(args.ip_family);
+    builder.add_wildcard(args.wildcard);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn accept_size(&self) -> AcceptSize {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AcceptSize>(BindTLS::VT_ACCEPT_SIZE, Some(AcceptSize::Invalid)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindTLS::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn port(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(BindTLS::VT_PORT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn domain(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindTLS::VT_DOMAIN, None)}
+  }
+  #[inline]
+  pub fn wildcard(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(BindTLS::VT_WILDCARD, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(BindTLS::VT_NAMES, None)}
+  }
+  #[inline]
+  pub fn token(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(BindTLS::VT_TOKEN, None)}
+  }
+  #[inline]
+  pub fn lease_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindTLS::VT_LEASE_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn ip_family(&self) -> IPFamily {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<IPFamily>(BindTLS::VT_IP_FAMILY, Some(IPFamily::Dual)).unwrap()}
+  }
+  #[inline]
+  pub fn backlog(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindTLS::VT_BACKLOG, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn client_cert(&self) -> ClientCertPolicy {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ClientCertPolicy>(BindTLS::VT_CLIENT_CERT, Some(ClientCertPolicy::None)).unwrap()}
+  }
+  #[inline]
+  pub fn alpn(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(BindTLS::VT_ALPN, None)}
+  }
+  #[inline]
+  pub fn min_tls_version(&self) -> TLSVersion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TLSVersion>(BindTLS::VT_MIN_TLS_VERSION, Some(TLSVersion::Any)).unwrap()}
+  }
+  #[inline]
+  pub fn strict_sni(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(BindTLS::VT_STRICT_SNI, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn early_data(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(BindTLS::VT_EARLY_DATA, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for BindTLS<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<AcceptSize>("accept_size", Self::VT_ACCEPT_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u16>("port", Self::VT_PORT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("domain", Self::VT_DOMAIN, false)?
+     .visit_field::<bool>("wildcard", Self::VT_WILDCARD, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("names", Self::VT_NAMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("token", Self::VT_TOKEN, false)?
+     .visit_field::<u32>("lease_ms", Self::VT_LEASE_MS, false)?
+     .visit_field::<IPFamily>("ip_family", Self::VT_IP_FAMILY, false)?
+     .visit_field::<u32>("backlog", Self::VT_BACKLOG, false)?
+     .visit_field::<ClientCertPolicy>("client_cert", Self::VT_CLIENT_CERT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("alpn", Self::VT_ALPN, false)?
+     .visit_field::<TLSVersion>("min_tls_version", Self::VT_MIN_TLS_VERSION, false)?
+     .visit_field::<bool>("strict_sni", Self::VT_STRICT_SNI, false)?
+     .visit_field::<bool>("early_data", Self::VT_EARLY_DATA, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BindTLSArgs<'a> {
+    pub accept_size: AcceptSize,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub port: u16,
+    pub domain: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub wildcard: bool,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub token: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub lease_ms: u32,
+    pub ip_family: IPFamily,
+    pub backlog: u32,
+    pub client_cert: ClientCertPolicy,
+    pub alpn: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub min_tls_version: TLSVersion,
+    pub strict_sni: bool,
+    pub early_data: bool,
+}
+impl<'a> Default for BindTLSArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BindTLSArgs {
+      accept_size: AcceptSize::Invalid,
+      name: None,
+      port: 0,
+      domain: None,
+      wildcard: false,
+      names: None,
+      token: None,
+      lease_ms: 0,
+      ip_family: IPFamily::Dual,
+      backlog: 0,
+      client_cert: ClientCertPolicy::None,
+      alpn: None,
+      min_tls_version: TLSVersion::Any,
+      strict_sni: false,
+      early_data: false,
+    }
+  }
+}
+
+pub struct BindTLSBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BindTLSBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_accept_size(&mut self, accept_size: AcceptSize) {
+    self.fbb_.push_slot::<AcceptSize>(BindTLS::VT_ACCEPT_SIZE, accept_size, AcceptSize::Invalid);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLS::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_port(&mut self, port: u16) {
+    self.fbb_.push_slot::<u16>(BindTLS::VT_PORT, port, 0);
+  }
+  #[inline]
+  pub fn add_domain(&mut self, domain: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLS::VT_DOMAIN, domain);
+  }
+  #[inline]
+  pub fn add_wildcard(&mut self, wildcard: bool) {
+    self.fbb_.push_slot::<bool>(BindTLS::VT_WILDCARD, wildcard, false);
+  }
+  #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLS::VT_NAMES, names);
+  }
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLS::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn add_lease_ms(&mut self, lease_ms: u32) {
+    self.fbb_.push_slot::<u32>(BindTLS::VT_LEASE_MS, lease_ms, 0);
+  }
+  #[inline]
+  pub fn add_ip_family(&mut self, ip_family: IPFamily) {
+    self.fbb_.push_slot::<IPFamily>(BindTLS::VT_IP_FAMILY, ip_family, IPFamily::Dual);
+  }
+  #[inline]
+  pub fn add_backlog(&mut self, backlog: u32) {
+    self.fbb_.push_slot::<u32>(BindTLS::VT_BACKLOG, backlog, 0);
+  }
+  #[inline]
+  pub fn add_client_cert(&mut self, client_cert: ClientCertPolicy) {
+    self.fbb_.push_slot::<ClientCertPolicy>(BindTLS::VT_CLIENT_CERT, client_cert, ClientCertPolicy::None);
+  }
+  #[inline]
+  pub fn add_alpn(&mut self, alpn: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLS::VT_ALPN, alpn);
+  }
+  #[inline]
+  pub fn add_min_tls_version(&mut self, min_tls_version: TLSVersion) {
+    self.fbb_.push_slot::<TLSVersion>(BindTLS::VT_MIN_TLS_VERSION, min_tls_version, TLSVersion::Any);
+  }
+  #[inline]
+  pub fn add_strict_sni(&mut self, strict_sni: bool) {
+    self.fbb_.push_slot::<bool>(BindTLS::VT_STRICT_SNI, strict_sni, false);
+  }
+  #[inline]
+  pub fn add_early_data(&mut self, early_data: bool) {
+    self.fbb_.push_slot::<bool>(BindTLS::VT_EARLY_DATA, early_data, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindTLSBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BindTLSBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BindTLS<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BindTLS<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BindTLS");
+      ds.field("accept_size", &self.accept_size());
+      ds.field("name", &self.name());
+      ds.field("port", &self.port());
+      ds.field("domain", &self.domain());
+      ds.field("wildcard", &self.wildcard());
+      ds.field("names", &self.names());
+      ds.field("token", &self.token());
+      ds.field("lease_ms", &self.lease_ms());
+      ds.field("ip_family", &self.ip_family());
+      ds.field("backlog", &self.backlog());
+      ds.field("client_cert", &self.client_cert());
+      ds.field("alpn", &self.alpn());
+      ds.field("min_tls_version", &self.min_tls_version());
+      ds.field("strict_sni", &self.strict_sni());
+      ds.field("early_data", &self.early_data());
+      ds.finish()
+  }
+}
+pub enum BindTCPOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BindTCP<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BindTCP<'a> {
+  type Inner = BindTCP<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> BindTCP<'a> {
+  pub const VT_ACCEPT_SIZE: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_PORT: flatbuffers::VOffsetT = 8;
+  pub const VT_DOMAIN: flatbuffers::VOffsetT = 10;
+  pub const VT_WILDCARD: flatbuffers::VOffsetT = 12;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 14;
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 16;
+  pub const VT_LEASE_MS: flatbuffers::VOffsetT = 18;
+  pub const VT_IP_FAMILY: flatbuffers::VOffsetT = 20;
+  pub const VT_BACKLOG: flatbuffers::VOffsetT = 22;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BindTCP { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BindTCPArgs<'args>
+  ) -> flatbuffers::WIPOffset<BindTCP<'bldr>> {
+    let mut builder = BindTCPBuilder::new(_fbb);
+    builder.add_backlog(args.backlog);
+    builder.add_lease_ms(args.lease_ms);
+    if let Some(x) = args.token { builder.add_token(x); }
+    if let Some(x) = args.names { builder.add_names(x); }
+    if let Some(x) = args.domain { builder.add_domain(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_port(args.port);
+    builder.add_accept_size(args.accept_size);
+    builder.add_ip_family(args.ip_family);
+    builder.add_wildcard(args.wildcard);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn accept_size(&self) -> AcceptSize {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AcceptSize>(BindTCP::VT_ACCEPT_SIZE, Some(AcceptSize::Invalid)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindTCP::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn port(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(BindTCP::VT_PORT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn domain(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindTCP::VT_DOMAIN, None)}
+  }
+  #[inline]
+  pub fn wildcard(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(BindTCP::VT_WILDCARD, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(BindTCP::VT_NAMES, None)}
+  }
+  #[inline]
+  pub fn token(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(BindTCP::VT_TOKEN, None)}
+  }
+  #[inline]
+  pub fn lease_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindTCP::VT_LEASE_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn ip_family(&self) -> IPFamily {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<IPFamily>(BindTCP::VT_IP_FAMILY, Some(IPFamily::Dual)).unwrap()}
+  }
+  #[inline]
+  pub fn backlog(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindTCP::VT_BACKLOG, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for BindTCP<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<AcceptSize>("accept_size", Self::VT_ACCEPT_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u16>("port", Self::VT_PORT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("domain", Self::VT_DOMAIN, false)?
+     .visit_field::<bool>("wildcard", Self::VT_WILDCARD, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("names", Self::VT_NAMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("token", Self::VT_TOKEN, false)?
+     .visit_field::<u32>("lease_ms", Self::VT_LEASE_MS, false)?
+     .visit_field::<IPFamily>("ip_family", Self::VT_IP_FAMILY, false)?
+     .visit_field::<u32>("backlog", Self::VT_BACKLOG, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BindTCPArgs<'a> {
+    pub accept_size: AcceptSize,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub port: u16,
+    pub domain: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub wildcard: bool,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub token: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub lease_ms: u32,
+    pub ip_family: IPFamily,
+    pub backlog: u32,
+}
+impl<'a> Default for BindTCPArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BindTCPArgs {
+      accept_size: AcceptSize::Invalid,
+      name: None,
+      port: 0,
+      domain: None,
+      wildcard: false,
+      names: None,
+      token: None,
+      lease_ms: 0,
+      ip_family: IPFamily::Dual,
+      backlog: 0,
+    }
+  }
+}
+
+pub struct BindTCPBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BindTCPBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_accept_size(&mut self, accept_size: AcceptSize) {
+    self.fbb_.push_slot::<AcceptSize>(BindTCP::VT_ACCEPT_SIZE, accept_size, AcceptSize::Invalid);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTCP::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_port(&mut self, port: u16) {
+    self.fbb_.push_slot::<u16>(BindTCP::VT_PORT, port, 0);
+  }
+  #[inline]
+  pub fn add_domain(&mut self, domain: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTCP::VT_DOMAIN, domain);
+  }
+  #[inline]
+  pub fn add_wildcard(&mut self, wildcard: bool) {
+    self.fbb_.push_slot::<bool>(BindTCP::VT_WILDCARD, wildcard, false);
+  }
+  #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTCP::VT_NAMES, names);
+  }
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTCP::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn add_lease_ms(&mut self, lease_ms: u32) {
+    self.fbb_.push_slot::<u32>(BindTCP::VT_LEASE_MS, lease_ms, 0);
+  }
+  #[inline]
+  pub fn add_ip_family(&mut self, ip_family: IPFamily) {
+    self.fbb_.push_slot::<IPFamily>(BindTCP::VT_IP_FAMILY, ip_family, IPFamily::Dual);
+  }
+  #[inline]
+  pub fn add_backlog(&mut self, backlog: u32) {
+    self.fbb_.push_slot::<u32>(BindTCP::VT_BACKLOG, backlog, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindTCPBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BindTCPBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BindTCP<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BindTCP<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BindTCP");
+      ds.field("accept_size", &self.accept_size());
+      ds.field("name", &self.name());
+      ds.field("port", &self.port());
+      ds.field("domain", &self.domain());
+      ds.field("wildcard", &self.wildcard());
+      ds.field("names", &self.names());
+      ds.field("token", &self.token());
+      ds.field("lease_ms", &self.lease_ms());
+      ds.field("ip_family", &self.ip_family());
+      ds.field("backlog", &self.backlog());
+      ds.finish()
+  }
+}
+pub enum BindUDPOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BindUDP<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BindUDP<'a> {
+  type Inner = BindUDP<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> BindUDP<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_PORT: flatbuffers::VOffsetT = 6;
+  pub const VT_DOMAIN: flatbuffers::VOffsetT = 8;
+  pub const VT_WILDCARD: flatbuffers::VOffsetT = 10;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 12;
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 14;
+  pub const VT_LEASE_MS: flatbuffers::VOffsetT = 16;
+  pub const VT_IP_FAMILY: flatbuffers::VOffsetT = 18;
+  pub const VT_BACKLOG: flatbuffers::VOffsetT = 20;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BindUDP { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BindUDPArgs<'args>
+  ) -> flatbuffers::WIPOffset<BindUDP<'bldr>> {
+    let mut builder = BindUDPBuilder::new(_fbb);
+    builder.add_backlog(args.backlog);
+    builder.add_lease_ms(args.lease_ms);
+    if let Some(x) = args.token { builder.add_token(x); }
+    if let Some(x) = args.names { builder.add_names(x); }
+    if let Some(x) = args.domain { builder.add_domain(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_port(args.port);
+    builder.add_ip_family(args.ip_family);
+    builder.add_wildcard(args.wildcard);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindUDP::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn port(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(BindUDP::VT_PORT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn domain(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindUDP::VT_DOMAIN, None)}
+  }
+  #[inline]
+  pub fn wildcard(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(BindUDP::VT_WILDCARD, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(BindUDP::VT_NAMES, None)}
+  }
+  #[inline]
+  pub fn token(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(BindUDP::VT_TOKEN, None)}
+  }
+  #[inline]
+  pub fn lease_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindUDP::VT_LEASE_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn ip_family(&self) -> IPFamily {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<IPFamily>(BindUDP::VT_IP_FAMILY, Some(IPFamily::Dual)).unwrap()}
+  }
+  #[inline]
+  pub fn backlog(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindUDP::VT_BACKLOG, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for BindUDP<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u16>("port", Self::VT_PORT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("domain", Self::VT_DOMAIN, false)?
+     .visit_field::<bool>("wildcard", Self::VT_WILDCARD, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("names", Self::VT_NAMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("token", Self::VT_TOKEN, false)?
+     .visit_field::<u32>("lease_ms", Self::VT_LEASE_MS, false)?
+     .visit_field::<IPFamily>("ip_family", Self::VT_IP_FAMILY, false)?
+     .visit_field::<u32>("backlog", Self::VT_BACKLOG, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BindUDPArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub port: u16,
+    pub domain: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub wildcard: bool,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub token: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub lease_ms: u32,
+    pub ip_family: IPFamily,
+    pub backlog: u32,
+}
+impl<'a> Default for BindUDPArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BindUDPArgs {
+      name: None,
+      port: 0,
+      domain: None,
+      wildcard: false,
+      names: None,
+      token: None,
+      lease_ms: 0,
+      ip_family: IPFamily::Dual,
+      backlog: 0,
+    }
+  }
+}
+
+pub struct BindUDPBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BindUDPBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindUDP::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_port(&mut self, port: u16) {
+    self.fbb_.push_slot::<u16>(BindUDP::VT_PORT, port, 0);
+  }
+  #[inline]
+  pub fn add_domain(&mut self, domain: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindUDP::VT_DOMAIN, domain);
+  }
+  #[inline]
+  pub fn add_wildcard(&mut self, wildcard: bool) {
+    self.fbb_.push_slot::<bool>(BindUDP::VT_WILDCARD, wildcard, false);
+  }
+  #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindUDP::VT_NAMES, names);
+  }
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindUDP::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn add_lease_ms(&mut self, lease_ms: u32) {
+    self.fbb_.push_slot::<u32>(BindUDP::VT_LEASE_MS, lease_ms, 0);
+  }
+  #[inline]
+  pub fn add_ip_family(&mut self, ip_family: IPFamily) {
+    self.fbb_.push_slot::<IPFamily>(BindUDP::VT_IP_FAMILY, ip_family, IPFamily::Dual);
+  }
+  #[inline]
+  pub fn add_backlog(&mut self, backlog: u32) {
+    self.fbb_.push_slot::<u32>(BindUDP::VT_BACKLOG, backlog, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindUDPBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BindUDPBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BindUDP<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BindUDP<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BindUDP");
+      ds.field("name", &self.name());
+      ds.field("port", &self.port());
+      ds.field("domain", &self.domain());
+      ds.field("wildcard", &self.wildcard());
+      ds.field("names", &self.names());
+      ds.field("token", &self.token());
+      ds.field("lease_ms", &self.lease_ms());
+      ds.field("ip_family", &self.ip_family());
+      ds.field("backlog", &self.backlog());
+      ds.finish()
+  }
+}
+pub enum BindQUICOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BindQUIC<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BindQUIC<'a> {
+  type Inner = BindQUIC<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> BindQUIC<'a> {
+  pub const VT_ACCEPT_SIZE: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_PORT: flatbuffers::VOffsetT = 8;
+  pub const VT_DOMAIN: flatbuffers::VOffsetT = 10;
+  pub const VT_WILDCARD: flatbuffers::VOffsetT = 12;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 14;
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 16;
+  pub const VT_LEASE_MS: flatbuffers::VOffsetT = 18;
+  pub const VT_IP_FAMILY: flatbuffers::VOffsetT = 20;
+  pub const VT_BACKLOG: flatbuffers::VOffsetT = 22;
+  pub const VT_CLIENT_CERT: flatbuffers::VOffsetT = 24;
+  pub const VT_ALPN: flatbuffers::VOffsetT = 26;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BindQUIC { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BindQUICArgs<'args>
+  ) -> flatbuffers::WIPOffset<BindQUIC<'bldr>> {
+    let mut builder = BindQUICBuilder::new(_fbb);
+    if let Some(x) = args.alpn { builder.add_alpn(x); }
+    builder.add_backlog(args.backlog);
+    builder.add_lease_ms(args.lease_ms);
+    if let Some(x) = args.token { builder.add_token(x); }
+    if let Some(x) = args.names { builder.add_names(x); }
+    if let Some(x) = args.domain { builder.add_domain(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_port(args.port);
+    builder.add_accept_size(args.accept_size);
+    builder.add_client_cert(args.client_cert);
+    builder.add_ip_family(args.ip_family);
+    builder.add_wildcard(args.wildcard);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn accept_size(&self) -> AcceptSize {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AcceptSize>(BindQUIC::VT_ACCEPT_SIZE, Some(AcceptSize::Invalid)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindQUIC::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn port(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(BindQUIC::VT_PORT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn domain(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindQUIC::VT_DOMAIN, None)}
+  }
+  #[inline]
+  pub fn wildcard(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(BindQUIC::VT_WILDCARD, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(BindQUIC::VT_NAMES, None)}
+  }
+  #[inline]
+  pub fn token(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(BindQUIC::VT_TOKEN, None)}
+  }
+  #[inline]
+  pub fn lease_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindQUIC::VT_LEASE_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn ip_family(&self) -> IPFamily {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<IPFamily>(BindQUIC::VT_IP_FAMILY, Some(IPFamily::Dual)).unwrap()}
+  }
+  #[inline]
+  pub fn backlog(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindQUIC::VT_BACKLOG, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn client_cert(&self) -> ClientCertPolicy {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ClientCertPolicy>(BindQUIC::VT_CLIENT_CERT, Some(ClientCertPolicy::None)).unwrap()}
+  }
+  #[inline]
+  pub fn alpn(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(BindQUIC::VT_ALPN, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for BindQUIC<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<AcceptSize>("accept_size", Self::VT_ACCEPT_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u16>("port", Self::VT_PORT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("domain", Self::VT_DOMAIN, false)?
+     .visit_field::<bool>("wildcard", Self::VT_WILDCARD, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("names", Self::VT_NAMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("token", Self::VT_TOKEN, false)?
+     .visit_field::<u32>("lease_ms", Self::VT_LEASE_MS, false)?
+     .visit_field::<IPFamily>("ip_family", Self::VT_IP_FAMILY, false)?
+     .visit_field::<u32>("backlog", Self::VT_BACKLOG, false)?
+     .visit_field::<ClientCertPolicy>("client_cert", Self::VT_CLIENT_CERT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("alpn", Self::VT_ALPN, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BindQUICArgs<'a> {
+    pub accept_size: AcceptSize,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub port: u16,
+    pub domain: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub wildcard: bool,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub token: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub lease_ms: u32,
+    pub ip_family: IPFamily,
+    pub backlog: u32,
+    pub client_cert: ClientCertPolicy,
+    pub alpn: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for BindQUICArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BindQUICArgs {
+      accept_size: AcceptSize::Invalid,
+      name: None,
+      port: 0,
+      domain: None,
+      wildcard: false,
+      names: None,
+      token: None,
+      lease_ms: 0,
+      ip_family: IPFamily::Dual,
+      backlog: 0,
+      client_cert: ClientCertPolicy::None,
+      alpn: None,
+    }
+  }
+}
+
+pub struct BindQUICBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BindQUICBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_accept_size(&mut self, accept_size: AcceptSize) {
+    self.fbb_.push_slot::<AcceptSize>(BindQUIC::VT_ACCEPT_SIZE, accept_size, AcceptSize::Invalid);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindQUIC::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_port(&mut self, port: u16) {
+    self.fbb_.push_slot::<u16>(BindQUIC::VT_PORT, port, 0);
+  }
+  #[inline]
+  pub fn add_domain(&mut self, domain: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindQUIC::VT_DOMAIN, domain);
+  }
+  #[inline]
+  pub fn add_wildcard(&mut self, wildcard: bool) {
+    self.fbb_.push_slot::<bool>(BindQUIC::VT_WILDCARD, wildcard, false);
+  }
+  #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindQUIC::VT_NAMES, names);
+  }
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindQUIC::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn add_lease_ms(&mut self, lease_ms: u32) {
+    self.fbb_.push_slot::<u32>(BindQUIC::VT_LEASE_MS, lease_ms, 0);
+  }
+  #[inline]
+  pub fn add_ip_family(&mut self, ip_family: IPFamily) {
+    self.fbb_.push_slot::<IPFamily>(BindQUIC::VT_IP_FAMILY, ip_family, IPFamily::Dual);
+  }
+  #[inline]
+  pub fn add_backlog(&mut self, backlog: u32) {
+    self.fbb_.push_slot::<u32>(BindQUIC::VT_BACKLOG, backlog, 0);
+  }
+  #[inline]
+  pub fn add_client_cert(&mut self, client_cert: ClientCertPolicy) {
+    self.fbb_.push_slot::<ClientCertPolicy>(BindQUIC::VT_CLIENT_CERT, client_cert, ClientCertPolicy::None);
+  }
+  #[inline]
+  pub fn add_alpn(&mut self, alpn: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindQUIC::VT_ALPN, alpn);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindQUICBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BindQUICBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BindQUIC<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BindQUIC<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BindQUIC");
+      ds.field("accept_size", &self.accept_size());
+      ds.field("name", &self.name());
+      ds.field("port", &self.port());
+      ds.field("domain", &self.domain());
+      ds.field("wildcard", &self.wildcard());
+      ds.field("names", &self.names());
+      ds.field("token", &self.token());
+      ds.field("lease_ms", &self.lease_ms());
+      ds.field("ip_family", &self.ip_family());
+      ds.field("backlog", &self.backlog());
+      ds.field("client_cert", &self.client_cert());
+      ds.field("alpn", &self.alpn());
+      ds.finish()
+  }
+}
+pub enum BindDTLSOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BindDTLS<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BindDTLS<'a> {
+  type Inner = BindDTLS<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> BindDTLS<'a> {
+  pub const VT_ACCEPT_SIZE: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_PORT: flatbuffers::VOffsetT = 8;
+  pub const VT_DOMAIN: flatbuffers::VOffsetT = 10;
+  pub const VT_WILDCARD: flatbuffers::VOffsetT = 12;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 14;
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 16;
+  pub const VT_LEASE_MS: flatbuffers::VOffsetT = 18;
+  pub const VT_IP_FAMILY: flatbuffers::VOffsetT = 20;
+  pub const VT_BACKLOG: flatbuffers::VOffsetT = 22;
+  pub const VT_CLIENT_CERT: flatbuffers::VOffsetT = 24;
+  pub const VT_ALPN: flatbuffers::VOffsetT = 26;
+  pub const VT_MIN_TLS_VERSION: flatbuffers::VOffsetT = 28;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BindDTLS { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BindDTLSArgs<'args>
+  ) -> flatbuffers::WIPOffset<BindDTLS<'bldr>> {
+    let mut builder = BindDTLSBuilder::new(_fbb);
+    if let Some(x) = args.alpn { builder.add_alpn(x); }
+    builder.add_backlog(args.backlog);
+    builder.add_lease_ms(args.lease_ms);
+    if let Some(x) = args.token { builder.add_token(x); }
+    if let Some(x) = args.names { builder.add_names(x); }
+    if let Some(x) = args.domain { builder.add_domain(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_port(args.port);
+    builder.add_accept_size(args.accept_size);
+    builder.add_min_tls_version(args.min_tls_version);
+    builder.add_client_cert(args.client_cert);
+    builder.add_ip_family(args.ip_family);
+    builder.add_wildcard(args.wildcard);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn accept_size(&self) -> AcceptSize {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AcceptSize>(BindDTLS::VT_ACCEPT_SIZE, Some(AcceptSize::Invalid)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindDTLS::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn port(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(BindDTLS::VT_PORT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn domain(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindDTLS::VT_DOMAIN, None)}
+  }
+  #[inline]
+  pub fn wildcard(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(BindDTLS::VT_WILDCARD, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(BindDTLS::VT_NAMES, None)}
+  }
+  #[inline]
+  pub fn token(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(BindDTLS::VT_TOKEN, None)}
+  }
+  #[inline]
+  pub fn lease_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindDTLS::VT_LEASE_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn ip_family(&self) -> IPFamily {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<IPFamily>(BindDTLS::VT_IP_FAMILY, Some(IPFamily::Dual)).unwrap()}
+  }
+  #[inline]
+  pub fn backlog(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindDTLS::VT_BACKLOG, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn client_cert(&self) -> ClientCertPolicy {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ClientCertPolicy>(BindDTLS::VT_CLIENT_CERT, Some(ClientCertPolicy::None)).unwrap()}
+  }
+  #[inline]
+  pub fn alpn(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(BindDTLS::VT_ALPN, None)}
+  }
+  #[inline]
+  pub fn min_tls_version(&self) -> TLSVersion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TLSVersion>(BindDTLS::VT_MIN_TLS_VERSION, Some(TLSVersion::Any)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for BindDTLS<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<AcceptSize>("accept_size", Self::VT_ACCEPT_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u16>("port", Self::VT_PORT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("domain", Self::VT_DOMAIN, false)?
+     .visit_field::<bool>("wildcard", Self::VT_WILDCARD, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("names", Self::VT_NAMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("token", Self::VT_TOKEN, false)?
+     .visit_field::<u32>("lease_ms", Self::VT_LEASE_MS, false)?
+     .visit_field::<IPFamily>("ip_family", Self::VT_IP_FAMILY, false)?
+     .visit_field::<u32>("backlog", Self::VT_BACKLOG, false)?
+     .visit_field::<ClientCertPolicy>("client_cert", Self::VT_CLIENT_CERT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("alpn", Self::VT_ALPN, false)?
+     .visit_field::<TLSVersion>("min_tls_version", Self::VT_MIN_TLS_VERSION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BindDTLSArgs<'a> {
+    pub accept_size: AcceptSize,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub port: u16,
+    pub domain: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub wildcard: bool,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub token: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub lease_ms: u32,
+    pub ip_family: IPFamily,
+    pub backlog: u32,
+    pub client_cert: ClientCertPolicy,
+    pub alpn: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub min_tls_version: TLSVersion,
+}
+impl<'a> Default for BindDTLSArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BindDTLSArgs {
+      accept_size: AcceptSize::Invalid,
+      name: None,
+      port: 0,
+      domain: None,
+      wildcard: false,
+      names: None,
+      token: None,
+      lease_ms: 0,
+      ip_family: IPFamily::Dual,
+      backlog: 0,
+      client_cert: ClientCertPolicy::None,
+      alpn: None,
+      min_tls_version: TLSVersion::Any,
+    }
+  }
+}
+
+pub struct BindDTLSBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BindDTLSBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_accept_size(&mut self, accept_size: AcceptSize) {
+    self.fbb_.push_slot::<AcceptSize>(BindDTLS::VT_ACCEPT_SIZE, accept_size, AcceptSize::Invalid);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindDTLS::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_port(&mut self, port: u16) {
+    self.fbb_.push_slot::<u16>(BindDTLS::VT_PORT, port, 0);
+  }
+  #[inline]
+  pub fn add_domain(&mut self, domain: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindDTLS::VT_DOMAIN, domain);
+  }
+  #[inline]
+  pub fn add_wildcard(&mut self, wildcard: bool) {
+    self.fbb_.push_slot::<bool>(BindDTLS::VT_WILDCARD, wildcard, false);
+  }
+  #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindDTLS::VT_NAMES, names);
+  }
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindDTLS::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn add_lease_ms(&mut self, lease_ms: u32) {
+    self.fbb_.push_slot::<u32>(BindDTLS::VT_LEASE_MS, lease_ms, 0);
+  }
+  #[inline]
+  pub fn add_ip_family(&mut self, ip_family: IPFamily) {
+    self.fbb_.push_slot::<IPFamily>(BindDTLS::VT_IP_FAMILY, ip_family, IPFamily::Dual);
+  }
+  #[inline]
+  pub fn add_backlog(&mut self, backlog: u32) {
+    self.fbb_.push_slot::<u32>(BindDTLS::VT_BACKLOG, backlog, 0);
+  }
+  #[inline]
+  pub fn add_client_cert(&mut self, client_cert: ClientCertPolicy) {
+    self.fbb_.push_slot::<ClientCertPolicy>(BindDTLS::VT_CLIENT_CERT, client_cert, ClientCertPolicy::None);
+  }
+  #[inline]
+  pub fn add_alpn(&mut self, alpn: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindDTLS::VT_ALPN, alpn);
+  }
+  #[inline]
+  pub fn add_min_tls_version(&mut self, min_tls_version: TLSVersion) {
+    self.fbb_.push_slot::<TLSVersion>(BindDTLS::VT_MIN_TLS_VERSION, min_tls_version, TLSVersion::Any);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindDTLSBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BindDTLSBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BindDTLS<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BindDTLS<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BindDTLS");
+      ds.field("accept_size", &self.accept_size());
+      ds.field("name", &self.name());
+      ds.field("port", &self.port());
+      ds.field("domain", &self.domain());
+      ds.field("wildcard", &self.wildcard());
+      ds.field("names", &self.names());
+      ds.field("token", &self.token());
+      ds.field("lease_ms", &self.lease_ms());
+      ds.field("ip_family", &self.ip_family());
+      ds.field("backlog", &self.backlog());
+      ds.field("client_cert", &self.client_cert());
+      ds.field("alpn", &self.alpn());
+      ds.field("min_tls_version", &self.min_tls_version());
+      ds.finish()
+  }
+}
+pub enum BindTLSPassthroughOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BindTLSPassthrough<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BindTLSPassthrough<'a> {
+  type Inner = BindTLSPassthrough<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> BindTLSPassthrough<'a> {
+  pub const VT_ACCEPT_SIZE: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_PORT: flatbuffers::VOffsetT = 8;
+  pub const VT_DOMAIN: flatbuffers::VOffsetT = 10;
+  pub const VT_WILDCARD: flatbuffers::VOffsetT = 12;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 14;
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 16;
+  pub const VT_LEASE_MS: flatbuffers::VOffsetT = 18;
+  pub const VT_IP_FAMILY: flatbuffers::VOffsetT = 20;
+  pub const VT_BACKLOG: flatbuffers::VOffsetT = 22;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BindTLSPassthrough { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BindTLSPassthroughArgs<'args>
+  ) -> flatbuffers::WIPOffset<BindTLSPassthrough<'bldr>> {
+    let mut builder = BindTLSPassthroughBuilder::new(_fbb);
+    builder.add_backlog(args.backlog);
+    builder.add_lease_ms(args.lease_ms);
+    if let Some(x) = args.token { builder.add_token(x); }
+    if let Some(x) = args.names { builder.add_names(x); }
+    if let Some(x) = args.domain { builder.add_domain(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_port(args.port);
+    builder.add_accept_size(args.accept_size);
+    builder.add_ip_family(args.ip_family);
+    builder.add_wildcard(args.wildcard);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn accept_size(&self) -> AcceptSize {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AcceptSize>(BindTLSPassthrough::VT_ACCEPT_SIZE, Some(AcceptSize::Invalid)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindTLSPassthrough::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn port(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(BindTLSPassthrough::VT_PORT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn domain(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindTLSPassthrough::VT_DOMAIN, None)}
+  }
+  #[inline]
+  pub fn wildcard(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(BindTLSPassthrough::VT_WILDCARD, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(BindTLSPassthrough::VT_NAMES, None)}
+  }
+  #[inline]
+  pub fn token(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(BindTLSPassthrough::VT_TOKEN, None)}
+  }
+  #[inline]
+  pub fn lease_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindTLSPassthrough::VT_LEASE_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn ip_family(&self) -> IPFamily {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<IPFamily>(BindTLSPassthrough::VT_IP_FAMILY, Some(IPFamily::Dual)).unwrap()}
+  }
+  #[inline]
+  pub fn backlog(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BindTLSPassthrough::VT_BACKLOG, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for BindTLSPassthrough<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<AcceptSize>("accept_size", Self::VT_ACCEPT_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u16>("port", Self::VT_PORT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("domain", Self::VT_DOMAIN, false)?
+     .visit_field::<bool>("wildcard", Self::VT_WILDCARD, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("names", Self::VT_NAMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("token", Self::VT_TOKEN, false)?
+     .visit_field::<u32>("lease_ms", Self::VT_LEASE_MS, false)?
+     .visit_field::<IPFamily>("ip_family", Self::VT_IP_FAMILY, false)?
+     .visit_field::<u32>("backlog", Self::VT_BACKLOG, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BindTLSPassthroughArgs<'a> {
+    pub accept_size: AcceptSize,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub port: u16,
+    pub domain: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub wildcard: bool,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub token: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub lease_ms: u32,
+    pub ip_family: IPFamily,
+    pub backlog: u32,
+}
+impl<'a> Default for BindTLSPassthroughArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BindTLSPassthroughArgs {
+      accept_size: AcceptSize::Invalid,
+      name: None,
+      port: 0,
+      domain: None,
+      wildcard: false,
+      names: None,
+      token: None,
+      lease_ms: 0,
+      ip_family: IPFamily::Dual,
+      backlog: 0,
+    }
+  }
+}
+
+pub struct BindTLSPassthroughBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BindTLSPassthroughBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_accept_size(&mut self, accept_size: AcceptSize) {
+    self.fbb_.push_slot::<AcceptSize>(BindTLSPassthrough::VT_ACCEPT_SIZE, accept_size, AcceptSize::Invalid);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLSPassthrough::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_port(&mut self, port: u16) {
+    self.fbb_.push_slot::<u16>(BindTLSPassthrough::VT_PORT, port, 0);
+  }
+  #[inline]
+  pub fn add_domain(&mut self, domain: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLSPassthrough::VT_DOMAIN, domain);
+  }
+  #[inline]
+  pub fn add_wildcard(&mut self, wildcard: bool) {
+    self.fbb_.push_slot::<bool>(BindTLSPassthrough::VT_WILDCARD, wildcard, false);
+  }
+  #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLSPassthrough::VT_NAMES, names);
+  }
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLSPassthrough::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn add_lease_ms(&mut self, lease_ms: u32) {
+    self.fbb_.push_slot::<u32>(BindTLSPassthrough::VT_LEASE_MS, lease_ms, 0);
+  }
+  #[inline]
+  pub fn add_ip_family(&mut self, ip_family: IPFamily) {
+    self.fbb_.push_slot::<IPFamily>(BindTLSPassthrough::VT_IP_FAMILY, ip_family, IPFamily::Dual);
+  }
+  #[inline]
+  pub fn add_backlog(&mut self, backlog: u32) {
+    self.fbb_.push_slot::<u32>(BindTLSPassthrough::VT_BACKLOG, backlog, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindTLSPassthroughBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BindTLSPassthroughBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BindTLSPassthrough<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BindTLSPassthrough<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BindTLSPassthrough");
+      ds.field("accept_size", &self.accept_size());
+      ds.field("name", &self.name());
+      ds.field("port", &self.port());
+      ds.field("domain", &self.domain());
+      ds.field("wildcard", &self.wildcard());
+      ds.field("names", &self.names());
+      ds.field("token", &self.token());
+      ds.field("lease_ms", &self.lease_ms());
+      ds.field("ip_family", &self.ip_family());
+      ds.field("backlog", &self.backlog());
+      ds.finish()
+  }
+}
+pub enum BindingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Binding<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Binding<'a> {
+  type Inner = Binding<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Binding<'a> {
+  pub const VT_ERROR: flatbuffers::VOffsetT = 4;
+  pub const VT_LISTEN_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_HOST: flatbuffers::VOffsetT = 8;
+  pub const VT_PORT: flatbuffers::VOffsetT = 10;
+  pub const VT_HOSTS: flatbuffers::VOffsetT = 12;
+  pub const VT_TOKEN: flatbuffers::VOffsetT = 14;
+  pub const VT_PROTOCOL: flatbuffers::VOffsetT = 16;
+  pub const VT_NAMES: flatbuffers::VOffsetT = 18;
+  pub const VT_LEASE_MS: flatbuffers::VOffsetT = 20;
+  pub const VT_IP_FAMILY: flatbuffers::VOffsetT = 22;
+  pub const VT_BACKLOG: flatbuffers::VOffsetT = 24;
+  pub const VT_CERT_SHA256: flatbuffers::VOffsetT = 26;
+  pub const VT_CERT_NOT_AFTER_MS: flatbuffers::VOffsetT = 28;
+  pub const VT_PUBLIC_ADDRS: flatbuffers::VOffsetT = 30;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Binding { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BindingArgs<'args>
+  ) -> flatbuffers::WIPOffset<Binding<'bldr>> {
+    let mut builder = BindingBuilder::new(_fbb);
+    builder.add_cert_not_after_ms(args.cert_not_after_ms);
+    if let Some(x) = args.public_addrs { builder.add_public_addrs(x); }
+    if let Some(x) = args.cert_sha256 { builder.add_cert_sha256(x); }
+    builder.add_backlog(args.backlog);
+    builder.add_lease_ms(args.lease_ms);
+    if let Some(x) = args.names { builder.add_names(x); }
+    if let Some(x) = args.token { builder.add_token(x); }
+    if let Some(x) = args.hosts { builder.add_hosts(x); }
+    if let Some(x) = args.host { builder.add_host(x); }
+    builder.add_listen_id(args.listen_id);
+    builder.add_port(args.port);
+    builder.add_error(args.error);
+    builder.add_ip_family(args.ip_family);
+    builder.add_protocol(args.protocol);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn error(&self) -> BindError {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<BindError>(Binding::VT_ERROR, Some(BindError::None)).unwrap()}
+  }
+  #[inline]
+  pub fn listen_id(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(Binding::VT_LISTEN_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn host(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Binding::VT_HOST, None)}
+  }
+  #[inline]
+  pub fn port(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Binding::VT_PORT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn hosts(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Binding::VT_HOSTS, None)}
+  }
+  #[inline]
+  pub fn token(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Binding::VT_TOKEN, None)}
+  }
+  #[inline]
+  pub fn protocol(&self) -> Protocol {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Protocol>(Binding::VT_PROTOCOL, Some(Protocol::TLS)).unwrap()}
+  }
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Binding::VT_NAMES, None)}
+  }
+  #[inline]
+  pub fn lease_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Binding::VT_LEASE_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn ip_family(&self) -> IPFamily {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<IPFamily>(Binding::VT_IP_FAMILY, Some(IPFamily::Dual)).unwrap()}
+  }
+  #[inline]
+  pub fn backlog(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Binding::VT_BACKLOG, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn cert_sha256(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Binding::VT_CERT_SHA256, None)}
+  }
+  #[inline]
+  pub fn cert_not_after_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Binding::VT_CERT_NOT_AFTER_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn public_addrs(&self) -> Option<flatbuffers::Vector<'a, IPAddr>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, IPAddr>>>(Binding::VT_PUBLIC_ADDRS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Binding<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<BindError>("error", Self::VT_ERROR, false)?
+     .visit_field::<i32>("listen_id", Self::VT_LISTEN_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("host", Self::VT_HOST, false)?
+     .visit_field::<u16>("port", Self::VT_PORT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("hosts", Self::VT_HOSTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("token", Self::VT_TOKEN, false)?
+     .visit_field::<Protocol>("protocol", Self::VT_PROTOCOL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("names", Self::VT_NAMES, false)?
+     .visit_field::<u32>("lease_ms", Self::VT_LEASE_MS, false)?
+     .visit_field::<IPFamily>("ip_family", Self::VT_IP_FAMILY, false)?
+     .visit_field::<u32>("backlog", Self::VT_BACKLOG, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("cert_sha256", Self::VT_CERT_SHA256, false)?
+     .visit_field::<u64>("cert_not_after_ms", Self::VT_CERT_NOT_AFTER_MS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, IPAddr>>>("public_addrs", Self::VT_PUBLIC_ADDRS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BindingArgs<'a> {
+    pub error: BindError,
+    pub listen_id: i32,
+    pub host: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub port: u16,
+    pub hosts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub token: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub protocol: Protocol,
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub lease_ms: u32,
+    pub ip_family: IPFamily,
+    pub backlog: u32,
+    pub cert_sha256: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub cert_not_after_ms: u64,
+    pub public_addrs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, IPAddr>>>,
+}
+impl<'a> Default for BindingArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BindingArgs {
+      error: BindError::None,
+      listen_id: 0,
+      host: None,
+      port: 0,
+      hosts: None,
+      token: None,
+      protocol: Protocol::TLS,
+      names: None,
+      lease_ms: 0,
+      ip_family: IPFamily::Dual,
+      backlog: 0,
+      cert_sha256: None,
+      cert_not_after_ms: 0,
+      public_addrs: None,
+    }
+  }
+}
+
+pub struct BindingBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BindingBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_error(&mut self, error: BindError) {
+    self.fbb_.push_slot::<BindError>(Binding::VT_ERROR, error, BindError::None);
+  }
+  #[inline]
+  pub fn add_listen_id(&mut self, listen_id: i32) {
+    self.fbb_.push_slot::<i32>(Binding::VT_LISTEN_ID, listen_id, 0);
+  }
+  #[inline]
+  pub fn add_host(&mut self, host: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Binding::VT_HOST, host);
+  }
+  #[inline]
+  pub fn add_port(&mut self, port: u16) {
+    self.fbb_.push_slot::<u16>(Binding::VT_PORT, port, 0);
+  }
+  #[inline]
+  pub fn add_hosts(&mut self, hosts: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Binding::VT_HOSTS, hosts);
+  }
+  #[inline]
+  pub fn add_token(&mut self, token: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Binding::VT_TOKEN, token);
+  }
+  #[inline]
+  pub fn add_protocol(&mut self, protocol: Protocol) {
+    self.fbb_.push_slot::<Protocol>(Binding::VT_PROTOCOL, protocol, Protocol::TLS);
+  }
+  #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Binding::VT_NAMES, names);
+  }
+  #[inline]
+  pub fn add_lease_ms(&mut self, lease_ms: u32) {
+    self.fbb_.push_slot::<u32>(Binding::VT_LEASE_MS, lease_ms, 0);
+  }
+  #[inline]
+  pub fn add_ip_family(&mut self, ip_family: IPFamily) {
+    self.fbb_.push_slot::<IPFamily>(Binding::VT_IP_FAMILY, ip_family, IPFamily::Dual);
+  }
+  #[inline]
+  pub fn add_backlog(&mut self, backlog: u32) {
+    self.fbb_.push_slot::<u32>(Binding::VT_BACKLOG, backlog, 0);
+  }
+  #[inline]
+  pub fn add_cert_sha256(&mut self, cert_sha256: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Binding::VT_CERT_SHA256, cert_sha256);
+  }
+  #[inline]
+  pub fn add_cert_not_after_ms(&mut self, cert_not_after_ms: u64) {
+    self.fbb_.push_slot::<u64>(Binding::VT_CERT_NOT_AFTER_MS, cert_not_after_ms, 0);
+  }
+  #[inline]
+  pub fn add_public_addrs(&mut self, public_addrs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , IPAddr>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Binding::VT_PUBLIC_ADDRS, public_addrs);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindingBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BindingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Binding<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Binding<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Binding");
+      ds.field("error", &self.error());
+      ds.field("listen_id", &self.listen_id());
+      ds.field("host", &self.host());
+      ds.field("port", &self.port());
+      ds.field("hosts", &self.hosts());
+      ds.field("token", &self.token());
+      ds.field("protocol", &self.protocol());
+      ds.field("names", &self.names());
+      ds.field("lease_ms", &self.lease_ms());
+      ds.field("ip_family", &self.ip_family());
+      ds.field("backlog", &self.backlog());
+      ds.field("cert_sha256", &self.cert_sha256());
+      ds.field("cert_not_after_ms", &self.cert_not_after_ms());
+      ds.field("public_addrs", &self.public_addrs());
+      ds.finish()
+  }
+}
+pub enum BindTLSManyOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BindTLSMany<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BindTLSMany<'a> {
+  type Inner = BindTLSMany<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> BindTLSMany<'a> {
+  pub const VT_BINDINGS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BindTLSMany { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BindTLSManyArgs<'args>
+  ) -> flatbuffers::WIPOffset<BindTLSMany<'bldr>> {
+    let mut builder = BindTLSManyBuilder::new(_fbb);
+    if let Some(x) = args.bindings { builder.add_bindings(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn bindings(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<BindTLS<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<BindTLS<'a>>>>>(BindTLSMany::VT_BINDINGS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for BindTLSMany<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<BindTLS>>>>("bindings", Self::VT_BINDINGS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BindTLSManyArgs<'a> {
+    pub bindings: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<BindTLS<'a>>>>>,
+}
+impl<'a> Default for BindTLSManyArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BindTLSManyArgs {
+      bindings: None,
+    }
+  }
+}
+
+pub struct BindTLSManyBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BindTLSManyBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_bindings(&mut self, bindings: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<BindTLS<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLSMany::VT_BINDINGS, bindings);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindTLSManyBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BindTLSManyBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BindTLSMany<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BindTLSMany<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BindTLSMany");
+      ds.field("bindings", &self.bindings());
+      ds.finish()
+  }
+}
+pub enum BindingsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Bindings<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Bindings<'a> {
+  type Inner = Bindings<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Bindings<'a> {
+  pub const VT_ERROR: flatbuffers::VOffsetT = 4;
+  pub const VT_BINDINGS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Bindings { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args BindingsArgs<'args>
+  ) -> flatbuffers::WIPOffset<Bindings<'bldr>> {
+    let mut builder = BindingsBuilder::new(_fbb);
+    if let Some(x) = args.bindings { builder.add_bindings(x); }
+    builder.add_error(args.error);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn error(&self) -> BindError {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<BindError>(Bindings::VT_ERROR, Some(BindError::None)).unwrap()}
+  }
+  #[inline]
+  pub fn bindings(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Binding<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Binding<'a>>>>>(Bindings::VT_BINDINGS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Bindings<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<BindError>("error", Self::VT_ERROR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Binding>>>>("bindings", Self::VT_BINDINGS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BindingsArgs<'a> {
+    pub error: BindError,
+    pub bindings: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Binding<'a>>>>>,
+}
+impl<'a> Default for BindingsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BindingsArgs {
+      error: BindError::None,
+      bindings: None,
+    }
+  }
+}
+
+pub struct BindingsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BindingsBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_error(&mut self, error: BindError) {
+    self.fbb_.push_slot::<BindError>(Bindings::VT_ERROR, error, BindError::None);
+  }
+  #[inline]
+  pub fn add_bindings(&mut self, bindings: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Binding<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Bindings::VT_BINDINGS, bindings);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindingsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BindingsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Bindings<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Bindings<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Bindings");
+      ds.field("error", &self.error());
+      ds.field("bindings", &self.bindings());
+      ds.finish()
+  }
+}
+pub enum DatagramOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Datagram<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Datagram<'a> {
+  type Inner = Datagram<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Datagram<'a> {
+  pub const VT_ADDR: flatbuffers::VOffsetT = 4;
+  pub const VT_PORT: flatbuffers::VOffsetT = 6;
+  pub const VT_DATA: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Datagram { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args DatagramArgs<'args>
+  ) -> flatbuffers::WIPOffset<Datagram<'bldr>> {
+    let mut builder = DatagramBuilder::new(_fbb);
+    if let Some(x) = args.data { builder.add_data(x); }
+    if let Some(x) = args.addr { builder.add_addr(x); }
+    builder.add_port(args.port);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn addr(&self) -> Option<&'a IPAddr> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<IPAddr>(Datagram::VT_ADDR, None)}
+  }
+  #[inline]
+  pub fn port(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Datagram::VT_PORT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Datagram::VT_DATA, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Datagram<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<IPAddr>("addr", Self::VT_ADDR, false)?
+     .visit_field::<u16>("port", Self::VT_PORT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("data", Self::VT_DATA, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DatagramArgs<'a> {
+    pub addr: Option<&'a IPAddr>,
+    pub port: u16,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for DatagramArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DatagramArgs {
+      addr: None,
+      port: 0,
+      data: None,
+    }
+  }
+}
+
+pub struct DatagramBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DatagramBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_addr(&mut self, addr: &IPAddr) {
+    self.fbb_.push_slot_always::<&IPAddr>(Datagram::VT_ADDR, addr);
+  }
+  #[inline]
+  pub fn add_port(&mut self, port: u16) {
+    self.fbb_.push_slot::<u16>(Datagram::VT_PORT, port, 0);
+  }
+  #[inline]
+  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Datagram::VT_DATA, data);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DatagramBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DatagramBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Datagram<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Datagram<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Datagram");
+      ds.field("addr", &self.addr());
+      ds.field("port", &self.port());
+      ds.field("data", &self.data());
+      ds.finish()
+  }
+}
+pub enum OpenQUICStreamOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OpenQUICStream<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OpenQUICStream<'a> {
+  type Inner = OpenQUICStream<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> OpenQUICStream<'a> {
+  pub const VT_CONN_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_BIDI: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OpenQUICStream { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args OpenQUICStreamArgs
+  ) -> flatbuffers::WIPOffset<OpenQUICStream<'bldr>> {
+    let mut builder = OpenQUICStreamBuilder::new(_fbb);
+    builder.add_conn_id(args.conn_id);
+    builder.add_bidi(args.bidi);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn conn_id(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(OpenQUICStream::VT_CONN_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn bidi(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(OpenQUICStream::VT_BIDI, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for OpenQUICStream<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("conn_id", Self::VT_CONN_ID, false)?
+     .visit_field::<bool>("bidi", Self::VT_BIDI, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OpenQUICStreamArgs {
+    pub conn_id: i32,
+    pub bidi: bool,
+}
+impl<'a> Default for OpenQUICStreamArgs {
+  #[inline]
+  fn default() -> Self {
+    OpenQUICStreamArgs {
+      conn_id: 0,
+      bidi: false,
+    }
+  }
+}
+
+pub struct OpenQUICStreamBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> OpenQUICStreamBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_conn_id(&mut self, conn_id: i32) {
+    self.fbb_.push_slot::<i32>(OpenQUICStream::VT_CONN_ID, conn_id, 0);
+  }
+  #[inline]
+  pub fn add_bidi(&mut self, bidi: bool) {
+    self.fbb_.push_slot::<bool>(OpenQUICStream::VT_BIDI, bidi, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> OpenQUICStreamBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    OpenQUICStreamBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OpenQUICStream<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OpenQUICStream<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OpenQUICStream");
+      ds.field("conn_id", &self.conn_id());
+      ds.field("bidi", &self.bidi());
+      ds.finish()
+  }
+}
+pub enum QUICStreamOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QUICStream<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QUICStream<'a> {
+  type Inner = QUICStream<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QUICStream<'a> {
+  pub const VT_ERROR: flatbuffers::VOffsetT = 4;
+  pub const VT_STREAM_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_QUIC_ID: flatbuffers::VOffsetT = 8;
+  pub const VT_BIDI: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QUICStream { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args QUICStreamArgs
+  ) -> flatbuffers::WIPOffset<QUICStream<'bldr>> {
+    let mut builder = QUICStreamBuilder::new(_fbb);
+    builder.add_quic_id(args.quic_id);
+    builder.add_stream_id(args.stream_id);
+    builder.add_error(args.error);
+    builder.add_bidi(args.bidi);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn error(&self) -> AcceptError {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AcceptError>(QUICStream::VT_ERROR, Some(AcceptError::None)).unwrap()}
+  }
+  #[inline]
+  pub fn stream_id(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(QUICStream::VT_STREAM_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn quic_id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(QUICStream::VT_QUIC_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn bidi(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(QUICStream::VT_BIDI, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for QUICStream<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<AcceptError>("error", Self::VT_ERROR, false)?
+     .visit_field::<i32>("stream_id", Self::VT_STREAM_ID, false)?
+     .visit_field::<u64>("quic_id", Self::VT_QUIC_ID, false)?
+     .visit_field::<bool>("bidi", Self::VT_BIDI, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QUICStreamArgs {
+    pub error: AcceptError,
+    pub stream_id: i32,
+    pub quic_id: u64,
+    pub bidi: bool,
+}
+impl<'a> Default for QUICStreamArgs {
+  #[inline]
+  fn default() -> Self {
+    QUICStreamArgs {
+      error: AcceptError::None,
+      stream_id: 0,
+      quic_id: 0,
+      bidi: false,
+    }
+  }
+}
+
+pub struct QUICStreamBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QUICStreamBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_error(&mut self, error: AcceptError) {
+    self.fbb_.push_slot::<AcceptError>(QUICStream::VT_ERROR, error, AcceptError::None);
+  }
+  #[inline]
+  pub fn add_stream_id(&mut self, stream_id: i32) {
+    self.fbb_.push_slot::<i32>(QUICStream::VT_STREAM_ID, stream_id, 0);
+  }
+  #[inline]
+  pub fn add_quic_id(&mut self, quic_id: u64) {
+    self.fbb_.push_slot::<u64>(QUICStream::VT_QUIC_ID, quic_id, 0);
+  }
+  #[inline]
+  pub fn add_bidi(&mut self, bidi: bool) {
+    self.fbb_.push_slot::<bool>(QUICStream::VT_BIDI, bidi, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QUICStreamBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QUICStreamBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QUICStream<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QUICStream<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QUICStream");
+      ds.field("error", &self.error());
+      ds.field("stream_id", &self.stream_id());
+      ds.field("quic_id", &self.quic_id());
+      ds.field("bidi", &self.bidi());
+      ds.finish()
+  }
+}
+pub enum GetQuotaOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GetQuota<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetQuota<'a> {
+  type Inner = GetQuota<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetQuota<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetQuota { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args GetQuotaArgs
+  ) -> flatbuffers::WIPOffset<GetQuota<'bldr>> {
+    let mut builder = GetQuotaBuilder::new(_fbb);
+    builder.finish()
+  }
+
+
+}
+
+impl flatbuffers::Verifiable for GetQuota<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetQuotaArgs {
+}
+impl<'a> Default for GetQuotaArgs {
+  #[inline]
+  fn default() -> Self {
+    GetQuotaArgs {
+    }
+  }
+}
+
+pub struct GetQuotaBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GetQuotaBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GetQuotaBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    GetQuotaBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetQuota<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetQuota<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetQuota");
+      ds.finish()
+  }
+}
+pub enum QuotaOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Quota<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Quota<'a> {
+  type Inner = Quota<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Quota<'a> {
+  pub const VT_REMAINING_BINDINGS: flatbuffers::VOffsetT = 4;
+  pub const VT_MAX_ACCEPT_SIZE: flatbuffers::VOffsetT = 6;
+  pub const VT_PORT_RANGES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Quota { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args QuotaArgs<'args>
+  ) -> flatbuffers::WIPOffset<Quota<'bldr>> {
+    let mut builder = QuotaBuilder::new(_fbb);
+    if let Some(x) = args.port_ranges { builder.add_port_ranges(x); }
+    builder.add_remaining_bindings(args.remaining_bindings);
+    builder.add_max_accept_size(args.max_accept_size);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn remaining_bindings(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Quota::VT_REMAINING_BINDINGS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn max_accept_size(&self) -> AcceptSize {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AcceptSize>(Quota::VT_MAX_ACCEPT_SIZE, Some(AcceptSize::Invalid)).unwrap()}
+  }
+  #[inline]
+  pub fn port_ranges(&self) -> Option<flatbuffers::Vector<'a, PortRange>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, PortRange>>>(Quota::VT_PORT_RANGES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Quota<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("remaining_bindings", Self::VT_REMAINING_BINDINGS, false)?
+     .visit_field::<AcceptSize>("max_accept_size", Self::VT_MAX_ACCEPT_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, PortRange>>>("port_ranges", Self::VT_PORT_RANGES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QuotaArgs<'a> {
+    pub remaining_bindings: u32,
+    pub max_accept_size: AcceptSize,
+    pub port_ranges: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, PortRange>>>,
+}
+impl<'a> Default for QuotaArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    QuotaArgs {
+      remaining_bindings: 0,
+      max_accept_size: AcceptSize::Invalid,
+      port_ranges: None,
+    }
+  }
+}
+
+pub struct QuotaBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QuotaBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_remaining_bindings(&mut self, remaining_bindings: u32) {
+    self.fbb_.push_slot::<u32>(Quota::VT_REMAINING_BINDINGS, remaining_bindings, 0);
+  }
+  #[inline]
+  pub fn add_max_accept_size(&mut self, max_accept_size: AcceptSize) {
+    self.fbb_.push_slot::<AcceptSize>(Quota::VT_MAX_ACCEPT_SIZE, max_accept_size, AcceptSize::Invalid);
+  }
+  #[inline]
+  pub fn add_port_ranges(&mut self, port_ranges: flatbuffers::WIPOffset<flatbuffers::Vector<'b , PortRange>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Quota::VT_PORT_RANGES, port_ranges);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QuotaBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QuotaBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Quota<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Quota<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Quota");
+      ds.field("remaining_bindings", &self.remaining_bindings());
+      ds.field("max_accept_size", &self.max_accept_size());
+      ds.field("port_ranges", &self.port_ranges());
+      ds.finish()
+  }
+}
+pub enum ListBindingsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ListBindings<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ListBindings<'a> {
+  type Inner = ListBindings<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ListBindings<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ListBindings { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ListBindingsArgs
+  ) -> flatbuffers::WIPOffset<ListBindings<'bldr>> {
+    let mut builder = ListBindingsBuilder::new(_fbb);
+    builder.finish()
+  }
+
+
+}
+
+impl flatbuffers::Verifiable for ListBindings<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ListBindingsArgs {
+}
+impl<'a> Default for ListBindingsArgs {
+  #[inline]
+  fn default() -> Self {
+    ListBindingsArgs {
+    }
+  }
+}
+
+pub struct ListBindingsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ListBindingsBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ListBindingsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ListBindingsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ListBindings<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ListBindings<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ListBindings");
+      ds.finish()
+  }
+}
+pub enum UnbindOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Unbind<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Unbind<'a> {
+  type Inner = Unbind<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Unbind<'a> {
+  pub const VT_LISTEN_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Unbind { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args UnbindArgs
+  ) -> flatbuffers::WIPOffset<Unbind<'bldr>> {
+    let mut builder = UnbindBuilder::new(_fbb);
+    builder.add_listen_id(args.listen_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn listen_id(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(Unbind::VT_LISTEN_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Unbind<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("listen_id", Self::VT_LISTEN_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct UnbindArgs {
+    pub listen_id: i32,
+}
+impl<'a> Default for UnbindArgs {
+  #[inline]
+  fn default() -> Self {
+    UnbindArgs {
+      listen_id: 0,
+    }
+  }
+}
+
+pub struct UnbindBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> UnbindBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_listen_id(&mut self, listen_id: i32) {
+    self.fbb_.push_slot::<i32>(Unbind::VT_LISTEN_ID, listen_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> UnbindBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    UnbindBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Unbind<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Unbind<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Unbind");
+      ds.field("listen_id", &self.listen_id());
+      ds.finish()
+  }
+}
+pub enum UnbindingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Unbinding<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Unbinding<'a> {
+  type Inner = Unbinding<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Unbinding<'a> {
+  pub const VT_ERROR: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Unbinding { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args UnbindingArgs
+  ) -> flatbuffers::WIPOffset<Unbinding<'bldr>> {
+    let mut builder = UnbindingBuilder::new(_fbb);
+    builder.add_error(args.error);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn error(&self) -> UnbindError {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<UnbindError>(Unbinding::VT_ERROR, Some(UnbindError::None)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Unbinding<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<UnbindError>("error", Self::VT_ERROR, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct UnbindingArgs {
+    pub error: UnbindError,
+}
+impl<'a> Default for UnbindingArgs {
+  #[inline]
+  fn default() -> Self {
+    UnbindingArgs {
+      error: UnbindError::None,
+    }
+  }
+}
+
+pub struct UnbindingBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> UnbindingBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_error(&mut self, error: UnbindError) {
+    self.fbb_.push_slot::<UnbindError>(Unbinding::VT_ERROR, error, UnbindError::None);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> UnbindingBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    UnbindingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Unbinding<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Unbinding<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Unbinding");
+      ds.field("error", &self.error());
+      ds.finish()
+  }
+}
+pub enum RenameBindingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RenameBinding<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RenameBinding<'a> {
+  type Inner = RenameBinding<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RenameBinding<'a> {
+  pub const VT_LISTEN_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RenameBinding { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RenameBindingArgs<'args>
+  ) -> flatbuffers::WIPOffset<RenameBinding<'bldr>> {
+    let mut builder = RenameBindingBuilder::new(_fbb);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_listen_id(args.listen_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn listen_id(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(RenameBinding::VT_LISTEN_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(RenameBinding::VT_NAME, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for RenameBinding<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("listen_id", Self::VT_LISTEN_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RenameBindingArgs<'a> {
+    pub listen_id: i32,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for RenameBindingArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RenameBindingArgs {
+      listen_id: 0,
+      name: None,
+    }
+  }
+}
+
+pub struct RenameBindingBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RenameBindingBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_listen_id(&mut self, listen_id: i32) {
+    self.fbb_.push_slot::<i32>(RenameBinding::VT_LISTEN_ID, listen_id, 0);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RenameBinding::VT_NAME, name);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RenameBindingBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RenameBindingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RenameBinding<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RenameBinding<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RenameBinding");
+      ds.field("listen_id", &self.listen_id());
+      ds.field("name", &self.name());
+      ds.finish()
+  }
+}
+pub enum RenewBindingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RenewBinding<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RenewBinding<'a> {
+  type Inner = RenewBinding<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RenewBinding<'a> {
+  pub const VT_LISTEN_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RenewBinding { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RenewBindingArgs
+  ) -> flatbuffers::WIPOffset<RenewBinding<'bldr>> {
+    let mut builder = RenewBindingBuilder::new(_fbb);
+    builder.add_listen_id(args.listen_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn listen_id(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(RenewBinding::VT_LISTEN_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for RenewBinding<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("listen_id", Self::VT_LISTEN_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RenewBindingArgs {
+    pub listen_id: i32,
+}
+impl<'a> Default for RenewBindingArgs {
+  #[inline]
+  fn default() -> Self {
+    RenewBindingArgs {
+      listen_id: 0,
+    }
+  }
+}
+
+pub struct RenewBindingBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RenewBindingBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_listen_id(&mut self, listen_id: i32) {
+    self.fbb_.push_slot::<i32>(RenewBinding::VT_LISTEN_ID, listen_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RenewBindingBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RenewBindingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RenewBinding<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RenewBinding<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RenewBinding");
+      ds.field("listen_id", &self.listen_id());
+      ds.finish()
+  }
+}
+pub enum GetTLSDetailsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GetTLSDetails<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetTLSDetails<'a> {
+  type Inner = GetTLSDetails<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GetTLSDetails<'a> {
+  pub const VT_LISTEN_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GetTLSDetails { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args GetTLSDetailsArgs
+  ) -> flatbuffers::WIPOffset<GetTLSDetails<'bldr>> {
+    let mut builder = GetTLSDetailsBuilder::new(_fbb);
+    builder.add_listen_id(args.listen_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn listen_id(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(GetTLSDetails::VT_LISTEN_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for GetTLSDetails<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("listen_id", Self::VT_LISTEN_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GetTLSDetailsArgs {
+    pub listen_id: i32,
+}
+impl<'a> Default for GetTLSDetailsArgs {
+  #[inline]
+  fn default() -> Self {
+    GetTLSDetailsArgs {
+      listen_id: 0,
+    }
+  }
+}
+
+pub struct GetTLSDetailsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GetTLSDetailsBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_listen_id(&mut self, listen_id: i32) {
+    self.fbb_.push_slot::<i32>(GetTLSDetails::VT_LISTEN_ID, listen_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GetTLSDetailsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    GetTLSDetailsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetTLSDetails<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GetTLSDetails<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GetTLSDetails");
+      ds.field("listen_id", &self.listen_id());
+      ds.finish()
+  }
+}
+pub enum TLSDetailsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TLSDetails<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TLSDetails<'a> {
+  type Inner = TLSDetails<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TLSDetails<'a> {
+  pub const VT_OCSP_STAPLED: flatbuffers::VOffsetT = 4;
+  pub const VT_OCSP_EXPIRES_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TLSDetails { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args TLSDetailsArgs
+  ) -> flatbuffers::WIPOffset<TLSDetails<'bldr>> {
+    let mut builder = TLSDetailsBuilder::new(_fbb);
+    builder.add_ocsp_expires_ms(args.ocsp_expires_ms);
+    builder.add_ocsp_stapled(args.ocsp_stapled);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn ocsp_stapled(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TLSDetails::VT_OCSP_STAPLED, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn ocsp_expires_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(TLSDetails::VT_OCSP_EXPIRES_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for TLSDetails<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("ocsp_stapled", Self::VT_OCSP_STAPLED, false)?
+     .visit_field::<u64>("ocsp_expires_ms", Self::VT_OCSP_EXPIRES_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TLSDetailsArgs {
+    pub ocsp_stapled: bool,
+    pub ocsp_expires_ms: u64,
+}
+impl<'a> Default for TLSDetailsArgs {
+  #[inline]
+  fn default() -> Self {
+    TLSDetailsArgs {
+      ocsp_stapled: false,
+      ocsp_expires_ms: 0,
+    }
+  }
+}
+
+pub struct TLSDetailsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TLSDetailsBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_ocsp_stapled(&mut self, ocsp_stapled: bool) {
+    self.fbb_.push_slot::<bool>(TLSDetails::VT_OCSP_STAPLED, ocsp_stapled, false);
+  }
+  #[inline]
+  pub fn add_ocsp_expires_ms(&mut self, ocsp_expires_ms: u64) {
+    self.fbb_.push_slot::<u64>(TLSDetails::VT_OCSP_EXPIRES_MS, ocsp_expires_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TLSDetailsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TLSDetailsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TLSDetails<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TLSDetails<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TLSDetails");
+      ds.field("ocsp_stapled", &self.ocsp_stapled());
+      ds.field("ocsp_expires_ms", &self.ocsp_expires_ms());
+      ds.finish()
+  }
+}
+pub enum RejectConnOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RejectConn<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RejectConn<'a> {
+  type Inner = RejectConn<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RejectConn<'a> {
+  pub const VT_CONN_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RejectConn { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RejectConnArgs
+  ) -> flatbuffers::WIPOffset<RejectConn<'bldr>> {
+    let mut builder = RejectConnBuilder::new(_fbb);
+    builder.add_conn_id(args.conn_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn conn_id(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(RejectConn::VT_CONN_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for RejectConn<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("conn_id", Self::VT_CONN_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RejectConnArgs {
+    pub conn_id: i32,
+}
+impl<'a> Default for RejectConnArgs {
+  #[inline]
+  fn default() -> Self {
+    RejectConnArgs {
+      conn_id: 0,
+    }
+  }
+}
+
+pub struct RejectConnBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RejectConnBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_conn_id(&mut self, conn_id: i32) {
+    self.fbb_.push_slot::<i32>(RejectConn::VT_CONN_ID, conn_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RejectConnBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RejectConnBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RejectConn<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RejectConn<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RejectConn");
+      ds.field("conn_id", &self.conn_id());
+      ds.finish()
+  }
+}
+pub enum SuspendBindingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SuspendBinding<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SuspendBinding<'a> {
+  type Inner = SuspendBinding<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SuspendBinding<'a> {
+  pub const VT_LISTEN_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SuspendBinding { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SuspendBindingArgs
+  ) -> flatbuffers::WIPOffset<SuspendBinding<'bldr>> {
+    let mut builder = SuspendBindingBuilder::new(_fbb);
+    builder.add_listen_id(args.listen_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn listen_id(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(SuspendBinding::VT_LISTEN_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SuspendBinding<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("listen_id", Self::VT_LISTEN_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SuspendBindingArgs {
+    pub listen_id: i32,
+}
+impl<'a> Default for SuspendBindingArgs {
+  #[inline]
+  fn default() -> Self {
+    SuspendBindingArgs {
+      listen_id: 0,
+    }
+  }
+}
+
+pub struct SuspendBindingBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SuspendBindingBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_listen_id(&mut self, listen_id: i32) {
+    self.fbb_.push_slot::<i32>(SuspendBinding::VT_LISTEN_ID, listen_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SuspendBindingBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SuspendBindingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SuspendBinding<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SuspendBinding<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SuspendBinding");
+      ds.field("listen_id", &self.listen_id());
+      ds.finish()
+  }
+}
+pub enum SuspendedBindingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SuspendedBinding<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SuspendedBinding<'a> {
+  type Inner = SuspendedBinding<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SuspendedBinding<'a> {
+  pub const VT_ERROR: flatbuffers::VOffsetT = 4;
+  pub const VT_STATE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SuspendedBinding { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SuspendedBindingArgs<'args>
+  ) -> flatbuffers::WIPOffset<SuspendedBinding<'bldr>> {
+    let mut builder = SuspendedBindingBuilder::new(_fbb);
+    if let Some(x) = args.state { builder.add_state(x); }
+    builder.add_error(args.error);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn error(&self) -> UnbindError {
     // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[8..].as_mut_ptr(),
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<UnbindError>(SuspendedBinding::VT_ERROR, Some(UnbindError::None)).unwrap()}
+  }
+  #[inline]
+  pub fn state(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(SuspendedBinding::VT_STATE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SuspendedBinding<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<UnbindError>("error", Self::VT_ERROR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("state", Self::VT_STATE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SuspendedBindingArgs<'a> {
+    pub error: UnbindError,
+    pub state: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for SuspendedBindingArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SuspendedBindingArgs {
+      error: UnbindError::None,
+      state: None,
     }
   }
+}
 
-  pub fn d(&self) -> u32 {
-    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    EndianScalar::from_little_endian(unsafe {
-      core::ptr::copy_nonoverlapping(
-        self.0[12..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
+pub struct SuspendedBindingBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SuspendedBindingBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_error(&mut self, error: UnbindError) {
+    self.fbb_.push_slot::<UnbindError>(SuspendedBinding::VT_ERROR, error, UnbindError::None);
+  }
+  #[inline]
+  pub fn add_state(&mut self, state: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SuspendedBinding::VT_STATE, state);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SuspendedBindingBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SuspendedBindingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SuspendedBinding<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
   }
+}
 
-  pub fn set_d(&mut self, x: u32) {
-    let x_le = x.to_little_endian();
+impl core::fmt::Debug for SuspendedBinding<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SuspendedBinding");
+      ds.field("error", &self.error());
+      ds.field("state", &self.state());
+      ds.finish()
+  }
+}
+pub enum ResumeBindingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ResumeBinding<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ResumeBinding<'a> {
+  type Inner = ResumeBinding<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ResumeBinding<'a> {
+  pub const VT_STATE: flatbuffers::VOffsetT = 4;
+  pub const VT_ACCEPT_SIZE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ResumeBinding { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ResumeBindingArgs<'args>
+  ) -> flatbuffers::WIPOffset<ResumeBinding<'bldr>> {
+    let mut builder = ResumeBindingBuilder::new(_fbb);
+    if let Some(x) = args.state { builder.add_state(x); }
+    builder.add_accept_size(args.accept_size);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn state(&self) -> Option<flatbuffers::Vector<'a, u8>> {
     // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[12..].as_mut_ptr(),
-        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
-      );
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ResumeBinding::VT_STATE, None)}
+  }
+  #[inline]
+  pub fn accept_size(&self) -> AcceptSize {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AcceptSize>(ResumeBinding::VT_ACCEPT_SIZE, Some(AcceptSize::Invalid)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ResumeBinding<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("state", Self::VT_STATE, false)?
+     .visit_field::<AcceptSize>("accept_size", Self::VT_ACCEPT_SIZE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResumeBindingArgs<'a> {
+    pub state: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub accept_size: AcceptSize,
+}
+impl<'a> Default for ResumeBindingArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResumeBindingArgs {
+      state: None,
+      accept_size: AcceptSize::Invalid,
     }
   }
+}
 
+pub struct ResumeBindingBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ResumeBindingBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_state(&mut self, state: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ResumeBinding::VT_STATE, state);
+  }
+  #[inline]
+  pub fn add_accept_size(&mut self, accept_size: AcceptSize) {
+    self.fbb_.push_slot::<AcceptSize>(ResumeBinding::VT_ACCEPT_SIZE, accept_size, AcceptSize::Invalid);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ResumeBindingBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ResumeBindingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ResumeBinding<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
 }
 
-// struct AcceptBasic, aligned to 4
-#[repr(transparent)]
-#[derive(Clone, Copy, PartialEq)]
-pub struct AcceptBasic(pub [u8; 28]);
-impl Default for AcceptBasic { 
-  fn default() -> Self { 
-    Self([0; 28])
+impl core::fmt::Debug for ResumeBinding<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ResumeBinding");
+      ds.field("state", &self.state());
+      ds.field("accept_size", &self.accept_size());
+      ds.finish()
   }
 }
-impl core::fmt::Debug for AcceptBasic {
-  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    f.debug_struct("AcceptBasic")
-      .field("error", &self.error())
-      .field("conn_id", &self.conn_id())
-      .field("addr", &self.addr())
-      .field("port", &self.port())
-      .finish()
+pub enum CloseConnOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CloseConn<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CloseConn<'a> {
+  type Inner = CloseConn<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CloseConn<'a> {
+  pub const VT_CONN_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CloseConn { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CloseConnArgs
+  ) -> flatbuffers::WIPOffset<CloseConn<'bldr>> {
+    let mut builder = CloseConnBuilder::new(_fbb);
+    builder.add_conn_id(args.conn_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn conn_id(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(CloseConn::VT_CONN_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for CloseConn<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("conn_id", Self::VT_CONN_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CloseConnArgs {
+    pub conn_id: i32,
+}
+impl<'a> Default for CloseConnArgs {
+  #[inline]
+  fn default() -> Self {
+    CloseConnArgs {
+      conn_id: 0,
+    }
+  }
+}
+
+pub struct CloseConnBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CloseConnBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_conn_id(&mut self, conn_id: i32) {
+    self.fbb_.push_slot::<i32>(CloseConn::VT_CONN_ID, conn_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CloseConnBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CloseConnBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CloseConn<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CloseConn<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CloseConn");
+      ds.field("conn_id", &self.conn_id());
+      ds.finish()
   }
 }
+pub enum ConnClosedOffset {}
+#[derive(Copy, Clone, PartialEq)]
 
-impl flatbuffers::SimpleToVerifyInSlice for AcceptBasic {}
-impl<'a> flatbuffers::Follow<'a> for AcceptBasic {
-  type Inner = &'a AcceptBasic;
+pub struct ConnClosed<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ConnClosed<'a> {
+  type Inner = ConnClosed<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    <&'a AcceptBasic>::follow(buf, loc)
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
-impl<'a> flatbuffers::Follow<'a> for &'a AcceptBasic {
-  type Inner = &'a AcceptBasic;
+
+impl<'a> ConnClosed<'a> {
+  pub const VT_ERROR: flatbuffers::VOffsetT = 4;
+
   #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    flatbuffers::follow_cast_ref::<AcceptBasic>(buf, loc)
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ConnClosed { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ConnClosedArgs
+  ) -> flatbuffers::WIPOffset<ConnClosed<'bldr>> {
+    let mut builder = ConnClosedBuilder::new(_fbb);
+    builder.add_error(args.error);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn error(&self) -> CloseError {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<CloseError>(ConnClosed::VT_ERROR, Some(CloseError::None)).unwrap()}
   }
-}
-impl<'b> flatbuffers::Push for AcceptBasic {
-    type Output = AcceptBasic;
-    #[inline]
-    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = ::core::slice::from_raw_parts(self as *const AcceptBasic as *const u8, Self::size());
-        dst.copy_from_slice(src);
-    }
 }
 
-impl<'a> flatbuffers::Verifiable for AcceptBasic {
+impl flatbuffers::Verifiable for ConnClosed<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.in_buffer::<Self>(pos)
+    v.visit_table(pos)?
+     .visit_field::<CloseError>("error", Self::VT_ERROR, false)?
+     .finish();
+    Ok(())
   }
 }
-
-impl<'a> AcceptBasic {
-  #[allow(clippy::too_many_arguments)]
-  pub fn new(
-    error: AcceptError,
-    conn_id: i32,
-    addr: &IPAddr,
-    port: u16,
-  ) -> Self {
-    let mut s = Self([0; 28]);
-    s.set_error(error);
-    s.set_conn_id(conn_id);
-    s.set_addr(addr);
-    s.set_port(port);
-    s
+pub struct ConnClosedArgs {
+    pub error: CloseError,
+}
+impl<'a> Default for ConnClosedArgs {
+  #[inline]
+  fn default() -> Self {
+    ConnClosedArgs {
+      error: CloseError::None,
+    }
   }
+}
 
-  pub fn error(&self) -> AcceptError {
-    let mut mem = core::mem::MaybeUninit::<<AcceptError as EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    EndianScalar::from_little_endian(unsafe {
-      core::ptr::copy_nonoverlapping(
-        self.0[0..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<AcceptError as EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
+pub struct ConnClosedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ConnClosedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_error(&mut self, error: CloseError) {
+    self.fbb_.push_slot::<CloseError>(ConnClosed::VT_ERROR, error, CloseError::None);
   }
-
-  pub fn set_error(&mut self, x: AcceptError) {
-    let x_le = x.to_little_endian();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[0..].as_mut_ptr(),
-        core::mem::size_of::<<AcceptError as EndianScalar>::Scalar>(),
-      );
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ConnClosedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ConnClosedBuilder {
+      fbb_: _fbb,
+      start_: start,
     }
   }
-
-  pub fn conn_id(&self) -> i32 {
-    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    EndianScalar::from_little_endian(unsafe {
-      core::ptr::copy_nonoverlapping(
-        self.0[4..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ConnClosed<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
   }
+}
 
-  pub fn set_conn_id(&mut self, x: i32) {
-    let x_le = x.to_little_endian();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[4..].as_mut_ptr(),
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
-      );
-    }
+impl core::fmt::Debug for ConnClosed<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ConnClosed");
+      ds.field("error", &self.error());
+      ds.finish()
   }
+}
+pub enum AbortConnOffset {}
+#[derive(Copy, Clone, PartialEq)]
 
-  pub fn addr(&self) -> &IPAddr {
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid struct in this slot
-    unsafe { &*(self.0[8..].as_ptr() as *const IPAddr) }
+pub struct AbortConn<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AbortConn<'a> {
+  type Inner = AbortConn<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
+}
 
-  #[allow(clippy::identity_op)]
-  pub fn set_addr(&mut self, x: &IPAddr) {
-    self.0[8..8 + 16].copy_from_slice(&x.0)
+impl<'a> AbortConn<'a> {
+  pub const VT_CONN_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AbortConn { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args AbortConnArgs
+  ) -> flatbuffers::WIPOffset<AbortConn<'bldr>> {
+    let mut builder = AbortConnBuilder::new(_fbb);
+    builder.add_conn_id(args.conn_id);
+    builder.finish()
   }
 
-  pub fn port(&self) -> u16 {
-    let mut mem = core::mem::MaybeUninit::<<u16 as EndianScalar>::Scalar>::uninit();
+
+  #[inline]
+  pub fn conn_id(&self) -> i32 {
     // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    EndianScalar::from_little_endian(unsafe {
-      core::ptr::copy_nonoverlapping(
-        self.0[24..].as_ptr(),
-        mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
-      );
-      mem.assume_init()
-    })
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(AbortConn::VT_CONN_ID, Some(0)).unwrap()}
   }
+}
 
-  pub fn set_port(&mut self, x: u16) {
-    let x_le = x.to_little_endian();
-    // Safety:
-    // Created from a valid Table for this object
-    // Which contains a valid value in this slot
-    unsafe {
-      core::ptr::copy_nonoverlapping(
-        &x_le as *const _ as *const u8,
-        self.0[24..].as_mut_ptr(),
-        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
-      );
+impl flatbuffers::Verifiable for AbortConn<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("conn_id", Self::VT_CONN_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AbortConnArgs {
+    pub conn_id: i32,
+}
+impl<'a> Default for AbortConnArgs {
+  #[inline]
+  fn default() -> Self {
+    AbortConnArgs {
+      conn_id: 0,
     }
   }
+}
 
+pub struct AbortConnBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AbortConnBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_conn_id(&mut self, conn_id: i32) {
+    self.fbb_.push_slot::<i32>(AbortConn::VT_CONN_ID, conn_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AbortConnBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AbortConnBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AbortConn<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
 }
 
-pub enum AcceptOffset {}
+impl core::fmt::Debug for AbortConn<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AbortConn");
+      ds.field("conn_id", &self.conn_id());
+      ds.finish()
+  }
+}
+pub enum ExportKeyingMaterialOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Accept<'a> {
+pub struct ExportKeyingMaterial<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Accept<'a> {
-  type Inner = Accept<'a>;
+impl<'a> flatbuffers::Follow<'a> for ExportKeyingMaterial<'a> {
+  type Inner = ExportKeyingMaterial<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> Accept<'a> {
-  pub const VT_BASIC: flatbuffers::VOffsetT = 4;
+impl<'a> ExportKeyingMaterial<'a> {
+  pub const VT_CONN_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_LABEL: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTEXT: flatbuffers::VOffsetT = 8;
+  pub const VT_LENGTH: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Accept { _tab: table }
+    ExportKeyingMaterial { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args AcceptArgs<'args>
-  ) -> flatbuffers::WIPOffset<Accept<'bldr>> {
-    let mut builder = AcceptBuilder::new(_fbb);
-    if let Some(x) = args.basic { builder.add_basic(x); }
+    args: &'args ExportKeyingMaterialArgs<'args>
+  ) -> flatbuffers::WIPOffset<ExportKeyingMaterial<'bldr>> {
+    let mut builder = ExportKeyingMaterialBuilder::new(_fbb);
+    builder.add_length(args.length);
+    if let Some(x) = args.context { builder.add_context(x); }
+    if let Some(x) = args.label { builder.add_label(x); }
+    builder.add_conn_id(args.conn_id);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn basic(&self) -> Option<&'a AcceptBasic> {
+  pub fn conn_id(&self) -> i32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<AcceptBasic>(Accept::VT_BASIC, None)}
+    unsafe { self._tab.get::<i32>(ExportKeyingMaterial::VT_CONN_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn label(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ExportKeyingMaterial::VT_LABEL, None)}
+  }
+  #[inline]
+  pub fn context(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ExportKeyingMaterial::VT_CONTEXT, None)}
+  }
+  #[inline]
+  pub fn length(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ExportKeyingMaterial::VT_LENGTH, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for Accept<'_> {
+impl flatbuffers::Verifiable for ExportKeyingMaterial<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<AcceptBasic>("basic", Self::VT_BASIC, false)?
+     .visit_field::<i32>("conn_id", Self::VT_CONN_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("label", Self::VT_LABEL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("context", Self::VT_CONTEXT, false)?
+     .visit_field::<u32>("length", Self::VT_LENGTH, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AcceptArgs<'a> {
-    pub basic: Option<&'a AcceptBasic>,
+pub struct ExportKeyingMaterialArgs<'a> {
+    pub conn_id: i32,
+    pub label: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub context: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub length: u32,
 }
-impl<'a> Default for AcceptArgs<'a> {
+impl<'a> Default for ExportKeyingMaterialArgs<'a> {
   #[inline]
-  fn default() -> Self {
-    AcceptArgs {
-      basic: None,
+  fn default() -> Self {
+    ExportKeyingMaterialArgs {
+      conn_id: 0,
+      label: None,
+      context: None,
+      length: 0,
     }
   }
 }
 
-pub struct AcceptBuilder<'a: 'b, 'b> {
+pub struct ExportKeyingMaterialBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> AcceptBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ExportKeyingMaterialBuilder<'a, 'b> {
   #[inline]
-  pub fn add_basic(&mut self, basic: &AcceptBasic) {
-    self.fbb_.push_slot_always::<&AcceptBasic>(Accept::VT_BASIC, basic);
+  pub fn add_conn_id(&mut self, conn_id: i32) {
+    self.fbb_.push_slot::<i32>(ExportKeyingMaterial::VT_CONN_ID, conn_id, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AcceptBuilder<'a, 'b> {
+  pub fn add_label(&mut self, label: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExportKeyingMaterial::VT_LABEL, label);
+  }
+  #[inline]
+  pub fn add_context(&mut self, context: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExportKeyingMaterial::VT_CONTEXT, context);
+  }
+  #[inline]
+  pub fn add_length(&mut self, length: u32) {
+    self.fbb_.push_slot::<u32>(ExportKeyingMaterial::VT_LENGTH, length, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ExportKeyingMaterialBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    AcceptBuilder {
+    ExportKeyingMaterialBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Accept<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ExportKeyingMaterial<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Accept<'_> {
+impl core::fmt::Debug for ExportKeyingMaterial<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Accept");
-      ds.field("basic", &self.basic());
+    let mut ds = f.debug_struct("ExportKeyingMaterial");
+      ds.field("conn_id", &self.conn_id());
+      ds.field("label", &self.label());
+      ds.field("context", &self.context());
+      ds.field("length", &self.length());
       ds.finish()
   }
 }
-pub enum BindTLSOffset {}
+pub enum KeyingMaterialOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct BindTLS<'a> {
+pub struct KeyingMaterial<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for BindTLS<'a> {
-  type Inner = BindTLS<'a>;
+impl<'a> flatbuffers::Follow<'a> for KeyingMaterial<'a> {
+  type Inner = KeyingMaterial<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> BindTLS<'a> {
-  pub const VT_ACCEPT_SIZE: flatbuffers::VOffsetT = 4;
-  pub const VT_NAME: flatbuffers::VOffsetT = 6;
-  pub const VT_PORT: flatbuffers::VOffsetT = 8;
+impl<'a> KeyingMaterial<'a> {
+  pub const VT_ERROR: flatbuffers::VOffsetT = 4;
+  pub const VT_MATERIAL: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    BindTLS { _tab: table }
+    KeyingMaterial { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args BindTLSArgs<'args>
-  ) -> flatbuffers::WIPOffset<BindTLS<'bldr>> {
-    let mut builder = BindTLSBuilder::new(_fbb);
-    if let Some(x) = args.name { builder.add_name(x); }
-    builder.add_port(args.port);
-    builder.add_accept_size(args.accept_size);
+    args: &'args KeyingMaterialArgs<'args>
+  ) -> flatbuffers::WIPOffset<KeyingMaterial<'bldr>> {
+    let mut builder = KeyingMaterialBuilder::new(_fbb);
+    if let Some(x) = args.material { builder.add_material(x); }
+    builder.add_error(args.error);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn accept_size(&self) -> AcceptSize {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<AcceptSize>(BindTLS::VT_ACCEPT_SIZE, Some(AcceptSize::Invalid)).unwrap()}
-  }
-  #[inline]
-  pub fn name(&self) -> Option<&'a str> {
+  pub fn error(&self) -> ExportError {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BindTLS::VT_NAME, None)}
+    unsafe { self._tab.get::<ExportError>(KeyingMaterial::VT_ERROR, Some(ExportError::None)).unwrap()}
   }
   #[inline]
-  pub fn port(&self) -> u16 {
+  pub fn material(&self) -> Option<flatbuffers::Vector<'a, u8>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u16>(BindTLS::VT_PORT, Some(0)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(KeyingMaterial::VT_MATERIAL, None)}
   }
 }
 
-impl flatbuffers::Verifiable for BindTLS<'_> {
+impl flatbuffers::Verifiable for KeyingMaterial<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<AcceptSize>("accept_size", Self::VT_ACCEPT_SIZE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-     .visit_field::<u16>("port", Self::VT_PORT, false)?
+     .visit_field::<ExportError>("error", Self::VT_ERROR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("material", Self::VT_MATERIAL, false)?
      .finish();
     Ok(())
   }
 }
-pub struct BindTLSArgs<'a> {
-    pub accept_size: AcceptSize,
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub port: u16,
+pub struct KeyingMaterialArgs<'a> {
+    pub error: ExportError,
+    pub material: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
 }
-impl<'a> Default for BindTLSArgs<'a> {
+impl<'a> Default for KeyingMaterialArgs<'a> {
   #[inline]
   fn default() -> Self {
-    BindTLSArgs {
-      accept_size: AcceptSize::Invalid,
-      name: None,
-      port: 0,
+    KeyingMaterialArgs {
+      error: ExportError::None,
+      material: None,
     }
   }
 }
 
-pub struct BindTLSBuilder<'a: 'b, 'b> {
+pub struct KeyingMaterialBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> BindTLSBuilder<'a, 'b> {
-  #[inline]
-  pub fn add_accept_size(&mut self, accept_size: AcceptSize) {
-    self.fbb_.push_slot::<AcceptSize>(BindTLS::VT_ACCEPT_SIZE, accept_size, AcceptSize::Invalid);
-  }
+impl<'a: 'b, 'b> KeyingMaterialBuilder<'a, 'b> {
   #[inline]
-  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BindTLS::VT_NAME, name);
+  pub fn add_error(&mut self, error: ExportError) {
+    self.fbb_.push_slot::<ExportError>(KeyingMaterial::VT_ERROR, error, ExportError::None);
   }
   #[inline]
-  pub fn add_port(&mut self, port: u16) {
-    self.fbb_.push_slot::<u16>(BindTLS::VT_PORT, port, 0);
+  pub fn add_material(&mut self, material: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(KeyingMaterial::VT_MATERIAL, material);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindTLSBuilder<'a, 'b> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> KeyingMaterialBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    BindTLSBuilder {
+    KeyingMaterialBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<BindTLS<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<KeyingMaterial<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for BindTLS<'_> {
+impl core::fmt::Debug for KeyingMaterial<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("BindTLS");
-      ds.field("accept_size", &self.accept_size());
-      ds.field("name", &self.name());
-      ds.field("port", &self.port());
+    let mut ds = f.debug_struct("KeyingMaterial");
+      ds.field("error", &self.error());
+      ds.field("material", &self.material());
       ds.finish()
   }
 }
-pub enum BindingOffset {}
+pub enum CallOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Binding<'a> {
+pub struct Call<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Binding<'a> {
-  type Inner = Binding<'a>;
+impl<'a> flatbuffers::Follow<'a> for Call<'a> {
+  type Inner = Call<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> Binding<'a> {
-  pub const VT_ERROR: flatbuffers::VOffsetT = 4;
-  pub const VT_LISTEN_ID: flatbuffers::VOffsetT = 6;
-  pub const VT_HOST: flatbuffers::VOffsetT = 8;
-  pub const VT_PORT: flatbuffers::VOffsetT = 10;
+impl<'a> Call<'a> {
+  pub const VT_FUNCTION_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_FUNCTION: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Binding { _tab: table }
+    Call { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args BindingArgs<'args>
-  ) -> flatbuffers::WIPOffset<Binding<'bldr>> {
-    let mut builder = BindingBuilder::new(_fbb);
-    if let Some(x) = args.host { builder.add_host(x); }
-    builder.add_listen_id(args.listen_id);
-    builder.add_port(args.port);
-    builder.add_error(args.error);
+    args: &'args CallArgs
+  ) -> flatbuffers::WIPOffset<Call<'bldr>> {
+    let mut builder = CallBuilder::new(_fbb);
+    if let Some(x) = args.function { builder.add_function(x); }
+    builder.add_function_type(args.function_type);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn error(&self) -> BindError {
+  pub fn function_type(&self) -> Function {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<BindError>(Binding::VT_ERROR, Some(BindError::None)).unwrap()}
+    unsafe { self._tab.get::<Function>(Call::VT_FUNCTION_TYPE, Some(Function::NONE)).unwrap()}
   }
   #[inline]
-  pub fn listen_id(&self) -> i32 {
+  pub fn function(&self) -> Option<flatbuffers::Table<'a>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<i32>(Binding::VT_LISTEN_ID, Some(0)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Call::VT_FUNCTION, None)}
   }
   #[inline]
-  pub fn host(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Binding::VT_HOST, None)}
+  #[allow(non_snake_case)]
+  pub fn function_as_bind_tls(&self) -> Option<BindTLS<'a>> {
+    if self.function_type() == Function::BindTLS {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { BindTLS::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn port(&self) -> u16 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u16>(Binding::VT_PORT, Some(0)).unwrap()}
+  #[allow(non_snake_case)]
+  pub fn function_as_bind_tcp(&self) -> Option<BindTCP<'a>> {
+    if self.function_type() == Function::BindTCP {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { BindTCP::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
-}
 
-impl flatbuffers::Verifiable for Binding<'_> {
   #[inline]
-  fn run_verifier(
-    v: &mut flatbuffers::Verifier, pos: usize
-  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-    use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .visit_field::<BindError>("error", Self::VT_ERROR, false)?
-     .visit_field::<i32>("listen_id", Self::VT_LISTEN_ID, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("host", Self::VT_HOST, false)?
-     .visit_field::<u16>("port", Self::VT_PORT, false)?
-     .finish();
-    Ok(())
+  #[allow(non_snake_case)]
+  pub fn function_as_bind_udp(&self) -> Option<BindUDP<'a>> {
+    if self.function_type() == Function::BindUDP {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { BindUDP::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
-}
-pub struct BindingArgs<'a> {
-    pub error: BindError,
-    pub listen_id: i32,
-    pub host: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub port: u16,
-}
-impl<'a> Default for BindingArgs<'a> {
+
   #[inline]
-  fn default() -> Self {
-    BindingArgs {
-      error: BindError::None,
-      listen_id: 0,
-      host: None,
-      port: 0,
+  #[allow(non_snake_case)]
+  pub fn function_as_bind_quic(&self) -> Option<BindQUIC<'a>> {
+    if self.function_type() == Function::BindQUIC {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { BindQUIC::init_from_table(t) }
+     })
+    } else {
+      None
     }
   }
-}
 
-pub struct BindingBuilder<'a: 'b, 'b> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b> BindingBuilder<'a, 'b> {
   #[inline]
-  pub fn add_error(&mut self, error: BindError) {
-    self.fbb_.push_slot::<BindError>(Binding::VT_ERROR, error, BindError::None);
+  #[allow(non_snake_case)]
+  pub fn function_as_open_quic_stream(&self) -> Option<OpenQUICStream<'a>> {
+    if self.function_type() == Function::OpenQUICStream {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { OpenQUICStream::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_listen_id(&mut self, listen_id: i32) {
-    self.fbb_.push_slot::<i32>(Binding::VT_LISTEN_ID, listen_id, 0);
+  #[allow(non_snake_case)]
+  pub fn function_as_bind_dtls(&self) -> Option<BindDTLS<'a>> {
+    if self.function_type() == Function::BindDTLS {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { BindDTLS::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn function_as_bind_tls_many(&self) -> Option<BindTLSMany<'a>> {
+    if self.function_type() == Function::BindTLSMany {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { BindTLSMany::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn function_as_get_quota(&self) -> Option<GetQuota<'a>> {
+    if self.function_type() == Function::GetQuota {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetQuota::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn function_as_list_bindings(&self) -> Option<ListBindings<'a>> {
+    if self.function_type() == Function::ListBindings {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ListBindings::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_host(&mut self, host: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Binding::VT_HOST, host);
+  #[allow(non_snake_case)]
+  pub fn function_as_unbind(&self) -> Option<Unbind<'a>> {
+    if self.function_type() == Function::Unbind {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Unbind::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn add_port(&mut self, port: u16) {
-    self.fbb_.push_slot::<u16>(Binding::VT_PORT, port, 0);
+  #[allow(non_snake_case)]
+  pub fn function_as_rename_binding(&self) -> Option<RenameBinding<'a>> {
+    if self.function_type() == Function::RenameBinding {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { RenameBinding::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BindingBuilder<'a, 'b> {
-    let start = _fbb.start_table();
-    BindingBuilder {
-      fbb_: _fbb,
-      start_: start,
+  #[allow(non_snake_case)]
+  pub fn function_as_renew_binding(&self) -> Option<RenewBinding<'a>> {
+    if self.function_type() == Function::RenewBinding {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { RenewBinding::init_from_table(t) }
+     })
+    } else {
+      None
     }
   }
+
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Binding<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
+  #[allow(non_snake_case)]
+  pub fn function_as_get_tls_details(&self) -> Option<GetTLSDetails<'a>> {
+    if self.function_type() == Function::GetTLSDetails {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { GetTLSDetails::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
-}
 
-impl core::fmt::Debug for Binding<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Binding");
-      ds.field("error", &self.error());
-      ds.field("listen_id", &self.listen_id());
-      ds.field("host", &self.host());
-      ds.field("port", &self.port());
-      ds.finish()
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn function_as_bind_tls_passthrough(&self) -> Option<BindTLSPassthrough<'a>> {
+    if self.function_type() == Function::BindTLSPassthrough {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { BindTLSPassthrough::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
-}
-pub enum CallOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct Call<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
 
-impl<'a> flatbuffers::Follow<'a> for Call<'a> {
-  type Inner = Call<'a>;
   #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  #[allow(non_snake_case)]
+  pub fn function_as_reject_conn(&self) -> Option<RejectConn<'a>> {
+    if self.function_type() == Function::RejectConn {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { RejectConn::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
-}
-
-impl<'a> Call<'a> {
-  pub const VT_FUNCTION_TYPE: flatbuffers::VOffsetT = 4;
-  pub const VT_FUNCTION: flatbuffers::VOffsetT = 6;
 
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Call { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args CallArgs
-  ) -> flatbuffers::WIPOffset<Call<'bldr>> {
-    let mut builder = CallBuilder::new(_fbb);
-    if let Some(x) = args.function { builder.add_function(x); }
-    builder.add_function_type(args.function_type);
-    builder.finish()
+  #[allow(non_snake_case)]
+  pub fn function_as_suspend_binding(&self) -> Option<SuspendBinding<'a>> {
+    if self.function_type() == Function::SuspendBinding {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SuspendBinding::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
 
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn function_as_resume_binding(&self) -> Option<ResumeBinding<'a>> {
+    if self.function_type() == Function::ResumeBinding {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ResumeBinding::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
 
   #[inline]
-  pub fn function_type(&self) -> Function {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<Function>(Call::VT_FUNCTION_TYPE, Some(Function::NONE)).unwrap()}
+  #[allow(non_snake_case)]
+  pub fn function_as_close_conn(&self) -> Option<CloseConn<'a>> {
+    if self.function_type() == Function::CloseConn {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { CloseConn::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
-  pub fn function(&self) -> Option<flatbuffers::Table<'a>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Call::VT_FUNCTION, None)}
+  #[allow(non_snake_case)]
+  pub fn function_as_abort_conn(&self) -> Option<AbortConn<'a>> {
+    if self.function_type() == Function::AbortConn {
+      self.function().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AbortConn::init_from_table(t) }
+     })
+    } else {
+      None
+    }
   }
+
   #[inline]
   #[allow(non_snake_case)]
-  pub fn function_as_bind_tls(&self) -> Option<BindTLS<'a>> {
-    if self.function_type() == Function::BindTLS {
+  pub fn function_as_export_keying_material(&self) -> Option<ExportKeyingMaterial<'a>> {
+    if self.function_type() == Function::ExportKeyingMaterial {
       self.function().map(|t| {
        // Safety:
        // Created from a valid Table for this object
        // Which contains a valid union in this slot
-       unsafe { BindTLS::init_from_table(t) }
+       unsafe { ExportKeyingMaterial::init_from_table(t) }
      })
     } else {
       None
@@ -1178,6 +6939,25 @@ impl flatbuffers::Verifiable for Call<'_> {
      .visit_union::<Function, _>("function_type", Self::VT_FUNCTION_TYPE, "function", Self::VT_FUNCTION, false, |key, v, pos| {
         match key {
           Function::BindTLS => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BindTLS>>("Function::BindTLS", pos),
+          Function::BindTCP => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BindTCP>>("Function::BindTCP", pos),
+          Function::BindUDP => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BindUDP>>("Function::BindUDP", pos),
+          Function::BindQUIC => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BindQUIC>>("Function::BindQUIC", pos),
+          Function::OpenQUICStream => v.verify_union_variant::<flatbuffers::ForwardsUOffset<OpenQUICStream>>("Function::OpenQUICStream", pos),
+          Function::BindDTLS => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BindDTLS>>("Function::BindDTLS", pos),
+          Function::BindTLSMany => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BindTLSMany>>("Function::BindTLSMany", pos),
+          Function::GetQuota => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetQuota>>("Function::GetQuota", pos),
+          Function::ListBindings => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ListBindings>>("Function::ListBindings", pos),
+          Function::Unbind => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Unbind>>("Function::Unbind", pos),
+          Function::RenameBinding => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RenameBinding>>("Function::RenameBinding", pos),
+          Function::RenewBinding => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RenewBinding>>("Function::RenewBinding", pos),
+          Function::GetTLSDetails => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetTLSDetails>>("Function::GetTLSDetails", pos),
+          Function::BindTLSPassthrough => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BindTLSPassthrough>>("Function::BindTLSPassthrough", pos),
+          Function::RejectConn => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RejectConn>>("Function::RejectConn", pos),
+          Function::SuspendBinding => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SuspendBinding>>("Function::SuspendBinding", pos),
+          Function::ResumeBinding => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ResumeBinding>>("Function::ResumeBinding", pos),
+          Function::CloseConn => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CloseConn>>("Function::CloseConn", pos),
+          Function::AbortConn => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AbortConn>>("Function::AbortConn", pos),
+          Function::ExportKeyingMaterial => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ExportKeyingMaterial>>("Function::ExportKeyingMaterial", pos),
           _ => Ok(()),
         }
      })?
@@ -1239,6 +7019,139 @@ impl core::fmt::Debug for Call<'_> {
             ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        Function::BindTCP => {
+          if let Some(x) = self.function_as_bind_tcp() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::BindUDP => {
+          if let Some(x) = self.function_as_bind_udp() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::BindQUIC => {
+          if let Some(x) = self.function_as_bind_quic() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::OpenQUICStream => {
+          if let Some(x) = self.function_as_open_quic_stream() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::BindDTLS => {
+          if let Some(x) = self.function_as_bind_dtls() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::BindTLSMany => {
+          if let Some(x) = self.function_as_bind_tls_many() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::GetQuota => {
+          if let Some(x) = self.function_as_get_quota() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::ListBindings => {
+          if let Some(x) = self.function_as_list_bindings() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::Unbind => {
+          if let Some(x) = self.function_as_unbind() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::RenameBinding => {
+          if let Some(x) = self.function_as_rename_binding() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::RenewBinding => {
+          if let Some(x) = self.function_as_renew_binding() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::GetTLSDetails => {
+          if let Some(x) = self.function_as_get_tls_details() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::BindTLSPassthrough => {
+          if let Some(x) = self.function_as_bind_tls_passthrough() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::RejectConn => {
+          if let Some(x) = self.function_as_reject_conn() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::SuspendBinding => {
+          if let Some(x) = self.function_as_suspend_binding() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::ResumeBinding => {
+          if let Some(x) = self.function_as_resume_binding() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::CloseConn => {
+          if let Some(x) = self.function_as_close_conn() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::AbortConn => {
+          if let Some(x) = self.function_as_abort_conn() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Function::ExportKeyingMaterial => {
+          if let Some(x) = self.function_as_export_keying_material() {
+            ds.field("function", &x)
+          } else {
+            ds.field("function", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("function", &x)
@@ -0,0 +1,153 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{AcceptError, AcceptErrorKind, Acceptor, Binding, Conn};
+use std::cmp::Reverse;
+use std::future::poll_fn;
+use std::task::Poll;
+
+struct Entry {
+    acceptor: Acceptor,
+    priority: u32,
+    weight: i64,
+    credit: i64,
+}
+
+/// Accepts connections from several acceptors.
+pub struct MultiAcceptor {
+    entries: Vec<Entry>,
+}
+
+impl MultiAcceptor {
+    /// Combine acceptors.  More can be added later with `push`.
+    pub fn new(acceptors: Vec<Acceptor>) -> Self {
+        let mut multi = Self {
+            entries: Vec::new(),
+        };
+        for acceptor in acceptors {
+            multi.push(acceptor);
+        }
+        multi
+    }
+
+    /// Add an acceptor with weight 1 and priority 0.
+    pub fn push(&mut self, acceptor: Acceptor) {
+        self.push_prioritized(acceptor, 0, 1);
+    }
+
+    /// Add an acceptor with priority 0.  When several acceptors of the same
+    /// priority have pending connections, they are served in proportion to
+    /// their weights.  Zero weight is treated as 1.
+    pub fn push_weighted(&mut self, acceptor: Acceptor, weight: u32) {
+        self.push_prioritized(acceptor, 0, weight);
+    }
+
+    /// Add an acceptor with a priority and a weight.  Acceptors with higher
+    /// priority are always served first: an acceptor is polled only if all
+    /// acceptors with higher priority have no pending connections.
+    pub fn push_prioritized(&mut self, acceptor: Acceptor, priority: u32, weight: u32) {
+        self.entries.push(Entry {
+            acceptor,
+            priority,
+            weight: weight.max(1).into(),
+            credit: 0,
+        });
+    }
+
+    /// Addresses of the acceptors which haven't been closed.
+    pub fn bindings(&self) -> impl Iterator<Item = &Binding> {
+        self.entries.iter().map(|e| &e.acceptor.addr)
+    }
+
+    /// Remove the acceptor of the listener with the id.
+    pub fn remove(&mut self, listen_id: i32) -> Option<Acceptor> {
+        let i = self
+            .entries
+            .iter()
+            .position(|e| e.acceptor.listen_id == listen_id)?;
+        Some(self.entries.remove(i).acceptor)
+    }
+
+    /// Accept a client connection from whichever acceptor receives one first.
+    /// The address of the originating acceptor is returned with the
+    /// connection.  Closed acceptors are removed; an `AcceptErrorKind::Closed`
+    /// error is returned when none remain.  Acceptors are polled in order of
+    /// priority, and by smooth weighted round-robin within a priority, so a
+    /// busy one doesn't starve the others of the same priority.
+    pub async fn accept(&mut self) -> Result<(Conn, &Binding), AcceptError> {
+        let (conn, i) = poll_fn(|cx| {
+            let mut order: Vec<usize> = (0..self.entries.len()).collect();
+            order.sort_by_key(|&i| {
+                let e = &self.entries[i];
+                (Reverse(e.priority), Reverse(e.credit + e.weight))
+            });
+
+            let mut closed = Vec::new();
+            let mut ready = None;
+
+            for i in order {
+                match self.entries[i].acceptor.poll_accept(cx) {
+                    Poll::Pending => {}
+
+                    Poll::Ready(Err(e)) if e.kind() == AcceptErrorKind::Closed => {
+                        closed.push(i);
+                    }
+
+                    Poll::Ready(result) => {
+                        ready = Some((result, i));
+                        break;
+                    }
+                }
+            }
+
+            if let Some((_, i)) = ready {
+                self.select(i);
+            }
+
+            closed.sort_unstable();
+            for &i in closed.iter().rev() {
+                self.entries.remove(i);
+            }
+
+            match ready {
+                Some((result, i)) => {
+                    // Account for the removed entries which preceded it.
+                    let i = i - closed.iter().filter(|&&c| c < i).count();
+                    Poll::Ready(result.map(|conn| (conn, i)))
+                }
+
+                None if self.entries.is_empty() => Poll::Ready(Err(AcceptError::listener_closed())),
+
+                None => Poll::Pending,
+            }
+        })
+        .await?;
+
+        Ok((conn, &self.entries[i].acceptor.addr))
+    }
+
+    /// Smooth weighted round-robin bookkeeping after the acceptor at index
+    /// has been selected.  Credit is bounded so that an idle acceptor can't
+    /// save up for a burst.
+    fn select(&mut self, index: usize) {
+        let priority = self.entries[index].priority;
+
+        let total: i64 = self
+            .entries
+            .iter()
+            .filter(|e| e.priority == priority)
+            .map(|e| e.weight)
+            .sum();
+
+        for e in self.entries.iter_mut().filter(|e| e.priority == priority) {
+            e.credit += e.weight;
+        }
+
+        self.entries[index].credit -= total;
+
+        for e in self.entries.iter_mut().filter(|e| e.priority == priority) {
+            e.credit = e.credit.clamp(-total, total);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{Acceptor, Verdict};
+use std::cell::RefCell;
+use std::fmt;
+use std::net::IpAddr;
+use std::rc::Rc;
+
+/// CIDR notation parsing error.
+#[derive(Debug, Eq, PartialEq)]
+pub enum CidrError {
+    /// The address part is not a valid IPv4 or IPv6 address.
+    InvalidAddr,
+
+    /// The prefix length is not a number, or is too large for the address
+    /// family.
+    InvalidLength,
+}
+
+impl fmt::Display for CidrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            CidrError::InvalidAddr => f.write_str("invalid address in CIDR"),
+            CidrError::InvalidLength => f.write_str("invalid prefix length in CIDR"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Cidr {
+    addr: IpAddr,
+    len: u8,
+}
+
+impl Cidr {
+    /// Parse `addr/len` or a plain address.
+    fn parse(s: &str) -> Result<Self, CidrError> {
+        let (addr, len) = match s.split_once('/') {
+            Some((addr, len)) => (addr, Some(len)),
+            None => (s, None),
+        };
+
+        let addr: IpAddr = addr.parse().map_err(|_| CidrError::InvalidAddr)?;
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+
+        let len = match len {
+            Some(len) => len.parse().map_err(|_| CidrError::InvalidLength)?,
+            None => max,
+        };
+        if len > max {
+            return Err(CidrError::InvalidLength);
+        }
+
+        Ok(Self { addr, len })
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip.to_canonical()) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.len as u32).unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            }
+
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.len as u32).unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            }
+
+            _ => false,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Lists {
+    allow: Vec<Cidr>,
+    deny: Vec<Cidr>,
+}
+
+/// Client address allow and deny lists.  A client is rejected if its address
+/// matches the deny list, or if the allow list is non-empty and the address
+/// doesn't match it.  Clones share the lists, so changes apply to acceptors
+/// which are using the policy.
+#[derive(Clone, Default)]
+pub struct IpPolicy {
+    lists: Rc<RefCell<Lists>>,
+}
+
+impl IpPolicy {
+    /// Empty lists: every address is allowed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an address range (e.g. `"192.0.2.0/24"`) or a single address to
+    /// the allow list.
+    pub fn allow(&self, cidr: &str) -> Result<(), CidrError> {
+        let cidr = Cidr::parse(cidr)?;
+        self.lists.borrow_mut().allow.push(cidr);
+        Ok(())
+    }
+
+    /// Add an address range (e.g. `"203.0.113.0/24"`) or a single address to
+    /// the deny list.
+    pub fn deny(&self, cidr: &str) -> Result<(), CidrError> {
+        let cidr = Cidr::parse(cidr)?;
+        self.lists.borrow_mut().deny.push(cidr);
+        Ok(())
+    }
+
+    /// Remove an address range from both lists.  It must be specified in the
+    /// same way as when it was added.
+    pub fn remove(&self, cidr: &str) -> Result<(), CidrError> {
+        let cidr = Cidr::parse(cidr)?;
+        let mut lists = self.lists.borrow_mut();
+        lists.allow.retain(|c| *c != cidr);
+        lists.deny.retain(|c| *c != cidr);
+        Ok(())
+    }
+
+    /// Empty both lists.
+    pub fn clear(&self) {
+        *self.lists.borrow_mut() = Lists::default();
+    }
+
+    /// Decide whether a client address is allowed.
+    pub fn check(&self, ip: IpAddr) -> Verdict {
+        let lists = self.lists.borrow();
+
+        if lists.deny.iter().any(|c| c.contains(ip)) {
+            return Verdict::Reject;
+        }
+
+        if lists.allow.is_empty() || lists.allow.iter().any(|c| c.contains(ip)) {
+            Verdict::Accept
+        } else {
+            Verdict::Reject
+        }
+    }
+}
+
+impl Acceptor {
+    /// Install a client address policy, or remove it.  Rejected connections
+    /// are closed without opening streams for them (see `set_filter`).
+    pub fn set_ip_policy(&mut self, policy: Option<IpPolicy>) {
+        self.ip_policy = policy;
+    }
+}
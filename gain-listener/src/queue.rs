@@ -0,0 +1,147 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::drive::{self, Driven};
+use crate::{AcceptError, AcceptErrorKind, Acceptor, Conn};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::poll_fn;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+
+struct Shared {
+    queue: VecDeque<Result<Conn, AcceptError>>,
+    capacity: usize,
+    consumer: Option<Waker>,
+    closed: bool,
+    dropped: bool,
+}
+
+/// Connections accepted in advance.  When the queue is full, accepting stops,
+/// so that pending connections are held by the service (subject to
+/// `BindOptions::backlog`).
+///
+/// The acceptor is polled by `recv`, and also when the runtime wakes it, so
+/// that connections are accepted while the queue isn't being received from.
+/// No tasks are spawned or woken while a task is running, so the queue can be
+/// used in a spawned task.
+pub struct AcceptQueue {
+    shared: Rc<RefCell<Shared>>,
+    fill: Driven,
+}
+
+impl AcceptQueue {
+    /// Wait for the next connection.  Errors are delivered in order with the
+    /// connections.  An `AcceptErrorKind::Closed` error is returned after the
+    /// acceptor has been closed and the queued connections have been
+    /// received.
+    pub async fn recv(&mut self) -> Result<Conn, AcceptError> {
+        poll_fn(|cx| {
+            self.fill.poll();
+
+            let mut shared = self.shared.borrow_mut();
+
+            if let Some(result) = shared.queue.pop_front() {
+                drop(shared);
+                // There is room for another connection.
+                self.fill.poll();
+                Poll::Ready(result)
+            } else if shared.closed {
+                Poll::Ready(Err(AcceptError::listener_closed()))
+            } else {
+                shared.consumer = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Number of queued connections (and errors).
+    pub fn len(&self) -> usize {
+        self.shared.borrow().queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum number of queued connections.
+    pub fn capacity(&self) -> usize {
+        self.shared.borrow().capacity
+    }
+}
+
+impl Drop for AcceptQueue {
+    fn drop(&mut self) {
+        self.shared.borrow_mut().dropped = true;
+        self.fill.poll();
+    }
+}
+
+impl Acceptor {
+    /// Accept connections in advance until the acceptor is closed or the
+    /// queue is dropped.  At most `capacity` connections (at least 1) are held
+    /// in the queue.
+    pub fn into_queue(self, capacity: usize) -> AcceptQueue {
+        let shared = Rc::new(RefCell::new(Shared {
+            queue: VecDeque::new(),
+            capacity: capacity.max(1),
+            consumer: None,
+            closed: false,
+            dropped: false,
+        }));
+
+        // The consumer is woken when the runtime wakes the acceptor, outside
+        // of any task.
+        let weak = Rc::downgrade(&shared);
+        let notify = move || {
+            if let Some(shared) = weak.upgrade() {
+                let consumer = shared.borrow_mut().consumer.take();
+                if let Some(waker) = consumer {
+                    waker.wake();
+                }
+            }
+        };
+
+        let fill = drive::start(fill(self, shared.clone()), Some(Rc::new(notify)));
+
+        AcceptQueue { shared, fill }
+    }
+}
+
+async fn fill(mut acceptor: Acceptor, shared: Rc<RefCell<Shared>>) {
+    loop {
+        // The consumer polls this again after receiving from a full queue.
+        let result = poll_fn(|cx| {
+            let s = shared.borrow();
+
+            if s.dropped {
+                return Poll::Ready(None);
+            }
+            if s.queue.len() >= s.capacity {
+                return Poll::Pending;
+            }
+
+            drop(s);
+            acceptor.poll_accept(cx).map(Some)
+        })
+        .await;
+
+        let result = match result {
+            Some(x) => x,
+            None => return,
+        };
+
+        let mut s = shared.borrow_mut();
+
+        match result {
+            Err(e) if e.kind() == AcceptErrorKind::Closed => s.closed = true,
+            result => s.queue.push_back(result),
+        }
+
+        if s.closed {
+            return;
+        }
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{
+    accept, bind, flat, recv_frame, AcceptError, BindError, BindOptions, Binding, FrameError,
+    Protocol, SERVICE,
+};
+use flatbuffers::{root, size_prefixed_root, FlatBufferBuilder};
+use gain::stream::{Close, CloseStream, RecvOnlyStream, RecvStream, RecvWriteStream, WriteStream};
+use std::collections::VecDeque;
+use std::net::SocketAddr;
+
+/// QUIC connection listener.
+pub struct QuicListener {
+    stream: RecvStream,
+    accept_size: flat::AcceptSize,
+    pub addr: Binding,
+}
+
+impl QuicListener {
+    /// Listen to QUIC connections at `BindOptions::port`.  The fully-qualified
+    /// DNS name can be discovered from the `QuicListener::addr.hostname`
+    /// field.
+    ///
+    /// `BindOptions::prefix` has the same semantics as with
+    /// `Listener::bind_tls`.
+    pub async fn bind_quic(opt: BindOptions<'_>) -> Result<Self, BindError> {
+        let (listen_id, accept_size, addr, _) = bind(opt, Protocol::Quic, None).await?;
+
+        Ok(Self {
+            stream: SERVICE.input_stream(listen_id),
+            accept_size,
+            addr,
+        })
+    }
+
+    /// Accept a client connection.  An `AcceptErrorKind::Closed` error may
+    /// occur due to environmental causes.
+    pub async fn accept(&mut self) -> Result<QuicConn, AcceptError> {
+        let conn = accept(&mut self.stream, self.accept_size, None).await?;
+
+        Ok(QuicConn {
+            id: conn.id,
+            control: conn.stream,
+            pending_bi: VecDeque::new(),
+            pending_uni: VecDeque::new(),
+            peer_addr: conn.peer_addr,
+        })
+    }
+
+    /// Detach the closing functionality.  When the `CloseStream` is closed or
+    /// dropped, `accept` will return an `AcceptErrorKind::Closed` error.
+    pub fn split(self) -> (QuicAcceptor, CloseStream) {
+        let (stream, c) = self.stream.split();
+        (
+            QuicAcceptor {
+                stream,
+                accept_size: self.accept_size,
+                addr: self.addr,
+            },
+            c,
+        )
+    }
+}
+
+/// QUIC connection acceptor.
+pub struct QuicAcceptor {
+    stream: RecvOnlyStream,
+    accept_size: flat::AcceptSize,
+    pub addr: Binding,
+}
+
+impl QuicAcceptor {
+    /// Accept a client connection.  An `AcceptErrorKind::Closed` error may be
+    /// caused by the associated `CloseStream`, or other environmental reasons.
+    pub async fn accept(&mut self) -> Result<QuicConn, AcceptError> {
+        let conn = accept(&mut self.stream, self.accept_size, None).await?;
+
+        Ok(QuicConn {
+            id: conn.id,
+            control: conn.stream,
+            pending_bi: VecDeque::new(),
+            pending_uni: VecDeque::new(),
+            peer_addr: conn.peer_addr,
+        })
+    }
+}
+
+/// QUIC client connection.  Streams are multiplexed over the connection.
+pub struct QuicConn {
+    id: i32,
+    control: RecvWriteStream,
+    pending_bi: VecDeque<QuicStream>,
+    pending_uni: VecDeque<QuicRecvStream>,
+
+    /// The client connection's address.
+    pub peer_addr: SocketAddr,
+}
+
+impl QuicConn {
+    /// Accept a bidirectional stream opened by the client.  An
+    /// `AcceptErrorKind::Closed` error is returned when the connection is
+    /// closed.
+    pub async fn accept_bi(&mut self) -> Result<QuicStream, AcceptError> {
+        loop {
+            if let Some(s) = self.pending_bi.pop_front() {
+                return Ok(s);
+            }
+            self.recv_stream().await?;
+        }
+    }
+
+    /// Accept a unidirectional stream opened by the client.  An
+    /// `AcceptErrorKind::Closed` error is returned when the connection is
+    /// closed.
+    pub async fn accept_uni(&mut self) -> Result<QuicRecvStream, AcceptError> {
+        loop {
+            if let Some(s) = self.pending_uni.pop_front() {
+                return Ok(s);
+            }
+            self.recv_stream().await?;
+        }
+    }
+
+    /// Open a bidirectional stream to the client.  An
+    /// `AcceptErrorKind::Other` error is returned if the service doesn't
+    /// support opening streams.
+    pub async fn open_bi(&mut self) -> Result<QuicStream, AcceptError> {
+        let (stream_id, id) = self.open_stream(true).await?;
+
+        Ok(QuicStream {
+            id,
+            stream: SERVICE.stream(stream_id),
+        })
+    }
+
+    /// Open a unidirectional stream to the client.
+    pub async fn open_uni(&mut self) -> Result<QuicSendStream, AcceptError> {
+        let (stream_id, id) = self.open_stream(false).await?;
+
+        Ok(QuicSendStream {
+            id,
+            stream: SERVICE.output_stream(stream_id),
+        })
+    }
+
+    /// Close the connection and all of its streams.
+    pub async fn close(&mut self) {
+        self.control.close().await
+    }
+
+    async fn recv_stream(&mut self) -> Result<(), AcceptError> {
+        let frame = match recv_frame(&mut self.control).await {
+            Ok(frame) => frame,
+            Err(FrameError::Closed) => return Err(AcceptError::listener_closed()),
+            Err(FrameError::TooLarge) => return Err(AcceptError::decode()),
+        };
+
+        let r = match size_prefixed_root::<flat::QUICStream>(&frame) {
+            Ok(r) => r,
+            Err(_) => return Err(AcceptError::decode()),
+        };
+
+        if r.error() != flat::AcceptError::None {
+            return Err(AcceptError::new(r.error()));
+        }
+
+        if r.bidi() {
+            self.pending_bi.push_back(QuicStream {
+                id: r.quic_id(),
+                stream: SERVICE.stream(r.stream_id()),
+            });
+        } else {
+            self.pending_uni.push_back(QuicRecvStream {
+                id: r.quic_id(),
+                stream: SERVICE.input_stream(r.stream_id()),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn open_stream(&mut self, bidi: bool) -> Result<(i32, u64), AcceptError> {
+        let mut b = FlatBufferBuilder::new();
+
+        let function = flat::OpenQUICStream::create(
+            &mut b,
+            &flat::OpenQUICStreamArgs {
+                conn_id: self.id,
+                bidi,
+            },
+        );
+
+        let call = flat::Call::create(
+            &mut b,
+            &flat::CallArgs {
+                function_type: flat::Function::OpenQUICStream,
+                function: Some(function.as_union_value()),
+            },
+        );
+
+        b.finish_minimal(call);
+
+        SERVICE
+            .call(b.finished_data(), |reply: &[u8]| {
+                if reply.is_empty() {
+                    return Err(AcceptError::unsupported_call());
+                }
+
+                let r = match root::<flat::QUICStream>(reply) {
+                    Ok(r) => r,
+                    Err(_) => return Err(AcceptError::decode()),
+                };
+
+                if r.error() != flat::AcceptError::None {
+                    return Err(AcceptError::new(r.error()));
+                }
+
+                Ok((r.stream_id(), r.quic_id()))
+            })
+            .await
+    }
+}
+
+/// Bidirectional QUIC stream.
+pub struct QuicStream {
+    /// QUIC stream id.
+    pub id: u64,
+
+    /// I/O stream for exchanging data with the client.
+    pub stream: RecvWriteStream,
+}
+
+/// Unidirectional QUIC stream opened by the client.
+pub struct QuicRecvStream {
+    /// QUIC stream id.
+    pub id: u64,
+
+    /// Input stream for receiving data from the client.
+    pub stream: RecvStream,
+}
+
+/// Unidirectional QUIC stream opened by the server.
+pub struct QuicSendStream {
+    /// QUIC stream id.
+    pub id: u64,
+
+    /// Output stream for sending data to the client.
+    pub stream: WriteStream,
+}
@@ -0,0 +1,68 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{flat, SERVICE};
+use flatbuffers::{root, FlatBufferBuilder};
+use std::ops::RangeInclusive;
+
+/// Binding limits of the program.
+pub struct Quota {
+    _internal: (),
+
+    /// Number of bindings which can still be created.
+    pub remaining_bindings: u32,
+
+    /// Size of the largest accept frame supported by the service, in bytes.
+    pub max_accept_size: usize,
+
+    /// Ports which may be bound.
+    pub port_ranges: Vec<RangeInclusive<u16>>,
+}
+
+impl Quota {
+    /// Check if `port` is within one of the allowed port ranges.
+    pub fn allows_port(&self, port: u16) -> bool {
+        self.port_ranges.iter().any(|r| r.contains(&port))
+    }
+}
+
+/// Query the binding limits from the listener service.  None is returned if
+/// the service doesn't support the query.
+pub async fn quota() -> Option<Quota> {
+    let mut b = FlatBufferBuilder::new();
+
+    let function = flat::GetQuota::create(&mut b, &flat::GetQuotaArgs {});
+
+    let call = flat::Call::create(
+        &mut b,
+        &flat::CallArgs {
+            function_type: flat::Function::GetQuota,
+            function: Some(function.as_union_value()),
+        },
+    );
+
+    b.finish_minimal(call);
+
+    SERVICE
+        .call(b.finished_data(), |reply: &[u8]| {
+            if reply.is_empty() {
+                return None;
+            }
+
+            let r = root::<flat::Quota>(reply).ok()?;
+
+            let port_ranges = match r.port_ranges() {
+                Some(v) => v.iter().map(|r| r.first()..=r.last()).collect(),
+                None => Vec::new(),
+            };
+
+            Some(Quota {
+                _internal: (),
+                remaining_bindings: r.remaining_bindings(),
+                max_accept_size: r.max_accept_size().0 as usize,
+                port_ranges,
+            })
+        })
+        .await
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{Acceptor, Verdict};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Instant;
+
+/// Accept rate limit.
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimit {
+    _internal: (),
+
+    /// Sustained number of connections accepted per second.
+    pub per_second: u32,
+
+    /// Number of connections which may be accepted in quick succession after
+    /// an idle period.
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// Allow `per_second` connections per second on average, and up to
+    /// `burst` at once.
+    pub fn new(per_second: u32, burst: u32) -> Self {
+        Self {
+            _internal: (),
+            per_second,
+            burst,
+        }
+    }
+}
+
+/// Token bucket.
+struct Bucket {
+    limit: RateLimit,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst.max(1) as f64,
+            updated: now,
+        }
+    }
+
+    /// Refill according to the time elapsed since the previous call, and
+    /// take a token if one is available.
+    fn take(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        let capacity = self.limit.burst.max(1) as f64;
+
+        self.tokens = (self.tokens + elapsed * self.limit.per_second as f64).min(capacity);
+        self.updated = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Rate limiter shared with the acceptor's reception future.
+#[derive(Clone)]
+pub(crate) struct RateLimiter {
+    bucket: Rc<RefCell<Bucket>>,
+}
+
+impl RateLimiter {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            bucket: Rc::new(RefCell::new(Bucket::new(limit, Instant::now()))),
+        }
+    }
+
+    /// Called when a connection arrives.  The runtime doesn't provide
+    /// timers, so the bucket is refilled only then.
+    pub(crate) fn check(&self) -> Verdict {
+        if self.bucket.borrow_mut().take(Instant::now()) {
+            Verdict::Accept
+        } else {
+            Verdict::Reject
+        }
+    }
+}
+
+impl Acceptor {
+    /// Reject connections which arrive faster than the limit allows.
+    /// Rejected connections are closed without opening streams for them (see
+    /// `set_filter`).  The runtime has no timers, so connections can't be
+    /// held back until the rate drops; the limit is applied when a connection
+    /// arrives, based on the time elapsed since the previous one.  Replaces
+    /// any previous rate limit.
+    pub fn rate_limit(&mut self, limit: RateLimit) {
+        self.rate = Some(RateLimiter::new(limit));
+    }
+
+    /// Remove the rate limit.
+    pub fn clear_rate_limit(&mut self) {
+        self.rate = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn burst_and_refill() {
+        let start = Instant::now();
+        let mut bucket = Bucket::new(RateLimit::new(10, 3), start);
+
+        for _ in 0..3 {
+            assert!(bucket.take(start));
+        }
+        assert!(!bucket.take(start));
+
+        let later = start + Duration::from_millis(150);
+        assert!(bucket.take(later));
+        assert!(!bucket.take(later));
+
+        // Idle time doesn't accumulate more tokens than the burst size.
+        let much_later = later + Duration::from_secs(60);
+        for _ in 0..3 {
+            assert!(bucket.take(much_later));
+        }
+        assert!(!bucket.take(much_later));
+    }
+
+    #[test]
+    fn zero_burst_allows_one() {
+        let start = Instant::now();
+        let mut bucket = Bucket::new(RateLimit::new(1, 0), start);
+
+        assert!(bucket.take(start));
+        assert!(!bucket.take(start + Duration::from_millis(500)));
+        assert!(bucket.take(start + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn zero_rate_never_refills() {
+        let start = Instant::now();
+        let mut bucket = Bucket::new(RateLimit::new(0, 2), start);
+
+        assert!(bucket.take(start));
+        assert!(bucket.take(start));
+        assert!(!bucket.take(start + Duration::from_secs(3600)));
+    }
+}
@@ -0,0 +1,374 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::drive::{self, Driven};
+use crate::io::stream_error;
+use crate::Conn;
+use gain::stream::buf::DEFAULT_READ_CAPACITY;
+use gain::stream::{CloseStream, ErrorCode, Recv as _, RecvOnlyStream, RecvStream};
+use std::cell::RefCell;
+use std::future::poll_fn;
+use std::io;
+use std::num::NonZeroI32;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+/// Receive buffering parameters of a connection.
+#[derive(Clone, Copy, Debug)]
+pub struct RecvOptions {
+    _internal: (),
+
+    /// Amount of data requested from the client at a time.  Small chunks
+    /// keep latency down; large chunks reduce overhead for bulk transfers.
+    pub chunk_size: usize,
+
+    /// Receiving is paused while this much data is buffered but hasn't been
+    /// read by the application.  At most one chunk more is buffered.
+    pub window: usize,
+}
+
+impl RecvOptions {
+    pub fn new(chunk_size: usize, window: usize) -> Self {
+        Self {
+            _internal: (),
+            chunk_size,
+            window,
+        }
+    }
+}
+
+/// 8 kB chunks and a 64 kB window.
+impl Default for RecvOptions {
+    fn default() -> Self {
+        Self::new(DEFAULT_READ_CAPACITY, 65536)
+    }
+}
+
+/// Received data which hasn't been read yet.
+pub(crate) struct RecvBuf {
+    data: Vec<u8>,
+    pos: usize,
+}
+
+impl RecvBuf {
+    /// Number of buffered bytes.
+    pub(crate) fn len(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.data[self.pos..]
+    }
+
+    /// Remove bytes from the start of the buffer.
+    pub(crate) fn consume(&mut self, n: usize) {
+        self.pos = (self.pos + n).min(self.data.len());
+
+        if self.pos == self.data.len() {
+            self.data.clear();
+            self.pos = 0;
+        }
+    }
+
+    /// Remove all bytes without copying them.  The buffer allocation is
+    /// handed over, and a new one is made when more data is received.
+    #[cfg(feature = "bytes")]
+    pub(crate) fn take_bytes(&mut self) -> bytes::Bytes {
+        let pos = std::mem::replace(&mut self.pos, 0);
+        bytes::Bytes::from(std::mem::take(&mut self.data)).slice(pos..)
+    }
+
+    fn extend(&mut self, src: &[u8]) {
+        if self.pos > 0 {
+            self.data.drain(..self.pos);
+            self.pos = 0;
+        }
+        self.data.extend_from_slice(src);
+    }
+}
+
+impl io::Read for RecvBuf {
+    fn read(&mut self, dest: &mut [u8]) -> io::Result<usize> {
+        let n = self.len().min(dest.len());
+        dest[..n].copy_from_slice(&self.as_slice()[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+enum RecvResult {
+    Pending,
+    Eof,
+    Err(ErrorCode),
+}
+
+struct Shared {
+    buf: RecvBuf,
+    result: RecvResult,
+    options: RecvOptions,
+    reader: Option<Waker>,
+    dropped: bool,
+}
+
+impl Shared {
+    fn new(result: RecvResult, options: RecvOptions) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            buf: RecvBuf {
+                data: Vec::new(),
+                pos: 0,
+            },
+            result,
+            options,
+            reader: None,
+            dropped: false,
+        }))
+    }
+
+    fn below_window(&self) -> bool {
+        self.buf.len() < self.options.window.max(1)
+    }
+
+    /// Size of the next chunk to request once there is room in the buffer,
+    /// or None if nobody is reading anymore.
+    fn poll_chunk(&self) -> Poll<Option<usize>> {
+        if self.dropped {
+            Poll::Ready(None)
+        } else if self.below_window() {
+            Poll::Ready(Some(self.options.chunk_size.max(1)))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    /// Buffer received data.  Returns the amount of additional data to
+    /// request, which keeps the requested amount at a chunk until the window
+    /// is full.
+    fn receive(&mut self, src: &[u8]) -> usize {
+        if self.dropped {
+            return 0;
+        }
+
+        self.buf.extend(src);
+
+        if self.below_window() {
+            src.len()
+        } else {
+            0
+        }
+    }
+}
+
+/// Input stream with a bounded buffer.
+///
+/// The receive future is polled by the reader, which requests more data
+/// after consuming some.  It is also polled when the runtime wakes it,
+/// because the runtime doesn't perform any I/O while received data is
+/// waiting to be consumed, even if the reader isn't reading at the moment.
+/// The reader is woken only at that point (outside of any task), so that it
+/// can be a spawned task.
+pub(crate) struct Receiver {
+    shared: Rc<RefCell<Shared>>,
+    receive: Option<Driven>,
+    _closer: CloseStream,
+}
+
+impl Receiver {
+    pub(crate) fn new(stream: RecvStream, options: RecvOptions) -> Self {
+        let (stream, closer) = stream.split();
+        let shared = Shared::new(RecvResult::Pending, options);
+
+        let weak = Rc::downgrade(&shared);
+        let notify = move || {
+            if let Some(shared) = weak.upgrade() {
+                let reader = shared.borrow_mut().reader.take();
+                if let Some(w) = reader {
+                    w.wake();
+                }
+            }
+        };
+
+        let receive = drive::start(receive(shared.clone(), stream), Some(Rc::new(notify)));
+
+        Self {
+            shared,
+            receive: Some(receive),
+            _closer: closer,
+        }
+    }
+
+    /// Poll the receive future, which requests more data if there is room.
+    fn pump(&self) {
+        if let Some(receive) = &self.receive {
+            receive.poll();
+        }
+    }
+
+    /// Takes effect when the next chunk is requested.
+    pub(crate) fn set_options(&mut self, options: RecvOptions) {
+        self.shared.borrow_mut().options = options;
+        self.pump();
+    }
+
+    /// Pass the buffered data to the receptor once there is some.  The
+    /// receptor isn't called at end of stream.
+    pub(crate) fn poll_read_with<F>(
+        &mut self,
+        cx: &mut Context,
+        receptor: F,
+    ) -> Poll<io::Result<usize>>
+    where
+        F: FnOnce(&mut RecvBuf) -> usize,
+    {
+        // Take data which the runtime is holding for the stream.
+        self.pump();
+
+        let mut shared = self.shared.borrow_mut();
+
+        if shared.buf.len() > 0 {
+            let n = receptor(&mut shared.buf);
+            let room = shared.below_window();
+            drop(shared);
+
+            if room {
+                self.pump();
+            }
+
+            return Poll::Ready(Ok(n));
+        }
+
+        match shared.result {
+            RecvResult::Pending => {
+                shared.reader = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            RecvResult::Eof => Poll::Ready(Ok(0)),
+            RecvResult::Err(code) => Poll::Ready(Err(stream_error(code))),
+        }
+    }
+}
+
+/// Reads see end of stream.
+impl Default for Receiver {
+    fn default() -> Self {
+        Self {
+            shared: Shared::new(RecvResult::Eof, RecvOptions::default()),
+            receive: None,
+            _closer: Default::default(),
+        }
+    }
+}
+
+impl Drop for Receiver {
+    fn drop(&mut self) {
+        // Let the receive future notice that nobody is reading anymore.  It
+        // keeps running until a chunk which has been requested is received.
+        self.shared.borrow_mut().dropped = true;
+        self.pump();
+    }
+}
+
+async fn receive(shared: Rc<RefCell<Shared>>, mut stream: RecvOnlyStream) {
+    let note = loop {
+        // Wait for room in the buffer.  The reader polls this again after
+        // consuming data.
+        let chunk_size = poll_fn(|_| shared.borrow().poll_chunk()).await;
+
+        let chunk_size = match chunk_size {
+            Some(n) => n,
+            None => return,
+        };
+
+        // Request more data as it arrives, until the window is full.  The
+        // future returns when the requested data has been received.
+        let receptor = |src: &[u8], _: i32| shared.borrow_mut().receive(src);
+
+        if let Some(note) = stream.recv(chunk_size, receptor).await {
+            break note;
+        }
+    };
+
+    shared.borrow_mut().result = match NonZeroI32::new(note) {
+        None => RecvResult::Eof,
+        Some(n) => RecvResult::Err(ErrorCode(n)),
+    };
+}
+
+impl Conn {
+    /// Set the receive chunk size and flow window.  They can be changed at
+    /// any time; a chunk which has already been requested is received in
+    /// full.
+    pub fn set_recv_options(&mut self, options: RecvOptions) {
+        if let Some(io) = &mut self.io {
+            io.reader.set_recv_options(options);
+        }
+        self.recv = options;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn recv_buf() {
+        let mut b = RecvBuf {
+            data: Vec::new(),
+            pos: 0,
+        };
+
+        b.extend(b"hello");
+        b.consume(2);
+        assert_eq!(b.as_slice(), b"llo");
+
+        // Consumed bytes are dropped when more data is appended.
+        b.extend(b" world");
+        assert_eq!(b.pos, 0);
+        assert_eq!(b.as_slice(), b"llo world");
+
+        let mut dest = [0; 4];
+        assert_eq!(b.read(&mut dest).unwrap(), 4);
+        assert_eq!(&dest, b"llo ");
+        assert_eq!(b.len(), 5);
+
+        b.consume(100);
+        assert_eq!(b.len(), 0);
+        assert_eq!(b.read(&mut dest).unwrap(), 0);
+    }
+
+    #[test]
+    fn window() {
+        let shared = Shared::new(RecvResult::Pending, RecvOptions::new(4, 10));
+        let mut s = shared.borrow_mut();
+
+        assert_eq!(s.poll_chunk(), Poll::Ready(Some(4)));
+        assert_eq!(s.receive(b"abcd"), 4);
+        assert_eq!(s.receive(b"efgh"), 4);
+
+        // The window is full; the rest of the requested chunk is buffered
+        // beyond it.
+        assert_eq!(s.receive(b"ij"), 0);
+        assert_eq!(s.receive(b"kl"), 0);
+        assert_eq!(s.buf.len(), 12);
+        assert_eq!(s.poll_chunk(), Poll::Pending);
+
+        s.buf.consume(3);
+        assert_eq!(s.poll_chunk(), Poll::Ready(Some(4)));
+
+        s.dropped = true;
+        assert_eq!(s.poll_chunk(), Poll::Ready(None));
+        assert_eq!(s.receive(b"mn"), 0);
+        assert_eq!(s.buf.len(), 9);
+    }
+
+    #[test]
+    fn zero_options() {
+        let shared = Shared::new(RecvResult::Pending, RecvOptions::new(0, 0));
+        let mut s = shared.borrow_mut();
+
+        assert_eq!(s.poll_chunk(), Poll::Ready(Some(1)));
+        assert_eq!(s.receive(b"a"), 0);
+        assert_eq!(s.poll_chunk(), Poll::Pending);
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{BindError, BindOptions, ClientCertPolicy, Conn, Listener};
+use gain::stream::buf::{Read as _, ReadWriteStream};
+use gain::stream::{Close as _, Write as _};
+use gain::task::spawn_local;
+
+const HTTP_PORT: u16 = 80;
+const HTTPS_PORT: u16 = 443;
+const MAX_REQUEST_LEN: usize = 8192;
+
+impl Listener {
+    /// Like `bind_tls`, but additionally listen to plain HTTP connections at
+    /// port 80 using the same server name.  HTTP requests are answered with a
+    /// permanent redirect to the TLS listener by a background task, which
+    /// runs until the TCP binding is lost.
+    ///
+    /// `BindOptions::ttl` is ignored for the redirect binding.
+    pub async fn bind_tls_with_redirect(opt: BindOptions<'_>) -> Result<Self, BindError> {
+        let redirect_opt = BindOptions {
+            port: HTTP_PORT,
+            ttl: None,
+            client_cert: ClientCertPolicy::None,
+            alpn: &[],
+            min_tls_version: None,
+            ..opt.clone()
+        };
+
+        let lis = Self::bind_tls(opt).await?;
+        let redirect = Self::bind_tcp(redirect_opt).await?;
+
+        let hostnames = lis.addr.hostnames.clone();
+        let port = lis.addr.port;
+        spawn_local(serve(redirect, hostnames, port));
+
+        Ok(lis)
+    }
+}
+
+async fn serve(mut lis: Listener, hostnames: Vec<String>, port: u16) {
+    while let Ok(conn) = lis.accept().await {
+        redirect(conn, &hostnames, port).await;
+    }
+}
+
+async fn redirect(conn: Conn, hostnames: &[String], port: u16) {
+    let mut stream = ReadWriteStream::new(conn.stream);
+    let mut buf = Vec::new();
+    let mut len = 0;
+
+    let head = loop {
+        if len == buf.len() {
+            if len >= MAX_REQUEST_LEN {
+                return;
+            }
+            buf.resize(len + 512, 0);
+        }
+
+        match stream.read(&mut buf[len..]).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => len += n,
+        }
+
+        if let Some(i) = find_head_end(&buf[..len]) {
+            break &buf[..i];
+        }
+    };
+
+    let (path, host) = match parse_head(head) {
+        Some(x) => x,
+        None => return,
+    };
+
+    // Don't turn the listener into an open redirector.
+    let host = match host.filter(|h| is_served(h, hostnames)) {
+        Some(h) => h,
+        None => match hostnames.first() {
+            Some(h) => h,
+            None => return,
+        },
+    };
+
+    let location = if port == HTTPS_PORT {
+        format!("https://{}{}", host, path)
+    } else {
+        format!("https://{}:{}{}", host, port, path)
+    };
+
+    let response = format!(
+        "HTTP/1.1 301 Moved Permanently\r\nLocation: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        location
+    );
+
+    if stream.write_all(response.as_bytes()).await.is_ok() {
+        let _ = stream.close().await;
+    }
+}
+
+fn is_served(host: &str, hostnames: &[String]) -> bool {
+    let host = host.as_bytes();
+    if !host
+        .iter()
+        .all(|&c| c.is_ascii_alphanumeric() || c == b'.' || c == b'-')
+    {
+        return false;
+    }
+
+    hostnames.iter().any(|name| {
+        let name = name.as_bytes();
+        host.eq_ignore_ascii_case(name)
+            || (host.len() > name.len()
+                && host[host.len() - name.len() - 1] == b'.'
+                && host[host.len() - name.len()..].eq_ignore_ascii_case(name))
+    })
+}
+
+fn find_head_end(data: &[u8]) -> Option<usize> {
+    data.windows(4).position(|w| w == b"\r\n\r\n")
+}
+
+/// Extract the request target and the hostname part of the Host header.
+fn parse_head(head: &[u8]) -> Option<(&str, Option<&str>)> {
+    let head = std::str::from_utf8(head).ok()?;
+    let mut lines = head.split("\r\n");
+
+    let mut request = lines.next()?.split(' ');
+    let _method = request.next()?;
+    let path = request.next().filter(|s| s.starts_with('/'))?;
+
+    let host = lines.find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        if !name.eq_ignore_ascii_case("host") {
+            return None;
+        }
+        let value = value.trim();
+        let host = match value.rsplit_once(':') {
+            Some((host, port)) if port.bytes().all(|c| c.is_ascii_digit()) => host,
+            _ => value,
+        };
+        Some(host)
+    });
+
+    Some((path, host))
+}
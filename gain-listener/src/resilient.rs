@@ -0,0 +1,131 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::events::EventSink;
+use crate::{
+    accept, AcceptError, AcceptErrorKind, BindError, BindErrorKind, BindOptions, Binding,
+    BindingToken, Conn, Listener, ListenerEvents,
+};
+use std::fmt;
+
+/// Outcome of `ResilientListener::accept`.
+#[allow(clippy::large_enum_variant)]
+pub enum ResilientAccept {
+    /// A client connection.
+    Conn(Conn),
+
+    /// The binding was lost due to the error.  It is re-established during
+    /// the next `accept` call.
+    Rebinding(AcceptError),
+}
+
+/// TLS listener which binds again when its service stream is closed for
+/// reasons other than the program's own actions, e.g. when the lease expires
+/// or the service is restarted.  The same hostname is reclaimed if possible.
+///
+/// A failed rebinding attempt isn't retried by itself, because the runtime
+/// has no timers to pace the attempts with.  The error is returned, and the
+/// next `accept` call tries again, so the caller decides when.
+pub struct ResilientListener<'a> {
+    opt: BindOptions<'a>,
+    token: BindingToken,
+    listener: Listener,
+    lost: bool,
+    events: EventSink,
+}
+
+impl<'a> ResilientListener<'a> {
+    /// Listen to TLS connections like `Listener::bind_tls`.
+    pub async fn bind_tls(opt: BindOptions<'a>) -> Result<Self, BindError> {
+        let (listener, token) = Listener::bind_tls_with_token(opt.clone(), None).await?;
+
+        Ok(Self {
+            opt,
+            token,
+            listener,
+            lost: false,
+            events: EventSink::default(),
+        })
+    }
+
+    /// Address of the current binding.  It may change when the binding is
+    /// re-established.
+    pub fn addr(&self) -> &Binding {
+        &self.listener.addr
+    }
+
+    /// Subscribe to events which don't result in connections.  The
+    /// subscription persists across rebinding.  See `Listener::events`.
+    pub fn events(&self) -> ListenerEvents {
+        self.events.subscribe()
+    }
+
+    /// Accept a client connection.  If the binding has been lost, it is
+    /// re-established first; a rebinding failure is returned as an error, and
+    /// the next call tries again.
+    pub async fn accept(&mut self) -> Result<ResilientAccept, ResilientError> {
+        if self.lost {
+            self.rebind().await.map_err(ResilientError::Rebind)?;
+            self.lost = false;
+        }
+
+        let lis = &mut self.listener;
+
+        match accept(&mut lis.stream, lis.accept_size, Some(&self.events)).await {
+            Ok(mut conn) => {
+                conn.attach(&lis.addr);
+                Ok(ResilientAccept::Conn(conn))
+            }
+
+            Err(e) if is_lost(&e) => {
+                self.lost = true;
+                Ok(ResilientAccept::Rebinding(e))
+            }
+
+            Err(e) => Err(ResilientError::Accept(e)),
+        }
+    }
+
+    async fn rebind(&mut self) -> Result<(), BindError> {
+        let result =
+            Listener::bind_tls_with_token(self.opt.clone(), Some(self.token.clone())).await;
+
+        // The hostname couldn't be reclaimed; settle for a new one.
+        let (listener, token) = match result {
+            Err(e) if e.kind() == BindErrorKind::InvalidToken => {
+                Listener::bind_tls_with_token(self.opt.clone(), None).await?
+            }
+            result => result?,
+        };
+
+        self.listener = listener;
+        self.token = token;
+        Ok(())
+    }
+}
+
+fn is_lost(e: &AcceptError) -> bool {
+    matches!(
+        e.kind(),
+        AcceptErrorKind::Closed | AcceptErrorKind::LeaseExpired
+    )
+}
+
+#[derive(Debug)]
+pub enum ResilientError {
+    /// Accepting a connection failed while the binding remained intact.
+    Accept(AcceptError),
+
+    /// The binding was lost and couldn't be re-established.
+    Rebind(BindError),
+}
+
+impl fmt::Display for ResilientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Accept(e) => e.fmt(f),
+            Self::Rebind(e) => write!(f, "rebinding failed: {}", e),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{
+    flat, AcceptError, Binding, Conn, Listener, ListenerCloser, MultiAcceptor, UnbindError,
+};
+
+/// Group of listeners which are accepted from together and shut down
+/// together.  Listeners can be added and removed while the set is in use.
+pub struct ListenerSet {
+    acceptors: MultiAcceptor,
+    closers: Vec<ListenerCloser>,
+}
+
+impl ListenerSet {
+    pub fn new() -> Self {
+        Self {
+            acceptors: MultiAcceptor::new(Vec::new()),
+            closers: Vec::new(),
+        }
+    }
+
+    /// Add a listener.  Its id can be used to remove it later.
+    pub fn insert(&mut self, listener: Listener) -> i32 {
+        let id = listener.id();
+        let (acceptor, closer) = listener.split_acked();
+        self.acceptors.push(acceptor);
+        self.closers.push(closer);
+        id
+    }
+
+    /// Remove a listener and release its binding.  A
+    /// `UnbindErrorKind::NotBound` error is returned if the set doesn't
+    /// contain a listener with the id.
+    pub async fn remove(&mut self, listen_id: i32) -> Result<(), UnbindError> {
+        let i = match self.closers.iter().position(|c| c.listen_id == listen_id) {
+            Some(i) => i,
+            None => return Err(UnbindError::new(flat::UnbindError::NotBound)),
+        };
+
+        self.acceptors.remove(listen_id);
+        self.closers.remove(i).close_and_wait().await
+    }
+
+    /// Number of listeners in the set.
+    pub fn len(&self) -> usize {
+        self.closers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.closers.is_empty()
+    }
+
+    /// Addresses of the listeners which haven't been closed.
+    pub fn bindings(&self) -> impl Iterator<Item = &Binding> {
+        self.acceptors.bindings()
+    }
+
+    /// Accept a client connection from any of the listeners.  See
+    /// `MultiAcceptor::accept`.
+    pub async fn accept(&mut self) -> Result<(Conn, &Binding), AcceptError> {
+        self.acceptors.accept().await
+    }
+
+    /// Close all listeners and wait until their bindings have been released.
+    /// All of them are closed even if some fail; the first error is
+    /// returned.
+    pub async fn shutdown(self) -> Result<(), UnbindError> {
+        drop(self.acceptors);
+
+        let mut result = Ok(());
+
+        for closer in self.closers {
+            if let Err(e) = closer.close_and_wait().await {
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl Default for ListenerSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{Listener, UnbindError};
+use std::cell::Cell;
+use std::future::poll_fn;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+
+#[derive(Default)]
+struct Tracker {
+    active: Cell<usize>,
+    waker: Cell<Option<Waker>>,
+}
+
+/// Counts connections which are being served, for `Listener::shutdown`.
+/// Clones share the count.
+#[derive(Clone, Default)]
+pub struct ConnTracker {
+    shared: Rc<Tracker>,
+}
+
+impl ConnTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a connection.  It is considered to be in flight until the
+    /// returned handle is dropped.
+    pub fn track(&self) -> ConnHandle {
+        let active = &self.shared.active;
+        active.set(active.get() + 1);
+        ConnHandle {
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Number of connections in flight.
+    pub fn active(&self) -> usize {
+        self.shared.active.get()
+    }
+}
+
+/// In-flight connection registration.  See `ConnTracker::track`.
+pub struct ConnHandle {
+    shared: Rc<Tracker>,
+}
+
+impl Drop for ConnHandle {
+    fn drop(&mut self) {
+        let active = &self.shared.active;
+        active.set(active.get() - 1);
+
+        if let Some(waker) = self.shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Listener {
+    /// Stop accepting connections, wait until the connections tracked by
+    /// `conns` have finished, and release the binding.  Clients which connect
+    /// during the drain period are not served.
+    ///
+    /// There is no deadline, because the runtime has no timers: a stalled
+    /// connection delays shutdown until it ends.  Handles are typically
+    /// dropped by spawned connection tasks, which may only wake the main
+    /// future, so shutdown must be awaited in the main future.
+    pub async fn shutdown(self, conns: &ConnTracker) -> Result<(), UnbindError> {
+        poll_fn(|cx| {
+            if conns.active() == 0 {
+                Poll::Ready(())
+            } else {
+                conns.shared.waker.set(Some(cx.waker().clone()));
+                Poll::Pending
+            }
+        })
+        .await;
+
+        self.unbind().await
+    }
+}
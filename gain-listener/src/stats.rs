@@ -0,0 +1,72 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::Conn;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Byte counters shared by a connection and its halves.
+#[derive(Default)]
+pub(crate) struct Counters {
+    read: Cell<u64>,
+    written: Cell<u64>,
+}
+
+impl Counters {
+    pub(crate) fn add_read(&self, n: usize) {
+        self.read.set(self.read.get() + n as u64);
+    }
+
+    pub(crate) fn add_written(&self, n: usize) {
+        self.written.set(self.written.get() + n as u64);
+    }
+}
+
+/// Amount of data transferred over a connection.  Data is counted only if it
+/// is transferred through `Conn::split`, the owned halves or the
+/// `AsyncRead`/`AsyncWrite` implementations (not the `stream` field).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ConnStats {
+    _internal: (),
+
+    /// Number of bytes read from the connection.
+    pub bytes_read: u64,
+
+    /// Number of bytes which have been written to the connection.  Data
+    /// which is still being written is not included.
+    pub bytes_written: u64,
+}
+
+/// Access to a connection's transfer counters which outlives the
+/// connection.  See `Conn::stats_handle`.
+#[derive(Clone)]
+pub struct ConnStatsHandle {
+    counters: Rc<Counters>,
+}
+
+impl ConnStatsHandle {
+    /// Current values of the counters.
+    pub fn stats(&self) -> ConnStats {
+        ConnStats {
+            _internal: (),
+            bytes_read: self.counters.read.get(),
+            bytes_written: self.counters.written.get(),
+        }
+    }
+}
+
+impl Conn {
+    /// Amount of data transferred so far.
+    pub fn stats(&self) -> ConnStats {
+        self.stats_handle().stats()
+    }
+
+    /// Handle for reading the transfer counters after the connection (or its
+    /// halves) has been closed or dropped, e.g. for access logging.
+    pub fn stats_handle(&self) -> ConnStatsHandle {
+        ConnStatsHandle {
+            counters: self.counters.clone(),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{
+    fallback_accept_size, flat, BindError, Binding, Listener, Protocol, UnbindError, SERVICE,
+};
+use flatbuffers::{root, FlatBufferBuilder};
+
+/// Serialized state of a suspended listener.  The state is opaque; it can be
+/// stored as bytes and restored with `ListenerState::from_bytes`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ListenerState(Vec<u8>);
+
+impl ListenerState {
+    /// Restore a state which was previously stored.
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// Serialized form of the state.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Convert the state into its serialized form.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl Listener {
+    /// Detach the binding from this listener before the instance is
+    /// suspended.  The service keeps the binding and queues incoming
+    /// connections until `Listener::resume` is called with the returned
+    /// state.  Afterwards this listener's `accept` returns an
+    /// `AcceptErrorKind::Closed` error, and the listener should be dropped.
+    pub async fn prepare_suspend(&self) -> Result<ListenerState, UnbindError> {
+        let mut b = FlatBufferBuilder::new();
+
+        let function = flat::SuspendBinding::create(
+            &mut b,
+            &flat::SuspendBindingArgs {
+                listen_id: self.listen_id,
+            },
+        );
+
+        let call = flat::Call::create(
+            &mut b,
+            &flat::CallArgs {
+                function_type: flat::Function::SuspendBinding,
+                function: Some(function.as_union_value()),
+            },
+        );
+
+        b.finish_minimal(call);
+
+        SERVICE
+            .call(b.finished_data(), |reply: &[u8]| {
+                if reply.is_empty() {
+                    return Err(UnbindError::unsupported_call());
+                }
+
+                let r = match root::<flat::SuspendedBinding>(reply) {
+                    Ok(r) => r,
+                    Err(_) => return Err(UnbindError::decode()),
+                };
+
+                if r.error() != flat::UnbindError::None {
+                    return Err(UnbindError::new(r.error()));
+                }
+
+                match r.state() {
+                    Some(v) => Ok(ListenerState(v.bytes().to_vec())),
+                    None => Err(UnbindError::decode()),
+                }
+            })
+            .await
+    }
+
+    /// Reattach to a binding which was detached with `prepare_suspend`,
+    /// after the instance has been resumed or restored.  Connections which
+    /// arrived in the meantime can be accepted from the new listener.  The
+    /// binding's label is not part of the state.
+    pub async fn resume(state: &ListenerState) -> Result<Self, BindError> {
+        let mut accept_size = flat::AcceptSize::Variable;
+
+        loop {
+            match resume_with_accept_size(state, accept_size).await {
+                Err(e) if e.flat == flat::BindError::InvalidAcceptSize => {
+                    match fallback_accept_size(accept_size) {
+                        Some(size) => accept_size = size,
+                        None => return Err(BindError::protocol_mismatch()),
+                    }
+                }
+                result => {
+                    return result.map(|(listen_id, addr)| Self::new(listen_id, accept_size, addr))
+                }
+            }
+        }
+    }
+}
+
+async fn resume_with_accept_size(
+    state: &ListenerState,
+    accept_size: flat::AcceptSize,
+) -> Result<(i32, Binding), BindError> {
+    let mut b = FlatBufferBuilder::new();
+
+    let state = b.create_vector(&state.0);
+
+    let function = flat::ResumeBinding::create(
+        &mut b,
+        &flat::ResumeBindingArgs {
+            state: Some(state),
+            accept_size,
+        },
+    );
+
+    let call = flat::Call::create(
+        &mut b,
+        &flat::CallArgs {
+            function_type: flat::Function::ResumeBinding,
+            function: Some(function.as_union_value()),
+        },
+    );
+
+    b.finish_minimal(call);
+
+    SERVICE
+        .call(b.finished_data(), |reply: &[u8]| {
+            if reply.is_empty() {
+                return Err(BindError::unsupported_call());
+            }
+
+            let r = match root::<flat::Binding>(reply) {
+                Ok(r) => r,
+                Err(_) => return Err(BindError::decode()),
+            };
+
+            if r.error() != flat::BindError::None {
+                return Err(BindError::new(r.error()));
+            }
+
+            let addr = Protocol::new(r.protocol())
+                .and_then(|protocol| Binding::new(&r, protocol))
+                .ok_or_else(BindError::protocol_mismatch)?;
+
+            Ok((r.listen_id(), addr))
+        })
+        .await
+}
@@ -0,0 +1,289 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+use crate::{flat, Acceptor, Conn, ExportError, Listener, SERVICE};
+use flatbuffers::{root, FlatBufferBuilder};
+use std::time::{Duration, SystemTime};
+
+/// Client certificate verification policy.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ClientCertPolicy {
+    /// Don't ask clients for certificates.
+    #[default]
+    None,
+
+    /// Ask clients for certificates, but accept connections without one.
+    Optional,
+
+    /// Reject clients which don't present a valid certificate.
+    Required,
+}
+
+impl ClientCertPolicy {
+    pub(crate) fn flat(self) -> flat::ClientCertPolicy {
+        match self {
+            ClientCertPolicy::None => flat::ClientCertPolicy::None,
+            ClientCertPolicy::Optional => flat::ClientCertPolicy::Optional,
+            ClientCertPolicy::Required => flat::ClientCertPolicy::Required,
+        }
+    }
+}
+
+/// TLS protocol version.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum TlsVersion {
+    Tls12,
+    Tls13,
+}
+
+impl TlsVersion {
+    pub(crate) fn new(version: flat::TLSVersion) -> Option<Self> {
+        match version {
+            flat::TLSVersion::TLS12 => Some(TlsVersion::Tls12),
+            flat::TLSVersion::TLS13 => Some(TlsVersion::Tls13),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn flat(version: Option<Self>) -> flat::TLSVersion {
+        match version {
+            None => flat::TLSVersion::Any,
+            Some(TlsVersion::Tls12) => flat::TLSVersion::TLS12,
+            Some(TlsVersion::Tls13) => flat::TLSVersion::TLS13,
+        }
+    }
+}
+
+/// How a TLS session was established.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HandshakeKind {
+    /// Full handshake.
+    Full,
+
+    /// Abbreviated handshake which resumed a previous session.
+    Resumed,
+
+    /// Resumed session with early data (0-RTT).  The early data may have
+    /// been replayed; see `Conn::early_data`.
+    ZeroRtt,
+}
+
+impl HandshakeKind {
+    pub(crate) fn new(handshake: flat::Handshake) -> Option<Self> {
+        match handshake {
+            flat::Handshake::Full => Some(HandshakeKind::Full),
+            flat::Handshake::Resumed => Some(HandshakeKind::Resumed),
+            flat::Handshake::ZeroRTT => Some(HandshakeKind::ZeroRtt),
+            _ => None,
+        }
+    }
+}
+
+/// Verified identity of a TLS client.
+pub struct ClientIdentity {
+    /// Certificate chain in DER encoding, starting with the client's own
+    /// certificate.
+    pub chain: Vec<Vec<u8>>,
+
+    /// Subject distinguished name of the client's certificate.
+    pub subject: String,
+
+    /// Subject alternative names (DNS names, email addresses, URIs and IP
+    /// addresses) of the client's certificate, if reported by the service.
+    pub sans: Vec<String>,
+}
+
+impl ClientIdentity {
+    pub(crate) fn new(a: &flat::Accept) -> Option<Self> {
+        let subject = a.client_subject()?;
+
+        let chain = match a.client_chain() {
+            Some(v) => v
+                .iter()
+                .map(|c| match c.der() {
+                    Some(der) => der.bytes().to_vec(),
+                    None => Vec::new(),
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let sans = match a.client_sans() {
+            Some(v) => v.iter().map(Into::into).collect(),
+            None => Vec::new(),
+        };
+
+        Some(Self {
+            chain,
+            subject: subject.into(),
+            sans,
+        })
+    }
+}
+
+/// TLS parameters of a connection.  See `Conn::tls_info`.
+#[derive(Clone, Debug)]
+pub struct TlsInfo {
+    _internal: (),
+
+    /// Server name indicated by the client.
+    pub server_name: Option<String>,
+
+    /// Application-layer protocol negotiated with the client.
+    pub alpn: Option<String>,
+
+    /// TLS version negotiated with the client.
+    pub version: Option<TlsVersion>,
+
+    /// IANA identifier of the negotiated cipher suite, e.g. 0x1301 for
+    /// TLS_AES_128_GCM_SHA256.
+    pub cipher_suite: Option<u16>,
+
+    /// Kind of handshake which established the session.
+    pub handshake: Option<HandshakeKind>,
+}
+
+impl Conn {
+    /// TLS parameters negotiated with the client.  Fields are populated only
+    /// if the service reported them, which requires an accept frame variant
+    /// that carries them (see `Listener::accept_size`).
+    pub fn tls_info(&self) -> TlsInfo {
+        TlsInfo {
+            _internal: (),
+            server_name: self.meta.server_name.clone(),
+            alpn: self.alpn.clone(),
+            version: self.meta.tls_version,
+            cipher_suite: self.meta.cipher_suite,
+            handshake: self.meta.handshake,
+        }
+    }
+
+    /// The client's verified certificate chain and a summary of its
+    /// identity, if the client presented a certificate.  See
+    /// `BindOptions::client_cert`.
+    pub fn peer_certificates(&self) -> Option<&ClientIdentity> {
+        self.client_identity.as_ref()
+    }
+
+    /// Export `len` bytes of keying material from the TLS session according
+    /// to RFC 5705, e.g. for channel binding.  Absent context is distinct
+    /// from empty context.
+    pub async fn export_keying_material(
+        &self,
+        label: &str,
+        context: Option<&[u8]>,
+        len: usize,
+    ) -> Result<Vec<u8>, ExportError> {
+        let mut b = FlatBufferBuilder::new();
+
+        let label = b.create_string(label);
+        let context = context.map(|x| b.create_vector(x));
+
+        let function = flat::ExportKeyingMaterial::create(
+            &mut b,
+            &flat::ExportKeyingMaterialArgs {
+                conn_id: self.id,
+                label: Some(label),
+                context,
+                length: len.try_into().unwrap_or(u32::MAX),
+            },
+        );
+
+        let call = flat::Call::create(
+            &mut b,
+            &flat::CallArgs {
+                function_type: flat::Function::ExportKeyingMaterial,
+                function: Some(function.as_union_value()),
+            },
+        );
+
+        b.finish_minimal(call);
+
+        SERVICE
+            .call(b.finished_data(), |reply: &[u8]| {
+                if reply.is_empty() {
+                    return Err(ExportError::unsupported_call());
+                }
+
+                let r = match root::<flat::KeyingMaterial>(reply) {
+                    Ok(r) => r,
+                    Err(_) => return Err(ExportError::decode()),
+                };
+
+                if r.error() != flat::ExportError::None {
+                    return Err(ExportError::new(r.error()));
+                }
+
+                match r.material() {
+                    Some(v) if v.len() == len => Ok(v.bytes().to_vec()),
+                    _ => Err(ExportError::decode()),
+                }
+            })
+            .await
+    }
+}
+
+/// Status of the certificate served by the listener.
+pub struct TlsDetails {
+    _internal: (),
+
+    /// The service staples an OCSP response to handshakes.
+    pub ocsp_stapled: bool,
+
+    /// Expiration time of the stapled OCSP response, if known.
+    pub ocsp_expires: Option<SystemTime>,
+}
+
+impl Listener {
+    /// Query the certificate status of the binding.  None is returned if the
+    /// service doesn't support the query.
+    pub async fn tls_details(&self) -> Option<TlsDetails> {
+        tls_details(self.listen_id).await
+    }
+}
+
+impl Acceptor {
+    /// Query the certificate status of the binding.  See
+    /// `Listener::tls_details`.
+    pub async fn tls_details(&self) -> Option<TlsDetails> {
+        tls_details(self.listen_id).await
+    }
+}
+
+async fn tls_details(listen_id: i32) -> Option<TlsDetails> {
+    let mut b = FlatBufferBuilder::new();
+
+    let function = flat::GetTLSDetails::create(&mut b, &flat::GetTLSDetailsArgs { listen_id });
+
+    let call = flat::Call::create(
+        &mut b,
+        &flat::CallArgs {
+            function_type: flat::Function::GetTLSDetails,
+            function: Some(function.as_union_value()),
+        },
+    );
+
+    b.finish_minimal(call);
+
+    SERVICE
+        .call(b.finished_data(), |reply: &[u8]| {
+            if reply.is_empty() {
+                return None;
+            }
+
+            let r = root::<flat::TLSDetails>(reply).ok()?;
+
+            let ocsp_expires = match r.ocsp_expires_ms() {
+                0 => None,
+                ms => Some(SystemTime::UNIX_EPOCH + Duration::from_millis(ms)),
+            };
+
+            Some(TlsDetails {
+                _internal: (),
+                ocsp_stapled: r.ocsp_stapled(),
+                ocsp_expires,
+            })
+        })
+        .await
+}
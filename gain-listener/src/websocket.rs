@@ -0,0 +1,744 @@
+// Copyright (c) 2021 Timo Savola.
+// Use of this source code is governed by the MIT
+// license that can be found in the LICENSE file.
+
+//! WebSocket server (RFC 6455).
+//!
+//! A WebSocket connection is established by answering an HTTP request with
+//! the response returned by `upgrade`:
+//!
+//! ```no_run
+//! use gain_listener::http::{Request, Response};
+//! use gain_listener::websocket::{upgrade, Message, WebSocket};
+//!
+//! async fn echo(req: Request) -> Response {
+//!     upgrade(&req, |mut ws: WebSocket| async move {
+//!         while let Ok(Some(msg)) = ws.recv().await {
+//!             if let Message::Text(_) | Message::Binary(_) = msg {
+//!                 if ws.send(msg).await.is_err() {
+//!                     break;
+//!                 }
+//!             }
+//!         }
+//!     })
+//! }
+//! ```
+
+use crate::http::{Request, Response, Upgraded, Version};
+use crate::{OwnedReadHalf, OwnedWriteHalf};
+use futures_io::AsyncWrite;
+use std::future::{poll_fn, Future};
+use std::io;
+use std::pin::Pin;
+use std::str;
+
+const ACCEPT_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Maximum payload size of a control frame.
+const MAX_CONTROL_SIZE: usize = 125;
+
+/// Status codes used when closing a connection.
+pub const CLOSE_NORMAL: u16 = 1000;
+pub const CLOSE_GOING_AWAY: u16 = 1001;
+pub const CLOSE_PROTOCOL_ERROR: u16 = 1002;
+pub const CLOSE_UNSUPPORTED: u16 = 1003;
+pub const CLOSE_NO_STATUS: u16 = 1005;
+pub const CLOSE_INVALID_DATA: u16 = 1007;
+pub const CLOSE_POLICY_VIOLATION: u16 = 1008;
+pub const CLOSE_TOO_LARGE: u16 = 1009;
+pub const CLOSE_INTERNAL_ERROR: u16 = 1011;
+
+/// WebSocket options.
+#[derive(Clone, Debug)]
+pub struct WebSocketOptions {
+    _internal: (),
+
+    /// Maximum size of a received message (or a single frame).  The
+    /// connection is closed with status 1009 if it is exceeded.
+    pub max_message_size: usize,
+}
+
+impl WebSocketOptions {
+    pub fn new() -> Self {
+        Self {
+            _internal: (),
+            max_message_size: 1 << 20,
+        }
+    }
+}
+
+impl Default for WebSocketOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Accept a WebSocket connection request with default options.  See
+/// `upgrade_with`.
+pub fn upgrade<F, R>(req: &Request, f: F) -> Response
+where
+    F: FnOnce(WebSocket) -> R + 'static,
+    R: Future<Output = ()> + 'static,
+{
+    upgrade_with(req, WebSocketOptions::new(), f)
+}
+
+/// Accept a WebSocket connection request.  The returned response should be
+/// returned by the handler; `f` is called with the WebSocket after it has
+/// been sent.  If the request isn't a valid WebSocket handshake, an error
+/// response is returned instead.
+///
+/// Subprotocol negotiation is left to the caller: a Sec-WebSocket-Protocol
+/// header may be added to the response.
+pub fn upgrade_with<F, R>(req: &Request, options: WebSocketOptions, f: F) -> Response
+where
+    F: FnOnce(WebSocket) -> R + 'static,
+    R: Future<Output = ()> + 'static,
+{
+    let key = match handshake_key(req) {
+        Ok(key) => key,
+        Err(resp) => return resp,
+    };
+
+    Response::new(101)
+        .with_header("Upgrade", "websocket")
+        .with_header("Sec-WebSocket-Accept", accept_key(key))
+        .with_upgrade(move |conn| f(WebSocket::new(conn, options)))
+}
+
+/// Validate the handshake request and get its key.
+fn handshake_key(req: &Request) -> Result<&[u8], Response> {
+    if req.method != "GET" {
+        return Err(Response::error(405).with_header("Allow", "GET"));
+    }
+
+    if req.version != Version::Http11
+        || !req.headers.has_token("Connection", "upgrade")
+        || !req.headers.has_token("Upgrade", "websocket")
+    {
+        return Err(Response::error(426).with_header("Upgrade", "websocket"));
+    }
+
+    if req.headers.get_str("Sec-WebSocket-Version").map(str::trim) != Some("13") {
+        return Err(Response::error(426).with_header("Sec-WebSocket-Version", "13"));
+    }
+
+    match req.headers.get("Sec-WebSocket-Key").map(<[u8]>::trim_ascii) {
+        Some(key) if key.len() == 24 => Ok(key),
+        _ => Err(Response::error(400)),
+    }
+}
+
+/// Compute the Sec-WebSocket-Accept value for a key.
+fn accept_key(key: &[u8]) -> String {
+    let mut hash = sha1_smol::Sha1::new();
+    hash.update(key);
+    hash.update(ACCEPT_GUID.as_bytes());
+    base64_encode(&hash.digest().bytes())
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for group in data.chunks(3) {
+        let b = [
+            group[0],
+            group.get(1).copied().unwrap_or(0),
+            group.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        for i in 0..4 {
+            if i <= group.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+/// Frame type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Opcode {
+    Continuation,
+    Text,
+    Binary,
+    Close,
+    Ping,
+    Pong,
+}
+
+impl Opcode {
+    fn from_bits(bits: u8) -> Option<Self> {
+        match bits {
+            0x0 => Some(Self::Continuation),
+            0x1 => Some(Self::Text),
+            0x2 => Some(Self::Binary),
+            0x8 => Some(Self::Close),
+            0x9 => Some(Self::Ping),
+            0xa => Some(Self::Pong),
+            _ => None,
+        }
+    }
+
+    fn bits(self) -> u8 {
+        match self {
+            Self::Continuation => 0x0,
+            Self::Text => 0x1,
+            Self::Binary => 0x2,
+            Self::Close => 0x8,
+            Self::Ping => 0x9,
+            Self::Pong => 0xa,
+        }
+    }
+
+    /// Close, Ping or Pong.  Control frames may appear between the
+    /// fragments of a message.
+    pub fn is_control(self) -> bool {
+        matches!(self, Self::Close | Self::Ping | Self::Pong)
+    }
+}
+
+/// WebSocket frame.  Received frames have been unmasked.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    _internal: (),
+
+    /// This is the last fragment of a message.
+    pub fin: bool,
+
+    pub opcode: Opcode,
+    pub payload: Vec<u8>,
+}
+
+impl Frame {
+    /// Unfragmented frame.
+    pub fn new(opcode: Opcode, payload: impl Into<Vec<u8>>) -> Self {
+        Self {
+            _internal: (),
+            fin: true,
+            opcode,
+            payload: payload.into(),
+        }
+    }
+
+    /// Fragment of a message.  The first fragment has the message's opcode,
+    /// and the rest have `Opcode::Continuation`.
+    pub fn fragment(opcode: Opcode, payload: impl Into<Vec<u8>>, fin: bool) -> Self {
+        Self {
+            fin,
+            ..Self::new(opcode, payload)
+        }
+    }
+}
+
+/// Complete WebSocket message or control frame.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Message {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong(Vec<u8>),
+
+    /// Status code and reason.  The code is `CLOSE_NO_STATUS` if the peer
+    /// didn't specify one.
+    Close(u16, String),
+}
+
+/// Protocol violation by the client: close status code and description.
+struct Violation(u16, &'static str);
+
+/// WebSocket connection.
+///
+/// Received messages are read with `recv`, which reassembles fragmented
+/// messages, answers pings and completes the closing handshake.
+/// Alternatively, frames can be handled individually with `recv_frame` and
+/// `send_frame`; then it's up to the caller to do those things.
+pub struct WebSocket {
+    reader: OwnedReadHalf,
+    writer: OwnedWriteHalf,
+    buf: Vec<u8>,
+    pos: usize,
+    options: WebSocketOptions,
+    message: Option<(Opcode, Vec<u8>)>,
+    close_sent: bool,
+    close_received: bool,
+}
+
+impl WebSocket {
+    fn new(conn: Upgraded, options: WebSocketOptions) -> Self {
+        Self {
+            reader: conn.reader,
+            writer: conn.writer,
+            buf: conn.buffered,
+            pos: 0,
+            options,
+            message: None,
+            close_sent: false,
+            close_received: false,
+        }
+    }
+
+    /// Receive the next message.  Pings are answered automatically, but they
+    /// are also returned.  When a Close message is received, it is answered
+    /// (unless the closing handshake was initiated by `close`) and returned;
+    /// after that, None is returned.  None is also returned if the client
+    /// closes the connection without the closing handshake.
+    ///
+    /// An `io::ErrorKind::InvalidData` error is returned if the client
+    /// violates the protocol.  The connection is closed with an appropriate
+    /// status code in that case.
+    pub async fn recv(&mut self) -> io::Result<Option<Message>> {
+        loop {
+            let frame = match self.recv_frame().await? {
+                Some(frame) => frame,
+                None => return Ok(None),
+            };
+
+            match frame.opcode {
+                Opcode::Ping => {
+                    if !self.close_sent {
+                        self.send_frame(Frame::new(Opcode::Pong, frame.payload.clone()))
+                            .await?;
+                    }
+                    return Ok(Some(Message::Ping(frame.payload)));
+                }
+
+                Opcode::Pong => return Ok(Some(Message::Pong(frame.payload))),
+
+                Opcode::Close => {
+                    let (code, reason) = match parse_close(&frame.payload) {
+                        Ok(close) => close,
+                        Err(v) => return Err(self.fail(v).await),
+                    };
+
+                    if !self.close_sent {
+                        let echo = if code == CLOSE_NO_STATUS {
+                            Vec::new()
+                        } else {
+                            code.to_be_bytes().to_vec()
+                        };
+                        self.send_frame(Frame::new(Opcode::Close, echo)).await?;
+                    }
+
+                    // The server closes the underlying connection first.
+                    let _ = poll_fn(|cx| Pin::new(&mut self.writer).poll_close(cx)).await;
+
+                    return Ok(Some(Message::Close(code, reason)));
+                }
+
+                Opcode::Text | Opcode::Binary => {
+                    if self.message.is_some() {
+                        let v = Violation(CLOSE_PROTOCOL_ERROR, "expected continuation frame");
+                        return Err(self.fail(v).await);
+                    }
+
+                    if frame.fin {
+                        return self.complete(frame.opcode, frame.payload).await.map(Some);
+                    }
+
+                    self.message = Some((frame.opcode, frame.payload));
+                }
+
+                Opcode::Continuation => {
+                    let (opcode, mut data) = match self.message.take() {
+                        Some(message) => message,
+                        None => {
+                            let v =
+                                Violation(CLOSE_PROTOCOL_ERROR, "unexpected continuation frame");
+                            return Err(self.fail(v).await);
+                        }
+                    };
+
+                    if data.len() + frame.payload.len() > self.options.max_message_size {
+                        let v = Violation(CLOSE_TOO_LARGE, "message is too large");
+                        return Err(self.fail(v).await);
+                    }
+
+                    data.extend_from_slice(&frame.payload);
+
+                    if frame.fin {
+                        return self.complete(opcode, data).await.map(Some);
+                    }
+
+                    self.message = Some((opcode, data));
+                }
+            }
+        }
+    }
+
+    async fn complete(&mut self, opcode: Opcode, data: Vec<u8>) -> io::Result<Message> {
+        if opcode == Opcode::Binary {
+            return Ok(Message::Binary(data));
+        }
+
+        match String::from_utf8(data) {
+            Ok(text) => Ok(Message::Text(text)),
+            Err(_) => {
+                let v = Violation(CLOSE_INVALID_DATA, "text message is not valid UTF-8");
+                Err(self.fail(v).await)
+            }
+        }
+    }
+
+    /// Send a message.  Text and binary messages are sent unfragmented.
+    /// Sending a Close message is equivalent to calling `close`.
+    pub async fn send(&mut self, msg: Message) -> io::Result<()> {
+        match msg {
+            Message::Text(text) => self.send_frame(Frame::new(Opcode::Text, text)).await,
+            Message::Binary(data) => self.send_frame(Frame::new(Opcode::Binary, data)).await,
+            Message::Ping(data) => self.send_frame(Frame::new(Opcode::Ping, data)).await,
+            Message::Pong(data) => self.send_frame(Frame::new(Opcode::Pong, data)).await,
+            Message::Close(code, reason) => self.close(code, &reason).await,
+        }
+    }
+
+    /// Start the closing handshake.  The reason is truncated to fit in a
+    /// control frame.  Messages may still be received until `recv` returns
+    /// the client's Close message.  Nothing is sent if a Close frame has
+    /// already been sent.
+    pub async fn close(&mut self, code: u16, reason: &str) -> io::Result<()> {
+        if self.close_sent {
+            return Ok(());
+        }
+
+        let mut len = reason.len().min(MAX_CONTROL_SIZE - 2);
+        while !reason.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        let mut payload = code.to_be_bytes().to_vec();
+        payload.extend_from_slice(&reason.as_bytes()[..len]);
+
+        self.send_frame(Frame::new(Opcode::Close, payload)).await
+    }
+
+    /// Receive the next frame.  None is returned after a Close frame has
+    /// been received, or if the client closes the connection between frames.
+    ///
+    /// Masking, reserved bits, control frame constraints and the maximum
+    /// message size are checked; a violation closes the connection and
+    /// returns an `io::ErrorKind::InvalidData` error.
+    pub async fn recv_frame(&mut self) -> io::Result<Option<Frame>> {
+        if self.close_received {
+            return Ok(None);
+        }
+
+        loop {
+            match decode_frame(&self.buf[self.pos..], self.options.max_message_size) {
+                Ok(Some((frame, n))) => {
+                    self.pos += n;
+                    if frame.opcode == Opcode::Close {
+                        self.close_received = true;
+                    }
+                    return Ok(Some(frame));
+                }
+
+                Ok(None) => {}
+
+                Err(v) => return Err(self.fail(v).await),
+            }
+
+            if self.pos > 0 {
+                self.buf.drain(..self.pos);
+                self.pos = 0;
+            }
+
+            if self.reader.read_buf(&mut self.buf).await? == 0 {
+                if self.buf.is_empty() {
+                    return Ok(None);
+                }
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+        }
+    }
+
+    /// Send a frame.  Control frames must not be fragmented and their payload
+    /// may not exceed 125 bytes.  Nothing can be sent after a Close frame.
+    pub async fn send_frame(&mut self, frame: Frame) -> io::Result<()> {
+        if self.close_sent {
+            return Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "close frame has been sent",
+            ));
+        }
+
+        if frame.opcode.is_control() && (!frame.fin || frame.payload.len() > MAX_CONTROL_SIZE) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid control frame",
+            ));
+        }
+
+        if frame.opcode == Opcode::Close {
+            self.close_sent = true;
+        }
+
+        let data = encode_frame(&frame);
+        let mut buf = data.as_slice();
+
+        while !buf.is_empty() {
+            match poll_fn(|cx| Pin::new(&mut self.writer).poll_write(cx, buf)).await? {
+                0 => return Err(io::ErrorKind::WriteZero.into()),
+                n => buf = &buf[n..],
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Close the connection due to a protocol violation.
+    async fn fail(&mut self, v: Violation) -> io::Error {
+        let Violation(code, msg) = v;
+
+        let _ = self.close(code, msg).await;
+        let _ = poll_fn(|cx| Pin::new(&mut self.writer).poll_close(cx)).await;
+        self.close_received = true;
+
+        io::Error::new(io::ErrorKind::InvalidData, msg)
+    }
+}
+
+/// Decode a client frame.  Returns the frame and its encoded size, or None
+/// if the data is incomplete.
+fn decode_frame(data: &[u8], max_size: usize) -> Result<Option<(Frame, usize)>, Violation> {
+    if data.len() < 2 {
+        return Ok(None);
+    }
+
+    let fin = data[0] & 0x80 != 0;
+
+    if data[0] & 0x70 != 0 {
+        return Err(Violation(CLOSE_PROTOCOL_ERROR, "reserved bits are set"));
+    }
+
+    let opcode = Opcode::from_bits(data[0] & 0x0f)
+        .ok_or(Violation(CLOSE_PROTOCOL_ERROR, "unknown opcode"))?;
+
+    if data[1] & 0x80 == 0 {
+        return Err(Violation(
+            CLOSE_PROTOCOL_ERROR,
+            "client frame is not masked",
+        ));
+    }
+
+    let (len, mut pos) = match data[1] & 0x7f {
+        126 => match data.get(2..4) {
+            Some(b) => (u16::from_be_bytes([b[0], b[1]]) as u64, 4),
+            None => return Ok(None),
+        },
+        127 => match data.get(2..10) {
+            Some(b) => (u64::from_be_bytes(b.try_into().unwrap()), 10),
+            None => return Ok(None),
+        },
+        n => (n as u64, 2),
+    };
+
+    if opcode.is_control() && (!fin || len > MAX_CONTROL_SIZE as u64) {
+        return Err(Violation(CLOSE_PROTOCOL_ERROR, "invalid control frame"));
+    }
+
+    if len > max_size as u64 {
+        return Err(Violation(CLOSE_TOO_LARGE, "frame is too large"));
+    }
+
+    let len = len as usize;
+
+    let mask = match data.get(pos..pos + 4) {
+        Some(mask) => [mask[0], mask[1], mask[2], mask[3]],
+        None => return Ok(None),
+    };
+    pos += 4;
+
+    let payload = match data.get(pos..pos + len) {
+        Some(payload) => payload
+            .iter()
+            .enumerate()
+            .map(|(i, b)| b ^ mask[i & 3])
+            .collect(),
+        None => return Ok(None),
+    };
+
+    let frame = Frame {
+        _internal: (),
+        fin,
+        opcode,
+        payload,
+    };
+
+    Ok(Some((frame, pos + len)))
+}
+
+/// Encode an unmasked server frame.
+fn encode_frame(frame: &Frame) -> Vec<u8> {
+    let len = frame.payload.len();
+    let mut data = Vec::with_capacity(len + 10);
+
+    data.push((frame.fin as u8) << 7 | frame.opcode.bits());
+
+    if len < 126 {
+        data.push(len as u8);
+    } else if len <= u16::MAX as usize {
+        data.push(126);
+        data.extend_from_slice(&(len as u16).to_be_bytes());
+    } else {
+        data.push(127);
+        data.extend_from_slice(&(len as u64).to_be_bytes());
+    }
+
+    data.extend_from_slice(&frame.payload);
+    data
+}
+
+/// Parse the payload of a Close frame.
+fn parse_close(payload: &[u8]) -> Result<(u16, String), Violation> {
+    match payload {
+        [] => Ok((CLOSE_NO_STATUS, String::new())),
+
+        [_] => Err(Violation(CLOSE_PROTOCOL_ERROR, "invalid close frame")),
+
+        [hi, lo, reason @ ..] => {
+            let code = u16::from_be_bytes([*hi, *lo]);
+
+            if !matches!(code, 1000..=1003 | 1007..=1014 | 3000..=4999) {
+                return Err(Violation(CLOSE_PROTOCOL_ERROR, "invalid close code"));
+            }
+
+            match str::from_utf8(reason) {
+                Ok(reason) => Ok((code, reason.to_string())),
+                Err(_) => Err(Violation(
+                    CLOSE_INVALID_DATA,
+                    "close reason is not valid UTF-8",
+                )),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encode a masked client frame.
+    fn client_frame(first: u8, payload: &[u8]) -> Vec<u8> {
+        let mask = [0x37, 0xfa, 0x21, 0x3d];
+        let mut data = vec![first];
+
+        if payload.len() < 126 {
+            data.push(0x80 | payload.len() as u8);
+        } else {
+            data.push(0x80 | 126);
+            data.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+        }
+
+        data.extend_from_slice(&mask);
+        data.extend(payload.iter().enumerate().map(|(i, b)| b ^ mask[i & 3]));
+        data
+    }
+
+    fn violation(data: &[u8], max_size: usize) -> u16 {
+        match decode_frame(data, max_size) {
+            Err(Violation(code, _)) => code,
+            Ok(_) => panic!("frame was accepted"),
+        }
+    }
+
+    #[test]
+    fn decode_masked_text() {
+        // RFC 6455, section 5.7.
+        let data = [
+            0x81, 0x85, 0x37, 0xfa, 0x21, 0x3d, 0x7f, 0x9f, 0x4d, 0x51, 0x58,
+        ];
+
+        let (frame, n) = decode_frame(&data, 1024).ok().unwrap().unwrap();
+        assert!(frame.fin);
+        assert_eq!(frame.opcode, Opcode::Text);
+        assert_eq!(frame.payload, b"Hello");
+        assert_eq!(n, data.len());
+    }
+
+    #[test]
+    fn decode_extended_length() {
+        let payload = vec![7; 300];
+        let mut data = client_frame(0x02, &payload);
+        data.extend_from_slice(b"next");
+
+        let (frame, n) = decode_frame(&data, 1024).ok().unwrap().unwrap();
+        assert!(!frame.fin);
+        assert_eq!(frame.opcode, Opcode::Binary);
+        assert_eq!(frame.payload, payload);
+        assert_eq!(n, data.len() - 4);
+    }
+
+    #[test]
+    fn decode_incomplete() {
+        let data = client_frame(0x81, &[1; 200]);
+
+        for n in 0..data.len() {
+            assert!(decode_frame(&data[..n], 1024).ok().unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn decode_violations() {
+        assert_eq!(violation(&[0x81, 0x00], 1024), CLOSE_PROTOCOL_ERROR);
+        assert_eq!(
+            violation(&client_frame(0xc1, b""), 1024),
+            CLOSE_PROTOCOL_ERROR
+        );
+        assert_eq!(
+            violation(&client_frame(0x83, b""), 1024),
+            CLOSE_PROTOCOL_ERROR
+        );
+        assert_eq!(
+            violation(&client_frame(0x09, b""), 1024),
+            CLOSE_PROTOCOL_ERROR
+        );
+        assert_eq!(
+            violation(&client_frame(0x89, &[0; 126]), 1024),
+            CLOSE_PROTOCOL_ERROR
+        );
+        assert_eq!(
+            violation(&client_frame(0x82, &[0; 11]), 10),
+            CLOSE_TOO_LARGE
+        );
+    }
+
+    #[test]
+    fn encode_lengths() {
+        for (len, header) in [(125, 2), (126, 4), (65535, 4), (65536, 10)] {
+            let data = encode_frame(&Frame::new(Opcode::Binary, vec![0; len]));
+            assert_eq!(data[0], 0x82);
+            assert_eq!(data.len(), header + len);
+        }
+    }
+
+    #[test]
+    fn close_payload() {
+        assert_eq!(
+            parse_close(b"").ok(),
+            Some((CLOSE_NO_STATUS, String::new()))
+        );
+        assert_eq!(
+            parse_close(b"\x03\xe8bye").ok(),
+            Some((CLOSE_NORMAL, "bye".to_string()))
+        );
+        assert!(parse_close(b"\x03").is_err());
+        assert!(parse_close(b"\x03\xed").is_err());
+        assert!(parse_close(b"\x03\xe8\xff").is_err());
+    }
+
+    #[test]
+    fn handshake_accept_key() {
+        // RFC 6455, section 1.3.
+        assert_eq!(
+            accept_key(b"dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+}